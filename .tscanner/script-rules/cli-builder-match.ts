@@ -29,7 +29,7 @@ const FLAG_MAPPINGS: Record<string, string | string[]> = {
   rule: 'filter',
 };
 
-const IGNORED_FLAGS = ['format', 'staged'];
+const IGNORED_FLAGS = ['format', 'staged', 'watch', 'record'];
 
 function kebabToCamel(str: string): string {
   return str.replace(/-([a-z])/g, (_, c) => c.toUpperCase());
@@ -150,6 +150,16 @@ runScript((input) => {
     issues,
   );
 
+  const cacheConfigFields = extractZodObjectFields(tsContent, 'cacheConfigSchema');
+  compareFields(
+    extractSchemaFields(jsonSchema, 'CacheConfig'),
+    cacheConfigFields,
+    'cacheConfigSchema',
+    schemasFile.path,
+    findLineNumber(tsContent, 'cacheConfigSchema'),
+    issues,
+  );
+
   const cliConfigFields = extractZodObjectFields(tsContent, 'cliConfigSchema');
   compareFields(
     extractSchemaFields(jsonSchema, 'CliConfig'),
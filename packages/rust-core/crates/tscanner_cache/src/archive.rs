@@ -0,0 +1,187 @@
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use serde_bytes::ByteBuf;
+use std::fs;
+use std::path::Path;
+
+use crate::store::{count_encoded_entries, CACHE_NAMES, CACHE_VERSION};
+
+const ARCHIVE_FORMAT: &str = "tscanner-cache";
+
+#[derive(Serialize, Deserialize)]
+struct CacheArchive {
+    format: String,
+    version: String,
+    caches: Vec<(String, ByteBuf)>,
+}
+
+#[derive(Debug)]
+pub struct ArchiveSummary {
+    pub caches: Vec<String>,
+    pub bytes: usize,
+}
+
+pub fn export_archive(cache_dir: &Path, output: &Path) -> Result<ArchiveSummary> {
+    let mut caches = Vec::new();
+    for name in CACHE_NAMES {
+        let path = cache_dir.join(name);
+        if !path.exists() {
+            continue;
+        }
+        let content =
+            fs::read(&path).context(format!("Failed to read cache: {}", path.display()))?;
+        caches.push((name.to_string(), ByteBuf::from(content)));
+    }
+
+    if caches.is_empty() {
+        bail!("No cache found in {}", cache_dir.display());
+    }
+
+    let archive = CacheArchive {
+        format: ARCHIVE_FORMAT.to_string(),
+        version: CACHE_VERSION.to_string(),
+        caches,
+    };
+    let content = rmp_serde::to_vec_named(&archive).context("Failed to encode cache archive")?;
+    fs::write(output, &content).context(format!("Failed to write {}", output.display()))?;
+
+    Ok(ArchiveSummary {
+        caches: archive.caches.into_iter().map(|(name, _)| name).collect(),
+        bytes: content.len(),
+    })
+}
+
+pub fn import_archive(input: &Path, cache_dir: &Path) -> Result<ArchiveSummary> {
+    let content = fs::read(input).context(format!("Failed to read {}", input.display()))?;
+    let archive: CacheArchive = rmp_serde::from_slice(&content)
+        .ok()
+        .filter(|archive: &CacheArchive| archive.format == ARCHIVE_FORMAT)
+        .context(format!("{} is not a cache archive", input.display()))?;
+
+    if archive.version != CACHE_VERSION {
+        bail!(
+            "Cache archive was created by version {} (current: {})",
+            archive.version,
+            CACHE_VERSION
+        );
+    }
+
+    let archived: Vec<(String, ByteBuf)> = archive
+        .caches
+        .into_iter()
+        .filter(|(name, _)| CACHE_NAMES.contains(&name.as_str()))
+        .collect();
+    if let Some((name, _)) = archived
+        .iter()
+        .find(|(_, data)| count_encoded_entries(data).is_none())
+    {
+        bail!("Cache archive entry '{}' is corrupt", name);
+    }
+
+    fs::create_dir_all(cache_dir).context(format!("Failed to create {}", cache_dir.display()))?;
+
+    let mut caches = Vec::new();
+    for (name, data) in archived {
+        let path = cache_dir.join(&name);
+        fs::write(&path, data.as_ref()).context(format!("Failed to write {}", path.display()))?;
+        caches.push(name);
+    }
+
+    Ok(ArchiveSummary {
+        caches,
+        bytes: content.len(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CacheLookup, FileCache, RuleFingerprints};
+    use std::path::PathBuf;
+    use tscanner_types::Issue;
+
+    const SOURCE: &str = "console.log(1);\n";
+
+    fn scratch_dir(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("tscanner-archive-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn fingerprints() -> RuleFingerprints {
+        RuleFingerprints::from([("no-console".to_string(), 1)])
+    }
+
+    fn write_archive(path: &Path, version: &str, caches: Vec<(String, ByteBuf)>) {
+        let archive = CacheArchive {
+            format: ARCHIVE_FORMAT.to_string(),
+            version: version.to_string(),
+            caches,
+        };
+        fs::write(path, rmp_serde::to_vec_named(&archive).unwrap()).unwrap();
+    }
+
+    #[test]
+    fn export_then_import_roundtrips_lookups() {
+        let root = scratch_dir("roundtrip");
+        let source_dir = root.join("source");
+        let target_dir = root.join("target");
+        let archive = root.join("cache.tscache");
+        let file = root.join("a.ts");
+
+        let cache = FileCache::for_project(&root, Some(&source_dir), fingerprints());
+        let issue = Issue::new("no-console", file.clone(), 1, 1, 8, "Avoid".to_string());
+        cache.insert(&file, SOURCE, vec![issue]);
+        cache.flush();
+
+        let exported = export_archive(&source_dir, &archive).unwrap();
+        let imported = import_archive(&archive, &target_dir).unwrap();
+
+        assert_eq!(exported.caches, vec!["cache.bin"]);
+        assert_eq!(imported.caches, exported.caches);
+        let restored = FileCache::for_project(&root, Some(&target_dir), fingerprints());
+        match restored.lookup(&file, SOURCE) {
+            CacheLookup::Hit(issues) => assert_eq!(issues[0].file, file),
+            _ => panic!("expected imported cache to hit"),
+        }
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn import_rejects_mismatched_version() {
+        let root = scratch_dir("version");
+        let archive = root.join("cache.tscache");
+        write_archive(&archive, "0.0.0-old", Vec::new());
+
+        let error = import_archive(&archive, &root.join("target")).unwrap_err();
+
+        assert!(error.to_string().contains("created by version 0.0.0-old"));
+        assert!(!root.join("target").exists());
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn import_rejects_corrupt_archives() {
+        let root = scratch_dir("corrupt");
+        let target = root.join("target");
+
+        let garbage = root.join("garbage.tscache");
+        fs::write(&garbage, b"\xc1not msgpack").unwrap();
+        let error = import_archive(&garbage, &target).unwrap_err();
+        assert!(error.to_string().contains("is not a cache archive"));
+
+        let corrupt_entry = root.join("entry.tscache");
+        write_archive(
+            &corrupt_entry,
+            CACHE_VERSION,
+            vec![("cache.bin".to_string(), ByteBuf::from(vec![0xc1, 0x00]))],
+        );
+        let error = import_archive(&corrupt_entry, &target).unwrap_err();
+        assert!(error.to_string().contains("'cache.bin' is corrupt"));
+
+        assert!(!target.exists());
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
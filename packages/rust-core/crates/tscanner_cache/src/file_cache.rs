@@ -1,44 +1,46 @@
 use dashmap::DashMap;
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 use std::fs;
 use std::path::{Path, PathBuf};
-use std::time::SystemTime;
-use tscanner_constants::cache_dir_name;
 use tscanner_types::Issue;
 
+use crate::fingerprint::RuleFingerprints;
+use crate::hash::hash_bytes;
+use crate::limits::CacheLimits;
+use crate::paths::{absolutize_issue, relative_key, relativize_issue};
+use crate::project::resolve_cache_dir;
+use crate::store::{load_entries, now_secs, save_entries, CacheRecord, FILE_CACHE_NAME};
+
 #[derive(Clone, Serialize, Deserialize)]
 struct CacheEntry {
-    #[serde(with = "systemtime_serde")]
-    mtime: SystemTime,
-    config_hash: u64,
+    content_hash: u64,
+    rules: RuleFingerprints,
+    last_used: u64,
     issues: Vec<Issue>,
 }
 
-mod systemtime_serde {
-    use serde::{Deserialize, Deserializer, Serialize, Serializer};
-    use std::time::{SystemTime, UNIX_EPOCH};
-
-    pub fn serialize<S>(time: &SystemTime, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        let duration = time.duration_since(UNIX_EPOCH).unwrap();
-        duration.as_secs().serialize(serializer)
+impl CacheRecord for CacheEntry {
+    fn last_used(&self) -> u64 {
+        self.last_used
     }
+}
 
-    pub fn deserialize<'de, D>(deserializer: D) -> Result<SystemTime, D::Error>
-    where
-        D: Deserializer<'de>,
-    {
-        let secs = u64::deserialize(deserializer)?;
-        Ok(UNIX_EPOCH + std::time::Duration::from_secs(secs))
-    }
+pub enum CacheLookup {
+    Hit(Vec<Issue>),
+    Partial {
+        issues: Vec<Issue>,
+        stale_rules: HashSet<String>,
+    },
+    Miss,
 }
 
 pub struct FileCache {
     entries: DashMap<PathBuf, CacheEntry>,
-    config_hash: u64,
-    cache_dir: Option<PathBuf>,
+    fingerprints: Option<RuleFingerprints>,
+    root: Option<PathBuf>,
+    cache_file: Option<PathBuf>,
+    limits: CacheLimits,
 }
 
 impl Default for FileCache {
@@ -51,113 +53,144 @@ impl FileCache {
     pub fn new() -> Self {
         Self {
             entries: DashMap::new(),
-            config_hash: 0,
-            cache_dir: Self::get_cache_dir(),
+            fingerprints: None,
+            root: None,
+            cache_file: None,
+            limits: CacheLimits::default(),
         }
     }
 
-    pub fn with_config_hash(config_hash: u64) -> Self {
-        let cache_dir = Self::get_cache_dir();
-        let mut cache = Self {
+    pub fn for_project(
+        root: &Path,
+        cache_dir: Option<&Path>,
+        fingerprints: RuleFingerprints,
+    ) -> Self {
+        let cache_file = resolve_cache_dir(root, cache_dir).map(|dir| dir.join(FILE_CACHE_NAME));
+        let cache = Self {
             entries: DashMap::new(),
-            config_hash,
-            cache_dir: cache_dir.clone(),
+            fingerprints: Some(fingerprints),
+            root: Some(root.to_path_buf()),
+            cache_file,
+            limits: CacheLimits::default(),
         };
 
-        if let Some(dir) = cache_dir {
-            cache.load_from_disk(&dir, config_hash);
+        if let Some(ref cache_file) = cache.cache_file {
+            cache.load_from_disk(cache_file);
         }
 
         cache
     }
 
-    fn get_cache_dir() -> Option<PathBuf> {
-        let cache_dir = dirs::cache_dir()?.join(cache_dir_name());
-        fs::create_dir_all(&cache_dir).ok()?;
-        Some(cache_dir)
+    pub fn with_limits(mut self, limits: CacheLimits) -> Self {
+        self.limits = limits;
+        self
     }
 
-    fn load_from_disk(&mut self, cache_dir: &Path, config_hash: u64) {
-        let cache_file = cache_dir.join(format!("cache_{}.json", config_hash));
+    fn load_from_disk(&self, cache_file: &Path) {
+        for (path, entry) in load_entries::<PathBuf, CacheEntry>(cache_file) {
+            self.entries.insert(path, entry);
+        }
+    }
 
-        if !cache_file.exists() {
+    fn save_to_disk(&self) {
+        let Some(ref cache_file) = self.cache_file else {
             return;
-        }
+        };
 
-        if let Ok(content) = fs::read_to_string(&cache_file) {
-            if let Ok(entries) = serde_json::from_str::<Vec<(PathBuf, CacheEntry)>>(&content) {
-                for (path, entry) in entries {
-                    if entry.config_hash == config_hash {
-                        self.entries.insert(path, entry);
-                    }
-                }
-            }
-        }
+        let entries = self
+            .entries
+            .iter()
+            .map(|entry| (entry.key().clone(), entry.value().clone()))
+            .collect();
+        save_entries(cache_file, entries, &self.limits);
     }
 
-    fn save_to_disk(&self) {
-        if let Some(cache_dir) = &self.cache_dir {
-            let cache_file = cache_dir.join(format!("cache_{}.json", self.config_hash));
+    fn key(&self, path: &Path) -> PathBuf {
+        relative_key(self.root.as_deref(), path)
+    }
 
-            let entries: Vec<(PathBuf, CacheEntry)> = self
-                .entries
-                .iter()
-                .map(|entry| (entry.key().clone(), entry.value().clone()))
-                .collect();
+    fn relativize(&self, issue: Issue) -> Issue {
+        relativize_issue(self.root.as_deref(), issue)
+    }
 
-            if let Ok(content) = serde_json::to_string(&entries) {
-                let _ = fs::write(&cache_file, &content);
-            }
-        }
+    fn absolutize(&self, issue: Issue) -> Issue {
+        absolutize_issue(self.root.as_deref(), issue)
     }
 
-    pub fn get(&self, path: &Path) -> Option<Vec<Issue>> {
-        let metadata = fs::metadata(path).ok()?;
-        let mtime = metadata.modified().ok()?;
+    pub fn lookup(&self, path: &Path, content: &str) -> CacheLookup {
+        let Some(mut entry) = self.entries.get_mut(&self.key(path)) else {
+            return CacheLookup::Miss;
+        };
 
-        if let Some(entry) = self.entries.get(path) {
-            let cached_secs = entry
-                .mtime
-                .duration_since(std::time::UNIX_EPOCH)
-                .unwrap()
-                .as_secs();
-            let current_secs = mtime
-                .duration_since(std::time::UNIX_EPOCH)
-                .unwrap()
-                .as_secs();
+        if entry.content_hash != hash_bytes(content.as_bytes()) {
+            return CacheLookup::Miss;
+        }
+        entry.last_used = now_secs();
 
-            if cached_secs == current_secs && entry.config_hash == self.config_hash {
-                return Some(entry.issues.clone());
-            }
+        let Some(ref fingerprints) = self.fingerprints else {
+            return CacheLookup::Hit(
+                entry
+                    .issues
+                    .iter()
+                    .cloned()
+                    .map(|issue| self.absolutize(issue))
+                    .collect(),
+            );
+        };
+
+        let stale_rules: HashSet<String> = fingerprints
+            .iter()
+            .filter(|(rule, fingerprint)| entry.rules.get(*rule) != Some(fingerprint))
+            .map(|(rule, _)| rule.clone())
+            .collect();
+
+        if !fingerprints.is_empty() && stale_rules.len() == fingerprints.len() {
+            return CacheLookup::Miss;
         }
 
-        None
-    }
+        let issues: Vec<Issue> = entry
+            .issues
+            .iter()
+            .filter(|issue| {
+                fingerprints.contains_key(&issue.rule) && !stale_rules.contains(&issue.rule)
+            })
+            .cloned()
+            .map(|issue| self.absolutize(issue))
+            .collect();
 
-    pub fn insert(&self, path: PathBuf, issues: Vec<Issue>) {
-        if let Ok(metadata) = fs::metadata(&path) {
-            if let Ok(mtime) = metadata.modified() {
-                self.entries.insert(
-                    path,
-                    CacheEntry {
-                        mtime,
-                        config_hash: self.config_hash,
-                        issues: issues.clone(),
-                    },
-                );
+        if stale_rules.is_empty() {
+            CacheLookup::Hit(issues)
+        } else {
+            CacheLookup::Partial {
+                issues,
+                stale_rules,
             }
         }
     }
 
+    pub fn insert(&self, path: &Path, content: &str, issues: Vec<Issue>) {
+        self.entries.insert(
+            self.key(path),
+            CacheEntry {
+                content_hash: hash_bytes(content.as_bytes()),
+                rules: self.fingerprints.clone().unwrap_or_default(),
+                last_used: now_secs(),
+                issues: issues
+                    .into_iter()
+                    .map(|issue| self.relativize(issue))
+                    .collect(),
+            },
+        );
+    }
+
     pub fn invalidate(&self, path: &Path) {
-        self.entries.remove(path);
+        self.entries.remove(&self.key(path));
     }
 
     pub fn clear(&self) {
         self.entries.clear();
-        if let Some(cache_dir) = &self.cache_dir {
-            let cache_file = cache_dir.join(format!("cache_{}.json", self.config_hash));
-            let _ = std::fs::remove_file(&cache_file);
+        if let Some(ref cache_file) = self.cache_file {
+            let _ = fs::remove_file(cache_file);
         }
     }
 
@@ -173,3 +206,104 @@ impl FileCache {
         self.save_to_disk();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{Duration, SystemTime};
+
+    fn scratch_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "tscanner-file-cache-{}-{}",
+            name,
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn fingerprints() -> RuleFingerprints {
+        RuleFingerprints::from([("no-console".to_string(), 1)])
+    }
+
+    fn issue(file: PathBuf) -> Issue {
+        Issue::new("no-console", file, 1, 1, 8, "Avoid console".to_string())
+    }
+
+    fn hit(lookup: CacheLookup) -> Vec<Issue> {
+        match lookup {
+            CacheLookup::Hit(issues) => issues,
+            CacheLookup::Partial { .. } => panic!("expected hit, got partial"),
+            CacheLookup::Miss => panic!("expected hit, got miss"),
+        }
+    }
+
+    #[test]
+    fn mtime_only_change_still_hits_after_reload() {
+        let root = scratch_dir("mtime");
+        let cache_dir = root.join("cache");
+        let file = root.join("a.ts");
+        fs::write(&file, "console.log(1);\n").unwrap();
+
+        let cache = FileCache::for_project(&root, Some(&cache_dir), fingerprints());
+        cache.insert(
+            &file,
+            &fs::read_to_string(&file).unwrap(),
+            vec![issue(file.clone())],
+        );
+        cache.flush();
+
+        let touched = SystemTime::now() + Duration::from_secs(3600);
+        fs::File::options()
+            .write(true)
+            .open(&file)
+            .unwrap()
+            .set_modified(touched)
+            .unwrap();
+
+        let reloaded = FileCache::for_project(&root, Some(&cache_dir), fingerprints());
+        let issues = hit(reloaded.lookup(&file, &fs::read_to_string(&file).unwrap()));
+
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].file, file);
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn content_change_misses() {
+        let root = scratch_dir("content");
+        let file = root.join("a.ts");
+        let cache = FileCache::for_project(&root, Some(&root.join("cache")), fingerprints());
+        cache.insert(&file, "console.log(1);\n", vec![issue(file.clone())]);
+
+        assert!(matches!(
+            cache.lookup(&file, "console.log(2);\n"),
+            CacheLookup::Miss
+        ));
+        assert_eq!(hit(cache.lookup(&file, "console.log(1);\n")).len(), 1);
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn entries_are_keyed_relative_to_project_root() {
+        let first = scratch_dir("root-a");
+        let second = scratch_dir("root-b");
+        let cache_dir = first.join("cache");
+
+        let cache = FileCache::for_project(&first, Some(&cache_dir), fingerprints());
+        cache.insert(
+            &first.join("src/a.ts"),
+            "console.log(1);\n",
+            vec![issue(first.join("src/a.ts"))],
+        );
+        cache.flush();
+
+        let moved = FileCache::for_project(&second, Some(&cache_dir), fingerprints());
+        let issues = hit(moved.lookup(&second.join("src/a.ts"), "console.log(1);\n"));
+
+        assert_eq!(issues[0].file, second.join("src/a.ts"));
+        fs::remove_dir_all(&first).unwrap();
+        fs::remove_dir_all(&second).unwrap();
+    }
+}
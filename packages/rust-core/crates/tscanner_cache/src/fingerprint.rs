@@ -0,0 +1,15 @@
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+use crate::hash::hash_content;
+
+pub type RuleFingerprints = BTreeMap<String, u64>;
+
+pub fn rule_fingerprint(kind: &str, config: &Value) -> u64 {
+    hash_content(&format!(
+        "{}:{}:{}",
+        env!("CARGO_PKG_VERSION"),
+        kind,
+        config
+    ))
+}
@@ -1,9 +1,24 @@
 mod ai_cache;
+mod archive;
 mod file_cache;
+mod fingerprint;
 mod hash;
+mod limits;
+mod maintenance;
+mod paths;
+mod project;
 mod script_cache;
+mod store;
 
 pub use ai_cache::AiCache;
-pub use file_cache::FileCache;
-pub use hash::hash_content;
+pub use archive::{export_archive, import_archive, ArchiveSummary};
+pub use file_cache::{CacheLookup, FileCache};
+pub use fingerprint::{rule_fingerprint, RuleFingerprints};
+pub use hash::{hash_bytes, hash_content};
+pub use limits::CacheLimits;
+pub use maintenance::{
+    cache_root, clean_cache_dir, inspect_cache_dir, list_cache_dirs, CacheDirInfo, CacheFileInfo,
+    CleanSummary,
+};
+pub use project::{project_cache_dir, project_id, resolve_cache_dir};
 pub use script_cache::ScriptCache;
@@ -0,0 +1,32 @@
+use std::time::Duration;
+use tscanner_constants::{cache_max_age_days, cache_max_size_mb};
+
+const BYTES_PER_MB: u64 = 1024 * 1024;
+const SECS_PER_DAY: u64 = 24 * 60 * 60;
+
+#[derive(Debug, Clone, Copy)]
+pub struct CacheLimits {
+    pub max_size_bytes: u64,
+    pub max_age: Duration,
+}
+
+impl Default for CacheLimits {
+    fn default() -> Self {
+        Self::new(None, None)
+    }
+}
+
+impl CacheLimits {
+    pub fn new(max_size_mb: Option<u64>, max_age_days: Option<u64>) -> Self {
+        Self {
+            max_size_bytes: max_size_mb
+                .unwrap_or_else(cache_max_size_mb)
+                .saturating_mul(BYTES_PER_MB),
+            max_age: Duration::from_secs(
+                max_age_days
+                    .unwrap_or_else(cache_max_age_days)
+                    .saturating_mul(SECS_PER_DAY),
+            ),
+        }
+    }
+}
@@ -0,0 +1,202 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+use tscanner_constants::cache_dir_name;
+
+use crate::store::{count_entries, AI_CACHE_NAME, CACHE_NAMES, FILE_CACHE_NAME, SCRIPT_CACHE_NAME};
+
+pub struct CacheFileInfo {
+    pub name: String,
+    pub kind: Option<&'static str>,
+    pub bytes: u64,
+    pub entries: Option<usize>,
+    pub modified: Option<SystemTime>,
+}
+
+pub struct CacheDirInfo {
+    pub path: PathBuf,
+    pub files: Vec<CacheFileInfo>,
+}
+
+impl CacheDirInfo {
+    pub fn name(&self) -> String {
+        self.path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default()
+    }
+
+    pub fn bytes(&self) -> u64 {
+        self.files.iter().map(|f| f.bytes).sum()
+    }
+}
+
+#[derive(Default)]
+pub struct CleanSummary {
+    pub files: usize,
+    pub bytes: u64,
+}
+
+pub fn cache_root() -> Option<PathBuf> {
+    Some(dirs::cache_dir()?.join(cache_dir_name()))
+}
+
+fn cache_kind(name: &str) -> Option<&'static str> {
+    match name {
+        FILE_CACHE_NAME => Some("file"),
+        SCRIPT_CACHE_NAME => Some("script"),
+        AI_CACHE_NAME => Some("ai"),
+        _ => None,
+    }
+}
+
+pub fn inspect_cache_dir(dir: &Path) -> CacheDirInfo {
+    let mut files: Vec<CacheFileInfo> = fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.path().is_file())
+        .map(|e| {
+            let name = e.file_name().to_string_lossy().to_string();
+            let metadata = e.metadata().ok();
+            let kind = cache_kind(&name);
+            CacheFileInfo {
+                kind,
+                bytes: metadata.as_ref().map(|m| m.len()).unwrap_or_default(),
+                entries: kind.and_then(|_| count_entries(&e.path())),
+                modified: metadata.and_then(|m| m.modified().ok()),
+                name,
+            }
+        })
+        .collect();
+    files.sort_by(|a, b| a.name.cmp(&b.name));
+
+    CacheDirInfo {
+        path: dir.to_path_buf(),
+        files,
+    }
+}
+
+pub fn list_cache_dirs(root: &Path) -> Vec<CacheDirInfo> {
+    let mut dirs: Vec<CacheDirInfo> = fs::read_dir(root)
+        .into_iter()
+        .flatten()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.path().is_dir())
+        .map(|e| inspect_cache_dir(&e.path()))
+        .collect();
+    dirs.sort_by(|a, b| a.path.cmp(&b.path));
+
+    let loose = inspect_cache_dir(root);
+    if !loose.files.is_empty() {
+        dirs.push(loose);
+    }
+    dirs
+}
+
+const LEGACY_CACHE_PREFIXES: [&str; 3] = ["cache_", "ai_cache_", "script_cache_"];
+
+fn is_legacy_cache_name(name: &str) -> bool {
+    name.ends_with(".json")
+        && LEGACY_CACHE_PREFIXES
+            .iter()
+            .any(|prefix| name.starts_with(prefix))
+}
+
+pub fn clean_cache_dir(dir: &Path, older_than: Option<Duration>) -> CleanSummary {
+    let include_legacy = cache_root().is_some_and(|root| root == dir);
+    clean_dir(dir, older_than, include_legacy)
+}
+
+fn clean_dir(dir: &Path, older_than: Option<Duration>, include_legacy: bool) -> CleanSummary {
+    let cutoff = older_than.and_then(|age| SystemTime::now().checked_sub(age));
+    let mut summary = CleanSummary::default();
+
+    for file in inspect_cache_dir(dir).files {
+        let owned = CACHE_NAMES.contains(&file.name.as_str())
+            || (include_legacy && is_legacy_cache_name(&file.name));
+        if !owned {
+            continue;
+        }
+        let expired = match (cutoff, file.modified) {
+            (Some(cutoff), Some(modified)) => modified < cutoff,
+            (Some(_), None) => false,
+            (None, _) => true,
+        };
+        if expired && fs::remove_file(dir.join(&file.name)).is_ok() {
+            summary.files += 1;
+            summary.bytes += file.bytes;
+        }
+    }
+
+    let _ = fs::remove_dir(dir);
+    summary
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scratch_dir(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("tscanner-clean-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn clean_keeps_foreign_files() {
+        let dir = scratch_dir("foreign");
+        for name in CACHE_NAMES {
+            fs::write(dir.join(name), b"cache").unwrap();
+        }
+        fs::write(dir.join("notes.txt"), b"keep me").unwrap();
+        fs::write(dir.join("cache_legacy.json"), b"{}").unwrap();
+
+        let summary = clean_dir(&dir, None, false);
+
+        assert_eq!(summary.files, CACHE_NAMES.len());
+        assert!(dir.join("notes.txt").is_file());
+        assert!(dir.join("cache_legacy.json").is_file());
+        assert!(CACHE_NAMES.iter().all(|name| !dir.join(name).exists()));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn clean_removes_legacy_files_only_in_cache_root() {
+        let dir = scratch_dir("legacy");
+        for name in [
+            "cache_1a2b.json",
+            "ai_cache_1a2b.json",
+            "script_cache_1a2b.json",
+        ] {
+            fs::write(dir.join(name), b"{}").unwrap();
+        }
+        fs::write(dir.join("config.json"), b"{}").unwrap();
+
+        let summary = clean_dir(&dir, None, true);
+
+        assert_eq!(summary.files, 3);
+        assert!(dir.join("config.json").is_file());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn clean_with_older_than_keeps_fresh_and_foreign_files() {
+        let dir = scratch_dir("older");
+        fs::write(dir.join(FILE_CACHE_NAME), b"cache").unwrap();
+        fs::write(dir.join("notes.txt"), b"keep me").unwrap();
+
+        let summary = clean_dir(&dir, Some(Duration::from_secs(3600)), true);
+
+        assert_eq!(summary.files, 0);
+        assert!(dir.join(FILE_CACHE_NAME).is_file());
+
+        let summary = clean_dir(&dir, Some(Duration::ZERO), true);
+
+        assert_eq!(summary.files, 1);
+        assert!(dir.join("notes.txt").is_file());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,27 @@
+use std::path::{Path, PathBuf};
+use tscanner_types::Issue;
+
+pub(crate) fn relative_key(root: Option<&Path>, path: &Path) -> PathBuf {
+    match root {
+        Some(root) => path.strip_prefix(root).unwrap_or(path).to_path_buf(),
+        None => path.to_path_buf(),
+    }
+}
+
+pub(crate) fn relativize_issue(root: Option<&Path>, mut issue: Issue) -> Issue {
+    issue.file = relative_key(root, &issue.file);
+    for related in &mut issue.related {
+        related.file = relative_key(root, &related.file);
+    }
+    issue
+}
+
+pub(crate) fn absolutize_issue(root: Option<&Path>, mut issue: Issue) -> Issue {
+    if let Some(root) = root {
+        issue.file = root.join(&issue.file);
+        for related in &mut issue.related {
+            related.file = root.join(&related.file);
+        }
+    }
+    issue
+}
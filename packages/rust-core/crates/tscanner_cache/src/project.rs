@@ -0,0 +1,33 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use tscanner_constants::cache_dir_name;
+
+use crate::hash::hash_content;
+
+pub fn project_id(root: &Path) -> String {
+    let identity = tscanner_git::get_repository_id(root).unwrap_or_else(|_| {
+        fs::canonicalize(root)
+            .unwrap_or_else(|_| root.to_path_buf())
+            .display()
+            .to_string()
+    });
+    format!("{:016x}", hash_content(&identity))
+}
+
+pub fn project_cache_dir(root: &Path) -> Option<PathBuf> {
+    let cache_dir = dirs::cache_dir()?
+        .join(cache_dir_name())
+        .join(project_id(root));
+    fs::create_dir_all(&cache_dir).ok()?;
+    Some(cache_dir)
+}
+
+pub fn resolve_cache_dir(root: &Path, cache_dir: Option<&Path>) -> Option<PathBuf> {
+    match cache_dir {
+        Some(dir) => {
+            fs::create_dir_all(dir).ok()?;
+            Some(dir.to_path_buf())
+        }
+        None => project_cache_dir(root),
+    }
+}
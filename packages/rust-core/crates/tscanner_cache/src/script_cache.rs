@@ -3,31 +3,38 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fs;
 use std::path::{Path, PathBuf};
-use std::time::UNIX_EPOCH;
-use tscanner_constants::cache_dir_name;
 use tscanner_types::Issue;
 
-fn get_mtime_secs(path: &Path) -> Option<u64> {
-    fs::metadata(path)
-        .ok()?
-        .modified()
-        .ok()?
-        .duration_since(UNIX_EPOCH)
-        .ok()
-        .map(|d| d.as_secs())
-}
+use crate::fingerprint::RuleFingerprints;
+use crate::hash::{hash_bytes, hash_content};
+use crate::limits::CacheLimits;
+use crate::paths::{absolutize_issue, relative_key, relativize_issue};
+use crate::project::resolve_cache_dir;
+use crate::store::{load_entries, now_secs, save_entries, CacheRecord, SCRIPT_CACHE_NAME};
 
 #[derive(Clone, Serialize, Deserialize)]
 struct ScriptCacheEntry {
-    script_mtime: u64,
-    files_mtimes: HashMap<PathBuf, u64>,
+    fingerprint: u64,
+    script_hash: u64,
+    files_hashes: HashMap<PathBuf, u64>,
+    #[serde(default)]
+    dependencies_hashes: HashMap<PathBuf, u64>,
+    last_used: u64,
     issues: Vec<Issue>,
 }
 
+impl CacheRecord for ScriptCacheEntry {
+    fn last_used(&self) -> u64 {
+        self.last_used
+    }
+}
+
 pub struct ScriptCache {
     entries: DashMap<String, ScriptCacheEntry>,
-    config_hash: u64,
-    cache_dir: Option<PathBuf>,
+    fingerprints: Option<RuleFingerprints>,
+    root: Option<PathBuf>,
+    cache_file: Option<PathBuf>,
+    limits: CacheLimits,
 }
 
 impl Default for ScriptCache {
@@ -40,62 +47,94 @@ impl ScriptCache {
     pub fn new() -> Self {
         Self {
             entries: DashMap::new(),
-            config_hash: 0,
-            cache_dir: Self::get_cache_dir(),
+            fingerprints: None,
+            root: None,
+            cache_file: None,
+            limits: CacheLimits::default(),
         }
     }
 
-    pub fn with_config_hash(config_hash: u64) -> Self {
-        let cache_dir = Self::get_cache_dir();
-        let mut cache = Self {
+    pub fn for_project(
+        root: &Path,
+        cache_dir: Option<&Path>,
+        fingerprints: RuleFingerprints,
+    ) -> Self {
+        let cache_file = resolve_cache_dir(root, cache_dir).map(|dir| dir.join(SCRIPT_CACHE_NAME));
+        let cache = Self {
             entries: DashMap::new(),
-            config_hash,
-            cache_dir: cache_dir.clone(),
+            fingerprints: Some(fingerprints),
+            root: Some(root.to_path_buf()),
+            cache_file,
+            limits: CacheLimits::default(),
         };
 
-        if let Some(dir) = cache_dir {
-            cache.load_from_disk(&dir);
+        if let Some(ref cache_file) = cache.cache_file {
+            cache.load_from_disk(cache_file);
         }
 
         cache
     }
 
-    fn get_cache_dir() -> Option<PathBuf> {
-        let cache_dir = dirs::cache_dir()?.join(cache_dir_name());
-        fs::create_dir_all(&cache_dir).ok()?;
-        Some(cache_dir)
+    pub fn with_limits(mut self, limits: CacheLimits) -> Self {
+        self.limits = limits;
+        self
     }
 
-    fn load_from_disk(&mut self, cache_dir: &Path) {
-        let cache_file = cache_dir.join(format!("script_cache_{}.json", self.config_hash));
+    fn load_from_disk(&self, cache_file: &Path) {
+        for (rule_name, entry) in load_entries::<String, ScriptCacheEntry>(cache_file) {
+            self.entries.insert(rule_name, entry);
+        }
+    }
 
-        if !cache_file.exists() {
+    fn save_to_disk(&self) {
+        let Some(ref cache_file) = self.cache_file else {
             return;
-        }
+        };
 
-        if let Ok(content) = fs::read_to_string(&cache_file) {
-            if let Ok(entries) = serde_json::from_str::<Vec<(String, ScriptCacheEntry)>>(&content) {
-                for (rule_name, entry) in entries {
-                    self.entries.insert(rule_name, entry);
-                }
-            }
-        }
+        let entries = self
+            .entries
+            .iter()
+            .map(|entry| (entry.key().clone(), entry.value().clone()))
+            .collect();
+        save_entries(cache_file, entries, &self.limits);
     }
 
-    fn save_to_disk(&self) {
-        if let Some(cache_dir) = &self.cache_dir {
-            let cache_file = cache_dir.join(format!("script_cache_{}.json", self.config_hash));
+    fn fingerprint(&self, rule_name: &str) -> u64 {
+        self.fingerprints
+            .as_ref()
+            .and_then(|fingerprints| fingerprints.get(rule_name).copied())
+            .unwrap_or_default()
+    }
 
-            let entries: Vec<(String, ScriptCacheEntry)> = self
-                .entries
-                .iter()
-                .map(|entry| (entry.key().clone(), entry.value().clone()))
-                .collect();
+    fn files_hashes(&self, files: &[(PathBuf, String)]) -> HashMap<PathBuf, u64> {
+        files
+            .iter()
+            .map(|(path, content)| {
+                (
+                    relative_key(self.root.as_deref(), path),
+                    hash_content(content),
+                )
+            })
+            .collect()
+    }
 
-            if let Ok(content) = serde_json::to_string(&entries) {
-                let _ = fs::write(&cache_file, &content);
-            }
-        }
+    fn dependencies_hashes<'a>(
+        &self,
+        paths: impl Iterator<Item = &'a PathBuf>,
+    ) -> HashMap<PathBuf, u64> {
+        paths
+            .filter_map(|path| {
+                let absolute = match self.root {
+                    Some(ref root) => root.join(path),
+                    None => path.clone(),
+                };
+                let content = fs::read(&absolute).ok()?;
+                Some((
+                    relative_key(self.root.as_deref(), &absolute),
+                    hash_bytes(&content),
+                ))
+            })
+            .collect()
     }
 
     pub fn get(
@@ -103,27 +142,40 @@ impl ScriptCache {
         rule_name: &str,
         script_path: &Path,
         files: &[(PathBuf, String)],
+        dependencies: &[PathBuf],
     ) -> Option<Vec<Issue>> {
-        let entry = self.entries.get(rule_name)?;
+        let mut entry = self.entries.get_mut(rule_name)?;
+
+        if self.fingerprints.is_some() && entry.fingerprint != self.fingerprint(rule_name) {
+            return None;
+        }
 
-        let current_script_mtime = get_mtime_secs(script_path)?;
-        if entry.script_mtime != current_script_mtime {
+        if entry.script_hash != hash_bytes(&fs::read(script_path).ok()?) {
             return None;
         }
 
-        if entry.files_mtimes.len() != files.len() {
+        if entry.files_hashes != self.files_hashes(files) {
             return None;
         }
 
-        for (path, _) in files {
-            let current_mtime = get_mtime_secs(path)?;
-            let cached_mtime = entry.files_mtimes.get(path)?;
-            if *cached_mtime != current_mtime {
-                return None;
-            }
+        let tracked: Vec<PathBuf> = dependencies
+            .iter()
+            .map(|path| relative_key(self.root.as_deref(), path))
+            .chain(entry.dependencies_hashes.keys().cloned())
+            .collect();
+        if entry.dependencies_hashes != self.dependencies_hashes(tracked.iter()) {
+            return None;
         }
+        entry.last_used = now_secs();
 
-        Some(entry.issues.clone())
+        Some(
+            entry
+                .issues
+                .iter()
+                .cloned()
+                .map(|issue| absolutize_issue(self.root.as_deref(), issue))
+                .collect(),
+        )
     }
 
     pub fn insert(
@@ -131,35 +183,33 @@ impl ScriptCache {
         rule_name: &str,
         script_path: &Path,
         files: &[(PathBuf, String)],
+        dependencies: &[PathBuf],
         issues: Vec<Issue>,
     ) {
-        let script_mtime = match get_mtime_secs(script_path) {
-            Some(m) => m,
-            None => return,
+        let Ok(script) = fs::read(script_path) else {
+            return;
         };
 
-        let mut files_mtimes = HashMap::new();
-        for (path, _) in files {
-            if let Some(mtime) = get_mtime_secs(path) {
-                files_mtimes.insert(path.clone(), mtime);
-            }
-        }
-
         self.entries.insert(
             rule_name.to_string(),
             ScriptCacheEntry {
-                script_mtime,
-                files_mtimes,
-                issues,
+                fingerprint: self.fingerprint(rule_name),
+                script_hash: hash_bytes(&script),
+                files_hashes: self.files_hashes(files),
+                dependencies_hashes: self.dependencies_hashes(dependencies.iter()),
+                last_used: now_secs(),
+                issues: issues
+                    .into_iter()
+                    .map(|issue| relativize_issue(self.root.as_deref(), issue))
+                    .collect(),
             },
         );
     }
 
     pub fn clear(&self) {
         self.entries.clear();
-        if let Some(cache_dir) = &self.cache_dir {
-            let cache_file = cache_dir.join(format!("script_cache_{}.json", self.config_hash));
-            let _ = fs::remove_file(&cache_file);
+        if let Some(ref cache_file) = self.cache_file {
+            let _ = fs::remove_file(cache_file);
         }
     }
 
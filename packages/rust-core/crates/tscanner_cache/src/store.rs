@@ -0,0 +1,98 @@
+use serde::de::{DeserializeOwned, IgnoredAny};
+use serde::{Deserialize, Serialize};
+use std::cmp::Reverse;
+use std::fs;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::limits::CacheLimits;
+
+pub(crate) const CACHE_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+pub(crate) const FILE_CACHE_NAME: &str = "cache.bin";
+pub(crate) const SCRIPT_CACHE_NAME: &str = "script_cache.bin";
+pub(crate) const AI_CACHE_NAME: &str = "ai_cache.bin";
+pub(crate) const CACHE_NAMES: [&str; 3] = [FILE_CACHE_NAME, SCRIPT_CACHE_NAME, AI_CACHE_NAME];
+
+pub(crate) trait CacheRecord {
+    fn last_used(&self) -> u64;
+}
+
+pub(crate) fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default()
+}
+
+#[derive(Serialize, Deserialize)]
+struct CacheFile<K, V> {
+    version: String,
+    entries: Vec<(K, V)>,
+}
+
+pub(crate) fn load_entries<K, V>(cache_file: &Path) -> Vec<(K, V)>
+where
+    K: DeserializeOwned,
+    V: DeserializeOwned,
+{
+    let Ok(content) = fs::read(cache_file) else {
+        return Vec::new();
+    };
+    let Ok(file) = rmp_serde::from_slice::<CacheFile<K, V>>(&content) else {
+        return Vec::new();
+    };
+
+    if file.version != CACHE_VERSION {
+        return Vec::new();
+    }
+
+    file.entries
+}
+
+pub(crate) fn count_entries(cache_file: &Path) -> Option<usize> {
+    let content = fs::read(cache_file).ok()?;
+    count_encoded_entries(&content)
+}
+
+pub(crate) fn count_encoded_entries(content: &[u8]) -> Option<usize> {
+    rmp_serde::from_slice::<CacheFile<IgnoredAny, IgnoredAny>>(content)
+        .ok()
+        .map(|file| file.entries.len())
+}
+
+pub(crate) fn save_entries<K, V>(cache_file: &Path, entries: Vec<(K, V)>, limits: &CacheLimits)
+where
+    K: Serialize,
+    V: Serialize + CacheRecord,
+{
+    let file = CacheFile {
+        version: CACHE_VERSION.to_string(),
+        entries: evict(entries, limits),
+    };
+
+    if let Ok(content) = rmp_serde::to_vec_named(&file) {
+        let _ = fs::write(cache_file, &content);
+    }
+}
+
+fn evict<K, V>(mut entries: Vec<(K, V)>, limits: &CacheLimits) -> Vec<(K, V)>
+where
+    K: Serialize,
+    V: Serialize + CacheRecord,
+{
+    let oldest = now_secs().saturating_sub(limits.max_age.as_secs());
+    entries.retain(|(_, entry)| entry.last_used() >= oldest);
+    entries.sort_by_key(|(_, entry)| Reverse(entry.last_used()));
+
+    let mut total: u64 = 0;
+    entries
+        .into_iter()
+        .take_while(|entry| {
+            total += rmp_serde::to_vec_named(entry)
+                .map(|bytes| bytes.len() as u64)
+                .unwrap_or_default();
+            total <= limits.max_size_bytes
+        })
+        .collect()
+}
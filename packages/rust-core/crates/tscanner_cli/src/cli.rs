@@ -1,5 +1,7 @@
-use clap::{Parser, Subcommand, ValueEnum};
+use clap::{Args, Parser, Subcommand, ValueEnum};
 use std::path::PathBuf;
+use std::time::Duration;
+use tscanner_cli_output::ReportFormat;
 use tscanner_constants::config_dir_name;
 
 #[derive(Debug, Clone, Default, ValueEnum, PartialEq)]
@@ -7,12 +9,100 @@ pub enum OutputFormat {
     #[default]
     Text,
     Json,
+    Sarif,
+    Junit,
+    Checkstyle,
+    Gitlab,
+    Markdown,
+    Github,
+}
+
+impl OutputFormat {
+    pub fn is_machine_readable(&self) -> bool {
+        !matches!(self, OutputFormat::Text)
+    }
+
+    pub fn report_format(&self) -> Option<ReportFormat> {
+        match self {
+            OutputFormat::Text => None,
+            OutputFormat::Json => Some(ReportFormat::Json),
+            OutputFormat::Sarif => Some(ReportFormat::Sarif),
+            OutputFormat::Junit => Some(ReportFormat::Junit),
+            OutputFormat::Checkstyle => Some(ReportFormat::Checkstyle),
+            OutputFormat::Gitlab => Some(ReportFormat::Gitlab),
+            OutputFormat::Markdown => Some(ReportFormat::Markdown),
+            OutputFormat::Github => Some(ReportFormat::Github),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ReportTarget {
+    pub format: ReportFormat,
+    pub path: PathBuf,
+}
+
+fn parse_report_target(value: &str) -> Result<ReportTarget, String> {
+    let (format_name, path) = value
+        .split_once('=')
+        .ok_or_else(|| format!("expected FORMAT=FILE, got '{}'", value))?;
+    if path.is_empty() {
+        return Err(format!("missing file path for '{}' report", format_name));
+    }
+    let format = OutputFormat::from_str(format_name, true)
+        .ok()
+        .and_then(|f| f.report_format())
+        .ok_or_else(|| {
+            format!(
+                "unknown report format '{}' (expected json, sarif, junit, checkstyle, gitlab, markdown or github)",
+                format_name
+            )
+        })?;
+    Ok(ReportTarget {
+        format,
+        path: PathBuf::from(path),
+    })
+}
+
+fn parse_since_date(value: &str) -> Result<i64, String> {
+    let invalid = || format!("expected a date as YYYY-MM-DD, got '{}'", value);
+    let parts: Vec<&str> = value.split('-').collect();
+    let [year, month, day] = parts.as_slice() else {
+        return Err(invalid());
+    };
+    let year: i32 = year.parse().map_err(|_| invalid())?;
+    let month = month
+        .parse::<u8>()
+        .ok()
+        .and_then(|m| time::Month::try_from(m).ok())
+        .ok_or_else(invalid)?;
+    let day: u8 = day.parse().map_err(|_| invalid())?;
+    let date = time::Date::from_calendar_date(year, month, day).map_err(|_| invalid())?;
+    Ok(date.midnight().assume_utc().unix_timestamp())
+}
+
+fn parse_age(value: &str) -> Result<Duration, String> {
+    let invalid = || format!("expected an age like 30m, 12h, 7d or 2w, got '{}'", value);
+    let unit = value.chars().last().ok_or_else(invalid)?;
+    let amount: u64 = value[..value.len() - unit.len_utf8()]
+        .parse()
+        .map_err(|_| invalid())?;
+    let unit_secs = match unit {
+        'm' => 60,
+        'h' => 60 * 60,
+        'd' => 24 * 60 * 60,
+        'w' => 7 * 24 * 60 * 60,
+        _ => return Err(invalid()),
+    };
+    let secs = amount.checked_mul(unit_secs).ok_or_else(invalid)?;
+    Ok(Duration::from_secs(secs))
 }
 
 #[derive(Debug, Clone, ValueEnum)]
 pub enum CliGroupMode {
     File,
     Rule,
+    Author,
 }
 
 #[derive(Debug, Clone, ValueEnum)]
@@ -70,6 +160,21 @@ impl RegistryRuleKind {
     }
 }
 
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum MigrateSource {
+    Eslint,
+    Biome,
+}
+
+impl MigrateSource {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MigrateSource::Eslint => "eslint",
+            MigrateSource::Biome => "biome",
+        }
+    }
+}
+
 #[derive(Parser)]
 #[command(name = "tscanner")]
 #[command(version, about = "Code quality scanner for the AI-generated code era", long_about = None)]
@@ -84,145 +189,135 @@ pub enum Commands {
         about = "Scan code for issues and display results",
         after_help = "\u{200B}"
     )]
-    Check {
-        #[arg(
-            value_name = "PATH",
-            default_value = ".",
-            help = "Directory or file to scan (extra paths are ignored when --staged is used)",
-            num_args = 0..
-        )]
-        paths: Vec<PathBuf>,
+    Check(Box<CheckArgs>),
 
+    #[command(about = "Create a default configuration file")]
+    Init {
         #[arg(
             long,
-            value_name = "BRANCH",
-            help = "Only show issues in files changed compared to branch (e.g., origin/main)",
-            help_heading = "Scan Mode"
+            help = "Initialize with all built-in rules, example regex/script/AI rules, and sample files"
         )]
-        branch: Option<String>,
-
-        #[arg(long, help = "Scan only git staged files", help_heading = "Scan Mode")]
-        staged: bool,
+        full: bool,
+    },
 
+    #[command(about = "Validate configuration file")]
+    Validate {
         #[arg(
-            long,
-            help = "Scan all uncommitted changes (staged + unstaged)",
-            help_heading = "Scan Mode"
+            value_name = "CONFIG_PATH",
+            help = "Path to config file or directory (defaults to .tscanner/config.jsonc)"
         )]
-        uncommitted: bool,
+        config_path: Option<PathBuf>,
+    },
 
+    #[command(about = "List available rules and whether they are enabled")]
+    Rules {
         #[arg(
             long,
-            help = "Include AI rules in the scan (slower)",
-            help_heading = "AI Rules"
+            value_name = "CATEGORY",
+            help = "Filter by category (e.g., 'type_safety', 'imports')"
         )]
-        include_ai: bool,
+        category: Option<String>,
 
-        #[arg(
-            long,
-            help = "Run only AI rules, skip all other rules",
-            help_heading = "AI Rules"
-        )]
-        only_ai: bool,
+        #[arg(long, help = "Show only rules enabled in the current config")]
+        enabled: bool,
 
         #[arg(
             long,
-            value_name = "GLOB_PATTERN",
-            help = "Filter results by glob pattern (e.g., 'src/**/*.ts')",
-            help_heading = "Filtering"
+            value_name = "CONFIG_DIR",
+            help = "Path to config folder (defaults to .tscanner)"
         )]
-        glob: Option<String>,
+        config_path: Option<PathBuf>,
+    },
 
-        #[arg(
-            long,
-            value_name = "RULE_NAME",
-            help = "Filter results to specific rule (e.g., 'no-console')",
-            help_heading = "Filtering"
-        )]
-        rule: Option<String>,
+    #[command(about = "Show details, options and examples for a rule")]
+    Explain {
+        #[arg(value_name = "RULE", help = "Rule name (e.g., 'no-console')")]
+        name: String,
 
         #[arg(
             long,
-            value_enum,
-            value_name = "LEVEL",
-            help = "Filter results by minimum severity (e.g., 'error')",
-            help_heading = "Filtering"
+            value_name = "CONFIG_DIR",
+            help = "Path to config folder (defaults to .tscanner)"
         )]
-        severity: Option<CliSeverity>,
+        config_path: Option<PathBuf>,
+    },
 
-        #[arg(
-            long,
-            value_enum,
-            value_name = "TYPE",
-            help = "Filter results by rule type (e.g., 'builtin')",
-            help_heading = "Filtering"
-        )]
-        kind: Option<CliRuleKind>,
+    #[command(about = "Migrate rules from an ESLint or Biome config")]
+    Migrate {
+        #[arg(long, value_enum, value_name = "TOOL", help = "Linter to migrate from")]
+        from: MigrateSource,
 
         #[arg(
             long,
-            value_enum,
-            value_name = "MODE",
-            help = "Group issues by file or rule",
-            help_heading = "Output"
+            value_name = "FILE",
+            help = "Path to the linter config in JSON form (auto-detected if omitted)"
         )]
-        group_by: Option<CliGroupMode>,
+        input: Option<PathBuf>,
+
+        #[arg(long, help = "Overwrite rules that already exist in the config")]
+        force: bool,
+
+        #[arg(long, help = "Show the migration without writing any files")]
+        dry_run: bool,
 
         #[arg(
             long,
-            value_enum,
-            value_name = "FORMAT",
-            default_value = "text",
-            help = "Output format: text or json",
-            help_heading = "Output"
+            value_name = "CONFIG_DIR",
+            help = "Path to config folder (defaults to .tscanner)"
         )]
-        format: OutputFormat,
+        config_path: Option<PathBuf>,
+    },
 
+    #[command(about = "Show quality trends from recorded scans")]
+    Stats {
         #[arg(
             long,
-            value_name = "FILE",
-            help = "Additionally save JSON output to file (works with any format)",
-            help_heading = "Output"
+            value_name = "N",
+            default_value = "10",
+            help = "Number of recent scans to include"
         )]
-        json_output: Option<PathBuf>,
-
-        #[arg(long, help = "Skip cache and force full scan", help_heading = "Other")]
-        no_cache: bool,
+        limit: usize,
 
         #[arg(
             long,
-            help = "Continue execution even when errors are found",
-            help_heading = "Other"
+            value_name = "FILE",
+            help = "Export the scan history as CSV (use '-' for stdout)"
         )]
-        continue_on_error: bool,
+        csv: Option<PathBuf>,
 
         #[arg(
             long,
             value_name = "CONFIG_DIR",
-            help = "Path to config folder (defaults to .tscanner)",
-            help_heading = "Other"
+            help = "Path to config folder (defaults to .tscanner)"
         )]
         config_path: Option<PathBuf>,
     },
 
-    #[command(about = "Create a default configuration file")]
-    Init {
+    #[command(about = "Run fixture tests for custom rules")]
+    Test {
         #[arg(
-            long,
-            help = "Initialize with all built-in rules, example regex/script/AI rules, and sample files"
+            value_name = "RULE",
+            help = "Only run tests for these rules (runs all if omitted)"
         )]
-        full: bool,
-    },
+        rules: Vec<String>,
+
+        #[arg(long, help = "Write snapshot files for each fixture")]
+        update: bool,
 
-    #[command(about = "Validate configuration file")]
-    Validate {
         #[arg(
-            value_name = "CONFIG_PATH",
-            help = "Path to config file or directory (defaults to .tscanner/config.jsonc)"
+            long,
+            value_name = "CONFIG_DIR",
+            help = "Path to config folder (defaults to .tscanner)"
         )]
         config_path: Option<PathBuf>,
     },
 
+    #[command(about = "Inspect, clean, export or import the scan cache")]
+    Cache {
+        #[command(subcommand)]
+        command: CacheCommand,
+    },
+
     #[command(about = "Start the LSP server (Language Server Protocol)")]
     Lsp,
 
@@ -263,13 +358,351 @@ pub enum Commands {
     },
 }
 
+#[derive(Subcommand)]
+pub enum CacheCommand {
+    #[command(about = "Show cache location, size and entry counts per project")]
+    Info {
+        #[arg(
+            long,
+            value_name = "DIR",
+            help = "Inspect this cache directory instead of every project cache in the user cache folder"
+        )]
+        cache_dir: Option<PathBuf>,
+    },
+
+    #[command(about = "Delete cached results")]
+    Clean {
+        #[arg(
+            long,
+            value_name = "AGE",
+            value_parser = parse_age,
+            help = "Only delete caches not written for this long (e.g., 12h, 7d, 2w)"
+        )]
+        older_than: Option<Duration>,
+
+        #[arg(long, help = "Only delete the cache of the current project")]
+        project: bool,
+
+        #[arg(
+            long,
+            value_name = "DIR",
+            conflicts_with = "project",
+            help = "Clean this cache directory instead of every project cache in the user cache folder"
+        )]
+        cache_dir: Option<PathBuf>,
+    },
+
+    #[command(about = "Write the project cache to a portable archive")]
+    Export {
+        #[arg(value_name = "FILE", help = "Archive file to write")]
+        file: PathBuf,
+
+        #[arg(
+            long,
+            value_name = "DIR",
+            help = "Cache directory to export (defaults to this project's cache in the user cache folder)"
+        )]
+        cache_dir: Option<PathBuf>,
+    },
+
+    #[command(about = "Restore the project cache from an archive")]
+    Import {
+        #[arg(value_name = "FILE", help = "Archive file to read")]
+        file: PathBuf,
+
+        #[arg(
+            long,
+            value_name = "DIR",
+            help = "Cache directory to restore into (defaults to this project's cache in the user cache folder)"
+        )]
+        cache_dir: Option<PathBuf>,
+    },
+}
+
+#[derive(Args)]
+pub struct CheckArgs {
+    #[arg(
+        value_name = "PATH",
+        default_value = ".",
+        help = "Directory or file to scan (extra paths are ignored when --staged is used)",
+        num_args = 0..
+    )]
+    pub paths: Vec<PathBuf>,
+
+    #[arg(
+        long,
+        value_name = "BRANCH",
+        help = "Only show issues in files changed compared to branch (e.g., origin/main)",
+        help_heading = "Scan Mode"
+    )]
+    pub branch: Option<String>,
+
+    #[arg(
+        long,
+        requires = "branch",
+        help = "Diff against the branch tip instead of its merge base with HEAD",
+        help_heading = "Scan Mode"
+    )]
+    pub no_merge_base: bool,
+
+    #[arg(long, help = "Scan only git staged files", help_heading = "Scan Mode")]
+    pub staged: bool,
+
+    #[arg(
+        long,
+        help = "Scan all uncommitted changes (staged + unstaged)",
+        help_heading = "Scan Mode"
+    )]
+    pub uncommitted: bool,
+
+    #[arg(
+        long,
+        value_name = "A..B",
+        help = "Only show issues in lines changed by a commit range, reading files from B",
+        help_heading = "Scan Mode"
+    )]
+    pub range: Option<String>,
+
+    #[arg(
+        long,
+        value_name = "FILE",
+        help = "Only show issues in lines added by a unified diff file (use '-' for stdin)",
+        help_heading = "Scan Mode"
+    )]
+    pub diff: Option<PathBuf>,
+
+    #[arg(
+        long,
+        help = "Watch for file changes and re-run the check on affected files",
+        help_heading = "Scan Mode"
+    )]
+    pub watch: bool,
+
+    #[arg(
+        long,
+        help = "Include AI rules in the scan (slower)",
+        help_heading = "AI Rules"
+    )]
+    pub include_ai: bool,
+
+    #[arg(
+        long,
+        help = "Run only AI rules, skip all other rules",
+        help_heading = "AI Rules"
+    )]
+    pub only_ai: bool,
+
+    #[arg(
+        long,
+        value_name = "GLOB_PATTERN",
+        help = "Filter results by glob pattern (e.g., 'src/**/*.ts')",
+        help_heading = "Filtering"
+    )]
+    pub glob: Option<String>,
+
+    #[arg(
+        long,
+        value_name = "RULE_NAME",
+        help = "Filter results to specific rule (e.g., 'no-console')",
+        help_heading = "Filtering"
+    )]
+    pub rule: Option<String>,
+
+    #[arg(
+        long,
+        value_enum,
+        value_name = "LEVEL",
+        help = "Filter results by minimum severity (e.g., 'error')",
+        help_heading = "Filtering"
+    )]
+    pub severity: Option<CliSeverity>,
+
+    #[arg(
+        long,
+        value_enum,
+        value_name = "TYPE",
+        help = "Filter results by rule type (e.g., 'builtin')",
+        help_heading = "Filtering"
+    )]
+    pub kind: Option<CliRuleKind>,
+
+    #[arg(
+        long,
+        value_name = "EMAIL",
+        help = "Only show issues on lines last changed by this author (implies --blame)",
+        help_heading = "Filtering"
+    )]
+    pub author: Option<String>,
+
+    #[arg(
+        long,
+        value_name = "DATE",
+        value_parser = parse_since_date,
+        help = "Only show issues on lines last changed on or after DATE (YYYY-MM-DD, implies --blame)",
+        help_heading = "Filtering"
+    )]
+    pub since: Option<i64>,
+
+    #[arg(
+        long,
+        value_enum,
+        value_name = "MODE",
+        help = "Group issues by file, rule or author (author implies --blame)",
+        help_heading = "Output"
+    )]
+    pub group_by: Option<CliGroupMode>,
+
+    #[arg(
+        long,
+        value_enum,
+        value_name = "FORMAT",
+        default_value = "text",
+        help = "Output format: text, json, sarif, junit, checkstyle, gitlab, markdown or github",
+        help_heading = "Output"
+    )]
+    pub format: OutputFormat,
+
+    #[arg(
+        long,
+        value_name = "FILE",
+        help = "Additionally save JSON output to file (works with any format)",
+        help_heading = "Output"
+    )]
+    pub json_output: Option<PathBuf>,
+
+    #[arg(
+        long,
+        value_name = "FORMAT=FILE",
+        value_parser = parse_report_target,
+        help = "Additionally save a report to file, repeatable (e.g., 'junit=report.xml')",
+        help_heading = "Output"
+    )]
+    pub report: Vec<ReportTarget>,
+
+    #[arg(
+        long,
+        value_name = "TEMPLATE",
+        help = "Link template for markdown output, with {file}, {line} and {column} placeholders",
+        help_heading = "Output"
+    )]
+    pub link_template: Option<String>,
+
+    #[arg(
+        long,
+        value_name = "LINES",
+        num_args = 0..=1,
+        default_missing_value = "2",
+        help = "Show source code frames around each issue with N context lines (default: 2)",
+        help_heading = "Output"
+    )]
+    pub code_frame: Option<usize>,
+
+    #[arg(
+        long,
+        help = "Annotate each issue with the author, commit and date from git blame",
+        help_heading = "Output"
+    )]
+    pub blame: bool,
+
+    #[arg(
+        long,
+        help = "Append a summary of this scan to .tscanner/history.jsonl",
+        help_heading = "Output"
+    )]
+    pub record: bool,
+
+    #[arg(long, help = "Skip cache and force full scan", help_heading = "Other")]
+    pub no_cache: bool,
+
+    #[arg(
+        long,
+        value_name = "DIR",
+        conflicts_with = "no_cache",
+        help = "Store the scan cache in this directory instead of the user cache folder",
+        help_heading = "Other"
+    )]
+    pub cache_dir: Option<PathBuf>,
+
+    #[arg(
+        long,
+        help = "Continue execution even when errors are found",
+        help_heading = "Other"
+    )]
+    pub continue_on_error: bool,
+
+    #[arg(
+        long,
+        value_name = "CONFIG_DIR",
+        help = "Path to config folder (defaults to .tscanner)",
+        help_heading = "Other"
+    )]
+    pub config_path: Option<PathBuf>,
+}
+
 impl Commands {
     pub fn get_config_path(&self) -> PathBuf {
         match self {
-            Commands::Check { config_path, .. } => config_path
+            Commands::Check(args) => args
+                .config_path
                 .clone()
                 .unwrap_or_else(|| PathBuf::from(config_dir_name())),
             _ => PathBuf::from(config_dir_name()),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_report_target_accepts_format_and_path() {
+        let target = parse_report_target("sarif=out/report.sarif").unwrap();
+        assert_eq!(target.format, ReportFormat::Sarif);
+        assert_eq!(target.path, PathBuf::from("out/report.sarif"));
+
+        let target = parse_report_target("GitLab=gl-code-quality.json").unwrap();
+        assert_eq!(target.format, ReportFormat::Gitlab);
+
+        let target = parse_report_target("junit=a=b.xml").unwrap();
+        assert_eq!(target.format, ReportFormat::Junit);
+        assert_eq!(target.path, PathBuf::from("a=b.xml"));
+    }
+
+    #[test]
+    fn parse_report_target_rejects_invalid_values() {
+        assert!(parse_report_target("sarif")
+            .unwrap_err()
+            .contains("expected FORMAT=FILE"));
+        assert!(parse_report_target("junit=")
+            .unwrap_err()
+            .contains("missing file path"));
+        assert!(parse_report_target("text=out.txt")
+            .unwrap_err()
+            .contains("unknown report format 'text'"));
+        assert!(parse_report_target("pdf=out.pdf")
+            .unwrap_err()
+            .contains("unknown report format 'pdf'"));
+    }
+
+    #[test]
+    fn parse_age_accepts_units() {
+        assert_eq!(parse_age("30m").unwrap(), Duration::from_secs(30 * 60));
+        assert_eq!(parse_age("12h").unwrap(), Duration::from_secs(12 * 60 * 60));
+        assert_eq!(
+            parse_age("2w").unwrap(),
+            Duration::from_secs(14 * 24 * 60 * 60)
+        );
+    }
+
+    #[test]
+    fn parse_age_rejects_invalid_and_overflowing_values() {
+        for value in ["", "7", "d", "7y", "-1d", "99999999999999999w"] {
+            assert!(
+                parse_age(value).unwrap_err().contains("expected an age"),
+                "{}",
+                value
+            );
+        }
+    }
+}
@@ -0,0 +1,219 @@
+use anyhow::{Context, Result};
+use colored::*;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::shared::{fatal_error_and_exit, print_section_title};
+use tscanner_cache::{
+    cache_root, clean_cache_dir, export_archive, import_archive, inspect_cache_dir,
+    list_cache_dirs, project_id, resolve_cache_dir, CacheDirInfo, CleanSummary,
+};
+use tscanner_cli::CacheCommand;
+use tscanner_constants::app_name;
+use tscanner_service::log_info;
+
+pub fn cmd_cache(command: CacheCommand) -> Result<()> {
+    let root = std::env::current_dir()?;
+
+    match command {
+        CacheCommand::Info { cache_dir } => cache_info(&root, cache_dir),
+        CacheCommand::Clean {
+            older_than,
+            project,
+            cache_dir,
+        } => cache_clean(&root, older_than, project, cache_dir),
+        CacheCommand::Export { file, cache_dir } => cache_export(&root, &file, cache_dir),
+        CacheCommand::Import { file, cache_dir } => cache_import(&root, &file, cache_dir),
+    }
+}
+
+fn cache_info(root: &Path, cache_dir: Option<PathBuf>) -> Result<()> {
+    log_info(&format!("cmd_cache info: cache_dir={:?}", cache_dir));
+
+    let (location, dirs) = match cache_dir {
+        Some(dir) => {
+            let info = inspect_cache_dir(&dir);
+            (dir, vec![info])
+        }
+        None => {
+            let location = cache_root().context("Failed to resolve the user cache folder")?;
+            let dirs = list_cache_dirs(&location);
+            (location, dirs)
+        }
+    };
+    let current = project_id(root);
+
+    println!();
+    println!(
+        "{} {}",
+        "Cache location:".cyan().bold(),
+        location.display().to_string().dimmed()
+    );
+
+    let dirs: Vec<&CacheDirInfo> = dirs.iter().filter(|d| !d.files.is_empty()).collect();
+    if dirs.is_empty() {
+        println!();
+        println!("  {}", "The cache is empty".dimmed());
+        println!();
+        return Ok(());
+    }
+
+    for dir in &dirs {
+        println!();
+        let label = if dir.path == location {
+            "Legacy files".to_string()
+        } else if dir.name() == current {
+            format!("{} (current project)", dir.name())
+        } else {
+            dir.name()
+        };
+        print_section_title(&format!("{} - {}", label, format_size(dir.bytes())));
+
+        for file in &dir.files {
+            let entries = match (file.kind, file.entries) {
+                (Some(_), Some(count)) => format!("{} entries", count),
+                (Some(_), None) => "unreadable".to_string(),
+                (None, _) => "legacy".to_string(),
+            };
+            println!(
+                "  {:<8} {:>12}  {:>9}  {}",
+                file.kind.unwrap_or("-"),
+                entries,
+                format_size(file.bytes),
+                file.name.dimmed()
+            );
+        }
+    }
+
+    let total: u64 = dirs.iter().map(|d| d.bytes()).sum();
+    println!();
+    println!(
+        "{} {}",
+        "Total:".cyan().bold(),
+        format!("{} cache folders, {}", dirs.len(), format_size(total)).dimmed()
+    );
+    println!();
+
+    Ok(())
+}
+
+fn cache_clean(
+    root: &Path,
+    older_than: Option<Duration>,
+    project: bool,
+    cache_dir: Option<PathBuf>,
+) -> Result<()> {
+    log_info(&format!(
+        "cmd_cache clean: older_than={:?}, project={}, cache_dir={:?}",
+        older_than, project, cache_dir
+    ));
+
+    let targets: Vec<PathBuf> = match cache_dir {
+        Some(dir) => vec![dir],
+        None if project => vec![cache_dir_for(root, None)?],
+        None => {
+            let location = cache_root().context("Failed to resolve the user cache folder")?;
+            list_cache_dirs(&location)
+                .into_iter()
+                .map(|d| d.path)
+                .collect()
+        }
+    };
+
+    let mut summary = CleanSummary::default();
+    for target in &targets {
+        let cleaned = clean_cache_dir(target, older_than);
+        summary.files += cleaned.files;
+        summary.bytes += cleaned.bytes;
+    }
+
+    println!();
+    if summary.files == 0 {
+        println!("{}", "Nothing to clean".dimmed());
+    } else {
+        println!(
+            "{}",
+            format!(
+                "Removed {} cache files ({})",
+                summary.files,
+                format_size(summary.bytes)
+            )
+            .green()
+        );
+    }
+    println!();
+
+    Ok(())
+}
+
+fn cache_export(root: &Path, file: &Path, cache_dir: Option<PathBuf>) -> Result<()> {
+    log_info(&format!(
+        "cmd_cache export: file={:?}, cache_dir={:?}",
+        file, cache_dir
+    ));
+
+    let cache_dir = cache_dir_for(root, cache_dir)?;
+    let summary = export_archive(&cache_dir, file).unwrap_or_else(|e| {
+        fatal_error_and_exit(
+            &e.to_string(),
+            &[&format!(
+                "Run '{} check' to populate the cache first.",
+                app_name()
+            )],
+        )
+    });
+
+    println!();
+    println!(
+        "{}",
+        format!(
+            "Exported {} to {} ({})",
+            summary.caches.join(", "),
+            file.display(),
+            format_size(summary.bytes as u64)
+        )
+        .green()
+    );
+    println!();
+
+    Ok(())
+}
+
+fn cache_import(root: &Path, file: &Path, cache_dir: Option<PathBuf>) -> Result<()> {
+    log_info(&format!(
+        "cmd_cache import: file={:?}, cache_dir={:?}",
+        file, cache_dir
+    ));
+
+    let cache_dir = cache_dir_for(root, cache_dir)?;
+    let summary = import_archive(file, &cache_dir)
+        .unwrap_or_else(|e| fatal_error_and_exit(&e.to_string(), &[]));
+
+    println!();
+    println!(
+        "{}",
+        format!(
+            "Imported {} into {}",
+            summary.caches.join(", "),
+            cache_dir.display()
+        )
+        .green()
+    );
+    println!();
+
+    Ok(())
+}
+
+fn cache_dir_for(root: &Path, cache_dir: Option<PathBuf>) -> Result<PathBuf> {
+    resolve_cache_dir(root, cache_dir.as_deref()).context("Failed to resolve the cache directory")
+}
+
+fn format_size(bytes: u64) -> String {
+    if bytes < 1024 {
+        format!("{} B", bytes)
+    } else if bytes < 1024 * 1024 {
+        format!("{:.1} KB", bytes as f64 / 1024.0)
+    } else {
+        format!("{:.1} MB", bytes as f64 / (1024.0 * 1024.0))
+    }
+}
@@ -0,0 +1,110 @@
+use anyhow::Result;
+use rayon::prelude::*;
+use std::collections::{BTreeSet, HashMap};
+use std::path::Path;
+use std::time::Instant;
+use time::OffsetDateTime;
+use tscanner_scanner::GitContentSource;
+use tscanner_service::{log_error, log_info};
+use tscanner_types::{IssueBlame, ScanResult};
+
+use super::git::{self, BlameInfo};
+
+const MAX_LISTED_FAILURES: usize = 3;
+
+fn blame_file(
+    root: &Path,
+    file: &Path,
+    lines: &BTreeSet<usize>,
+    content_source: Option<&GitContentSource>,
+) -> Result<HashMap<usize, BlameInfo>> {
+    match content_source {
+        None => git::get_blame(root, file, lines, None),
+        Some(GitContentSource::Revision(revision)) => {
+            git::get_blame(root, file, lines, Some(revision))
+        }
+        Some(source @ GitContentSource::Index) => {
+            let contents = source.read(root, file)?;
+            git::get_blame_for_contents(root, file, lines, &contents)
+        }
+    }
+}
+
+pub fn annotate_blame(
+    root: &Path,
+    result: &mut ScanResult,
+    content_source: Option<&GitContentSource>,
+) {
+    let start = Instant::now();
+
+    let mut failures: Vec<String> = result
+        .files
+        .par_iter_mut()
+        .filter_map(|file_result| {
+            let lines: BTreeSet<usize> = file_result
+                .issues
+                .iter()
+                .map(|issue| issue.line)
+                .filter(|line| *line > 0)
+                .collect();
+
+            match blame_file(root, &file_result.file, &lines, content_source) {
+                Ok(blame) => {
+                    for issue in &mut file_result.issues {
+                        issue.blame = blame.get(&issue.line).map(to_issue_blame);
+                    }
+                    None
+                }
+                Err(e) => {
+                    log_error(&format!(
+                        "blame: Failed for {}: {}",
+                        file_result.file.display(),
+                        e
+                    ));
+                    let relative = pathdiff::diff_paths(&file_result.file, root)
+                        .unwrap_or_else(|| file_result.file.clone());
+                    Some(relative.display().to_string())
+                }
+            }
+        })
+        .collect();
+
+    log_info(&format!(
+        "blame: Annotated {} files in {}ms ({} failed)",
+        result.files.len(),
+        start.elapsed().as_millis(),
+        failures.len()
+    ));
+
+    if failures.is_empty() {
+        return;
+    }
+
+    failures.sort();
+    let total = failures.len();
+    let mut listed = failures
+        .into_iter()
+        .take(MAX_LISTED_FAILURES)
+        .collect::<Vec<_>>()
+        .join(", ");
+    if total > MAX_LISTED_FAILURES {
+        listed.push_str(&format!(" and {} more", total - MAX_LISTED_FAILURES));
+    }
+    result.warnings.push(format!(
+        "Could not blame {} files (untracked or unreadable): {}",
+        total, listed
+    ));
+}
+
+fn to_issue_blame(info: &BlameInfo) -> IssueBlame {
+    let date = OffsetDateTime::from_unix_timestamp(info.timestamp)
+        .map(|d| d.date().to_string())
+        .unwrap_or_default();
+    IssueBlame {
+        author: info.author.clone(),
+        email: info.email.clone(),
+        commit: info.commit.clone(),
+        date,
+        timestamp: info.timestamp,
+    }
+}
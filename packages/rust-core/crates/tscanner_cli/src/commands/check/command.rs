@@ -2,7 +2,7 @@ use anyhow::{Context, Result};
 use colored::*;
 use std::collections::{HashMap, HashSet};
 use std::fs;
-use std::io::{self, Write};
+use std::io::{self, Read, Write};
 use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex};
 
@@ -13,34 +13,44 @@ use crate::shared::{
     SummaryStats,
 };
 use tscanner_cache::{AiCache, FileCache, ScriptCache};
-use tscanner_cli::{CliGroupMode, CliRuleKind, CliSeverity, OutputFormat};
+use tscanner_cli::{CliGroupMode, CliRuleKind, CliSeverity, OutputFormat, ReportTarget};
 use tscanner_cli_output::GroupMode;
-use tscanner_config::{AiExecutionMode, AiProvider};
+use tscanner_cli_output::ReportFormat;
+use tscanner_config::{AiExecutionMode, AiProvider, TscannerConfig};
 use tscanner_constants::{
     app_name, config_dir_name, config_file_name, icon_error, icon_progress, icon_skipped,
     icon_success, is_dev_mode,
 };
 use tscanner_scanner::{
-    AiProgressCallback, AiProgressEvent, AiRuleStatus, ConfigExt, RegularRulesCompleteCallback,
-    ScanCallbacks, Scanner,
+    AiProgressCallback, AiProgressEvent, AiRuleStatus, ConfigExt, GitContentSource,
+    RegularRulesCompleteCallback, ScanCallbacks, Scanner,
 };
 use tscanner_service::{log_error, log_info};
 use tscanner_types::enums::IssueRuleType;
 use tscanner_types::enums::Severity;
 use tscanner_types::ScanResult;
 
+use super::blame;
 use super::context::CheckContext;
-use super::filters;
+use super::filters::{self, ResultFilters};
 use super::git;
 use super::output;
+use super::record;
+use super::watch::{self, WatchSession};
 
 type ModifiedLinesMap = HashMap<PathBuf, HashSet<usize>>;
+type BranchChanges = (
+    Option<String>,
+    Option<HashSet<PathBuf>>,
+    Option<ModifiedLinesMap>,
+);
 
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
 pub enum CliGroupBy {
     #[default]
     File,
     Rule,
+    Author,
 }
 
 #[derive(Debug, Clone)]
@@ -48,6 +58,7 @@ pub struct CliOptions {
     pub group_by: CliGroupBy,
     pub show_settings: bool,
     pub show_summary: bool,
+    pub code_frame: Option<usize>,
 }
 
 impl Default for CliOptions {
@@ -56,6 +67,7 @@ impl Default for CliOptions {
             group_by: CliGroupBy::File,
             show_settings: true,
             show_summary: true,
+            code_frame: None,
         }
     }
 }
@@ -64,35 +76,120 @@ impl Default for CliOptions {
 pub fn cmd_check(
     paths: &[PathBuf],
     no_cache: bool,
+    cache_dir: Option<PathBuf>,
     group_by: Option<CliGroupMode>,
     format: Option<OutputFormat>,
     json_output: Option<PathBuf>,
+    report: Vec<ReportTarget>,
+    link_template: Option<String>,
+    code_frame: Option<usize>,
+    blame: bool,
     branch: Option<String>,
+    no_merge_base: bool,
     staged: bool,
     uncommitted: bool,
+    range: Option<String>,
+    diff: Option<PathBuf>,
     glob_filter: Option<String>,
     rule_filter: Option<String>,
     severity_filter: Option<CliSeverity>,
     kind_filter: Option<CliRuleKind>,
+    author_filter: Option<String>,
+    since_filter: Option<i64>,
     continue_on_error: bool,
     include_ai: bool,
     only_ai: bool,
     config_path: Option<PathBuf>,
+    watch: bool,
+    record: bool,
 ) -> Result<()> {
-    let mode_flags = [staged, uncommitted, branch.is_some()]
-        .iter()
-        .filter(|&&x| x)
-        .count();
+    let mode_flags = [
+        staged,
+        uncommitted,
+        branch.is_some(),
+        range.is_some(),
+        diff.is_some(),
+    ]
+    .iter()
+    .filter(|&&x| x)
+    .count();
     if mode_flags > 1 {
         fatal_error_and_exit(
-            "--staged, --uncommitted, and --branch are mutually exclusive",
+            "--staged, --uncommitted, --branch, --range and --diff are mutually exclusive",
             &[],
         );
     }
+    if range.is_some() && (include_ai || only_ai) {
+        fatal_error_and_exit(
+            "--range does not support AI rules",
+            &["AI rules read files from the working tree"],
+        );
+    }
 
     let output_format = format.unwrap_or_default();
+    let blame = blame
+        || author_filter.is_some()
+        || since_filter.is_some()
+        || matches!(group_by, Some(CliGroupMode::Author));
+
+    if watch {
+        if mode_flags > 0 {
+            fatal_error_and_exit(
+                "--watch cannot be combined with --staged, --uncommitted, --branch, --range or --diff",
+                &[],
+            );
+        }
+        if include_ai || only_ai {
+            fatal_error_and_exit(
+                "--watch does not support AI rules",
+                &["Run AI rules separately with --include-ai or --only-ai"],
+            );
+        }
+        if output_format.is_machine_readable() {
+            fatal_error_and_exit("--watch only supports text output", &[]);
+        }
+        if blame {
+            fatal_error_and_exit(
+                "--watch does not support --blame, --author, --since or --group-by author",
+                &[],
+            );
+        }
+    }
+    if record && mode_flags > 0 {
+        fatal_error_and_exit(
+            "--record cannot be combined with --staged, --uncommitted, --branch, --range or --diff",
+            &["Recorded scans must cover the whole project to be comparable"],
+        );
+    }
+    let has_result_filters = glob_filter.is_some()
+        || rule_filter.is_some()
+        || severity_filter.is_some()
+        || kind_filter.is_some()
+        || author_filter.is_some()
+        || since_filter.is_some();
+    if record && has_result_filters {
+        fatal_error_and_exit(
+            "--record cannot be combined with --glob, --rule, --severity, --kind, --author or --since",
+            &["Recorded scans must cover the whole project to be comparable"],
+        );
+    }
+
+    let report_targets: Vec<ReportTarget> = json_output
+        .map(|path| ReportTarget {
+            format: ReportFormat::Json,
+            path,
+        })
+        .into_iter()
+        .chain(report)
+        .collect();
 
     let root = fs::canonicalize(".").context("Failed to resolve current directory")?;
+    if blame && git::get_head_commit(&root).is_err() {
+        fatal_error_and_exit(
+            "--blame requires a git repository with at least one commit",
+            &[],
+        );
+    }
     let scan_paths: Vec<PathBuf> = if staged || uncommitted {
         vec![root.clone()]
     } else {
@@ -116,7 +213,7 @@ pub fn cmd_check(
     ));
 
     let (config, resolved_config_path, mut config_warnings) =
-        match load_config_with_custom(&root, config_path) {
+        match load_config_with_custom(&root, config_path.clone()) {
             Ok(Some((cfg, config_file_path, warnings))) => {
                 log_info(&format!(
                     "cmd_check: Config loaded successfully from: {}",
@@ -162,11 +259,11 @@ pub fn cmd_check(
         config_warnings.push(warning);
     }
 
-    let cli_options = build_cli_options(group_by);
+    let rule_descriptors = output::build_rule_descriptors(&config);
+    let cli_options = build_cli_options(group_by, code_frame);
     let effective_group_mode = resolve_group_mode(&cli_options);
     let effective_ai_mode = resolve_ai_mode(include_ai, only_ai);
 
-    let config_hash = config.compute_hash();
     let ai_provider = config.ai.as_ref().and_then(|ai| ai.provider);
 
     if ai_provider.is_none() && effective_ai_mode != AiExecutionMode::Ignore {
@@ -207,36 +304,18 @@ pub fn cmd_check(
         + rules_breakdown.regex
         + rules_breakdown.script
         + rules_breakdown.ai;
-    let (cache, ai_cache, script_cache) = if no_cache {
-        (
-            Arc::new(FileCache::new()),
-            Arc::new(AiCache::new()),
-            Arc::new(ScriptCache::new()),
-        )
-    } else {
-        (
-            Arc::new(FileCache::with_config_hash(config_hash)),
-            Arc::new(AiCache::with_config_hash(config_hash)),
-            Arc::new(ScriptCache::with_config_hash(config_hash)),
-        )
-    };
-
-    let config_dir = Path::new(&resolved_config_path)
-        .parent()
-        .map(|p| p.to_path_buf())
-        .unwrap_or_else(|| root.clone());
-    let scanner = Scanner::with_caches_and_config_dir(
+    let scanner = build_scanner(
         config,
-        cache,
-        ai_cache,
-        script_cache,
-        root.clone(),
-        config_dir,
-    )
-    .map_err(|e| anyhow::anyhow!("{}", e))?;
+        &resolved_config_path,
+        &root,
+        no_cache,
+        cache_dir.as_deref(),
+    )?;
 
-    let is_json = matches!(output_format, OutputFormat::Json);
+    let is_machine_readable = output_format.is_machine_readable();
 
+    let mut content_source: Option<GitContentSource> = None;
+    let mut scan_warnings: Vec<String> = Vec::new();
     let (files_to_scan, modified_lines, scan_mode) = if staged {
         let staged_files = git::get_staged_files(&root)?;
         let staged_lines = git::get_staged_modified_lines(&root)?;
@@ -247,6 +326,7 @@ pub fn cmd_check(
             glob_filter.as_deref(),
             Some(staged_files),
         );
+        content_source = Some(GitContentSource::Index);
         (files, Some(staged_lines), ScanMode::Staged { file_count })
     } else if uncommitted {
         let uncommitted_files = git::get_uncommitted_files(&root)?;
@@ -267,7 +347,8 @@ pub fn cmd_check(
             ScanMode::Uncommitted { file_count },
         )
     } else if let Some(ref branch_name) = branch {
-        let (changed_files, modified_lines) = get_branch_changes(&root, branch_name)?;
+        let (merge_base, changed_files, modified_lines) =
+            get_branch_changes(&root, branch_name, !no_merge_base)?;
         let file_count = changed_files.as_ref().map_or(0, |f| f.len());
         let files =
             filters::get_files_to_scan_multi(&scan_paths, glob_filter.as_deref(), changed_files);
@@ -276,6 +357,56 @@ pub fn cmd_check(
             modified_lines,
             ScanMode::Branch {
                 name: branch_name.clone(),
+                merge_base,
+                file_count,
+            },
+        )
+    } else if let Some(ref range_spec) = range {
+        let (revision_range, changed_files, range_lines) = get_range_changes(&root, range_spec);
+        let changed_files: HashSet<PathBuf> = changed_files
+            .into_iter()
+            .filter(|f| scan_paths.iter().any(|p| f.starts_with(p)))
+            .collect();
+        let file_count = changed_files.len();
+        let files = filters::get_files_to_scan_multi(
+            &scan_paths,
+            glob_filter.as_deref(),
+            Some(changed_files),
+        );
+        content_source = Some(GitContentSource::Revision(revision_range.head));
+        (
+            files,
+            Some(range_lines),
+            ScanMode::Range {
+                spec: range_spec.clone(),
+                file_count,
+            },
+        )
+    } else if let Some(ref diff_source) = diff {
+        let diff_lines = get_diff_changes(&root, diff_source)?;
+        let changed_files: HashSet<PathBuf> = diff_lines
+            .keys()
+            .filter(|f| f.is_file() && scan_paths.iter().any(|p| f.starts_with(p)))
+            .cloned()
+            .collect();
+        let missing = diff_lines.keys().filter(|f| !f.exists()).count();
+        if missing > 0 {
+            scan_warnings.push(format!(
+                "Diff references {} files that do not exist in the working tree",
+                missing
+            ));
+        }
+        let file_count = changed_files.len();
+        let files = filters::get_files_to_scan_multi(
+            &scan_paths,
+            glob_filter.as_deref(),
+            Some(changed_files),
+        );
+        (
+            files,
+            Some(diff_lines),
+            ScanMode::Diff {
+                source: diff_source.display().to_string(),
                 file_count,
             },
         )
@@ -284,7 +415,7 @@ pub fn cmd_check(
         (files, None, ScanMode::Codebase)
     };
 
-    if !is_json {
+    if !is_machine_readable {
         let relative_config_path = pathdiff::diff_paths(&resolved_config_path, &root)
             .map(|p| p.display().to_string())
             .unwrap_or(resolved_config_path.clone());
@@ -316,7 +447,7 @@ pub fn cmd_check(
         .unwrap_or(false);
 
     let regular_rules_callback: Option<RegularRulesCompleteCallback> =
-        if !is_json && regular_rules_count > 0 && !scan_skipped {
+        if !is_machine_readable && regular_rules_count > 0 && !scan_skipped {
             let count = regular_rules_count;
             Some(Arc::new(move |duration_ms: u128| {
                 render_rules_status("Regular rules", count, RuleStatus::Completed(duration_ms));
@@ -327,7 +458,7 @@ pub fn cmd_check(
         };
 
     let ai_progress_callback: Option<AiProgressCallback> =
-        if effective_ai_mode != AiExecutionMode::Ignore && !is_json && !scan_skipped {
+        if effective_ai_mode != AiExecutionMode::Ignore && !is_machine_readable && !scan_skipped {
             let rule_states: Arc<Mutex<HashMap<usize, (String, AiRuleStatus)>>> =
                 Arc::new(Mutex::new(HashMap::new()));
             let has_rendered = Arc::new(Mutex::new(false));
@@ -356,26 +487,40 @@ pub fn cmd_check(
             None
         };
 
-    let mut result = scanner.scan_codebase_with_callbacks(
-        &scan_paths,
-        files_to_scan.as_ref(),
-        effective_ai_mode,
-        modified_lines.as_ref(),
-        ScanCallbacks {
-            on_regular_rules_complete: regular_rules_callback,
-            on_ai_progress: ai_progress_callback,
-        },
-    );
+    let mut result = match content_source {
+        Some(ref source) => scanner.scan_git_contents_with_callbacks(
+            source,
+            &files_to_scan.clone().unwrap_or_default(),
+            effective_ai_mode,
+            modified_lines.as_ref(),
+            ScanCallbacks {
+                on_regular_rules_complete: regular_rules_callback,
+                on_ai_progress: ai_progress_callback,
+            },
+        ),
+        None => scanner.scan_codebase_with_callbacks(
+            &scan_paths,
+            files_to_scan.as_ref(),
+            effective_ai_mode,
+            modified_lines.as_ref(),
+            ScanCallbacks {
+                on_regular_rules_complete: regular_rules_callback,
+                on_ai_progress: ai_progress_callback,
+            },
+        ),
+    };
 
     for warning in config_warnings {
         result.warnings.push(warning);
     }
+    result.warnings.extend(scan_warnings);
 
     if scan_skipped {
         result.notes.push(
-            "Scan skipped: no files to analyze (staged/branch has no matching files)".to_string(),
+            "Scan skipped: no files to analyze (staged/branch/range/diff has no matching files)"
+                .to_string(),
         );
-        if !is_json {
+        if !is_machine_readable {
             if regular_rules_count > 0 {
                 render_rules_status("Regular rules", regular_rules_count, RuleStatus::Skipped);
             }
@@ -383,7 +528,7 @@ pub fn cmd_check(
                 render_rules_status("AI rules", rules_breakdown.ai, RuleStatus::Skipped);
             }
         }
-    } else if !is_json && rules_breakdown.ai > 0 {
+    } else if !is_machine_readable && rules_breakdown.ai > 0 {
         let ai_status = if !result.errors.is_empty() {
             RuleStatus::Error(result.ai_rules_duration_ms)
         } else {
@@ -396,28 +541,17 @@ pub fn cmd_check(
         filters::apply_line_filter(&mut result, line_filter);
     }
 
-    if let Some(ref rule_name) = rule_filter {
-        filters::apply_rule_filter(&mut result, rule_name);
-    }
+    let result_filters = build_result_filters(rule_filter, severity_filter, kind_filter);
+    result_filters.apply(&mut result);
 
-    if let Some(ref sev) = severity_filter {
-        let severity = match sev {
-            CliSeverity::Error => Severity::Error,
-            CliSeverity::Warning => Severity::Warning,
-            CliSeverity::Info => Severity::Info,
-            CliSeverity::Hint => Severity::Hint,
-        };
-        filters::apply_severity_filter(&mut result, severity);
-    }
-
-    if let Some(ref kind) = kind_filter {
-        let rule_type = match kind {
-            CliRuleKind::Builtin => IssueRuleType::Builtin,
-            CliRuleKind::Regex => IssueRuleType::CustomRegex,
-            CliRuleKind::Script => IssueRuleType::CustomScript,
-            CliRuleKind::Ai => IssueRuleType::Ai,
-        };
-        filters::apply_rule_type_filter(&mut result, rule_type);
+    if blame {
+        blame::annotate_blame(&root, &mut result, content_source.as_ref());
+        if let Some(ref author) = author_filter {
+            filters::apply_author_filter(&mut result, author);
+        }
+        if let Some(since) = since_filter {
+            filters::apply_since_filter(&mut result, since);
+        }
     }
 
     log_info(&format!(
@@ -428,12 +562,16 @@ pub fn cmd_check(
 
     let stats = SummaryStats::from_result(&result, total_enabled_rules, rules_breakdown);
 
-    if result.files.is_empty() && !is_json {
-        let formatted_output = match effective_group_mode {
-            GroupMode::File => FormattedOutput::build_by_file(&root, &result, &stats),
-            GroupMode::Rule => FormattedOutput::build_by_rule(&root, &result, &stats),
-        };
+    let formatted_output = match effective_group_mode {
+        GroupMode::File => FormattedOutput::build_by_file(&root, &result, &stats),
+        GroupMode::Rule => FormattedOutput::build_by_rule(&root, &result, &stats),
+        GroupMode::Author => FormattedOutput::build_by_author(&root, &result, &stats),
+    };
+
+    let ctx = CheckContext::new(cli_options, root.clone(), rule_descriptors, link_template)
+        .with_content_source(content_source);
 
+    if result.files.is_empty() && !is_machine_readable {
         println!();
         print_section_title("Results:");
         println!();
@@ -444,33 +582,45 @@ pub fn cmd_check(
         if result.notes.is_empty() && result.warnings.is_empty() && result.errors.is_empty() {
             println!();
         }
-        if cli_options.show_summary {
+        if ctx.cli_options.show_summary {
             output::render_summary(formatted_output.summary());
         }
+    } else {
+        let renderer = output::get_renderer(&output_format);
+        renderer.render(&ctx, &formatted_output, &result);
+    }
 
-        if let Some(ref json_path) = json_output {
-            write_json_output(json_path, &formatted_output)?;
-        }
-
-        if !result.errors.is_empty() && !continue_on_error {
-            std::process::exit(1);
+    output::write_reports(&report_targets, &ctx, &formatted_output, &result)?;
+
+    if record {
+        let history_file = record::record_scan(
+            &root,
+            &resolved_config_path,
+            &result,
+            formatted_output.summary(),
+        )?;
+        if !is_machine_readable {
+            let relative = pathdiff::diff_paths(&history_file, &root).unwrap_or(history_file);
+            println!(
+                "{}",
+                format!("Scan recorded to {}", relative.display()).dimmed()
+            );
         }
-
-        return Ok(());
     }
 
-    let formatted_output = match effective_group_mode {
-        GroupMode::File => FormattedOutput::build_by_file(&root, &result, &stats),
-        GroupMode::Rule => FormattedOutput::build_by_rule(&root, &result, &stats),
-    };
-
-    let ctx = CheckContext::new(cli_options);
-
-    let renderer = output::get_renderer(&output_format);
-    renderer.render(&ctx, &formatted_output, &result);
-
-    if let Some(ref json_path) = json_output {
-        write_json_output(json_path, &formatted_output)?;
+    if watch {
+        return watch::run_watch(WatchSession {
+            root,
+            scan_paths,
+            config_path,
+            resolved_config_path,
+            no_cache,
+            cache_dir,
+            glob_filter,
+            result_filters,
+            scanner,
+            initial_result: result,
+        });
     }
 
     log_info(&format!(
@@ -486,28 +636,87 @@ pub fn cmd_check(
     Ok(())
 }
 
-fn write_json_output(json_path: &Path, output: &FormattedOutput) -> Result<()> {
-    if let Some(json_str) = output.to_json() {
-        fs::write(json_path, json_str)
-            .context(format!("Failed to write JSON output to {:?}", json_path))?;
-        log_info(&format!(
-            "cmd_check: JSON output written to {:?}",
-            json_path
-        ));
-    }
-    Ok(())
-}
-
 fn render_scan_messages(result: &ScanResult) {
     render_messages(&result.notes, &result.warnings, &result.errors);
 }
 
-fn build_cli_options(group_by: Option<CliGroupMode>) -> CliOptions {
-    let mut options = CliOptions::default();
+pub(super) fn build_scanner(
+    config: TscannerConfig,
+    resolved_config_path: &str,
+    root: &Path,
+    no_cache: bool,
+    cache_dir: Option<&Path>,
+) -> Result<Scanner> {
+    let (cache, ai_cache, script_cache) = if no_cache {
+        (
+            Arc::new(FileCache::new()),
+            Arc::new(AiCache::new()),
+            Arc::new(ScriptCache::new()),
+        )
+    } else {
+        (
+            Arc::new(
+                FileCache::for_project(root, cache_dir, config.file_rule_fingerprints())
+                    .with_limits(config.cache_limits()),
+            ),
+            Arc::new(
+                AiCache::for_project(root, cache_dir, config.ai_rule_fingerprints())
+                    .with_limits(config.cache_limits()),
+            ),
+            Arc::new(
+                ScriptCache::for_project(root, cache_dir, config.script_rule_fingerprints())
+                    .with_limits(config.cache_limits()),
+            ),
+        )
+    };
+
+    let config_dir = Path::new(resolved_config_path)
+        .parent()
+        .map(|p| p.to_path_buf())
+        .unwrap_or_else(|| root.to_path_buf());
+    Scanner::with_caches_and_config_dir(
+        config,
+        cache,
+        ai_cache,
+        script_cache,
+        root.to_path_buf(),
+        config_dir,
+    )
+    .map_err(|e| anyhow::anyhow!("{}", e))
+}
+
+fn build_result_filters(
+    rule_filter: Option<String>,
+    severity_filter: Option<CliSeverity>,
+    kind_filter: Option<CliRuleKind>,
+) -> ResultFilters {
+    ResultFilters {
+        rule: rule_filter,
+        severity: severity_filter.map(|sev| match sev {
+            CliSeverity::Error => Severity::Error,
+            CliSeverity::Warning => Severity::Warning,
+            CliSeverity::Info => Severity::Info,
+            CliSeverity::Hint => Severity::Hint,
+        }),
+        rule_type: kind_filter.map(|kind| match kind {
+            CliRuleKind::Builtin => IssueRuleType::Builtin,
+            CliRuleKind::Regex => IssueRuleType::CustomRegex,
+            CliRuleKind::Script => IssueRuleType::CustomScript,
+            CliRuleKind::Ai => IssueRuleType::Ai,
+        }),
+    }
+}
+
+fn build_cli_options(group_by: Option<CliGroupMode>, code_frame: Option<usize>) -> CliOptions {
+    let mut options = CliOptions {
+        code_frame,
+        ..Default::default()
+    };
     if let Some(g) = group_by {
         options.group_by = match g {
             CliGroupMode::Rule => CliGroupBy::Rule,
             CliGroupMode::File => CliGroupBy::File,
+            CliGroupMode::Author => CliGroupBy::Author,
         };
     }
     options
@@ -517,6 +726,7 @@ fn resolve_group_mode(cli_options: &CliOptions) -> GroupMode {
     match cli_options.group_by {
         CliGroupBy::Rule => GroupMode::Rule,
         CliGroupBy::File => GroupMode::File,
+        CliGroupBy::Author => GroupMode::Author,
     }
 }
 
@@ -533,18 +743,60 @@ fn resolve_ai_mode(include_ai_flag: bool, only_ai_flag: bool) -> AiExecutionMode
 fn get_branch_changes(
     root: &Path,
     branch_name: &str,
-) -> Result<(Option<HashSet<PathBuf>>, Option<ModifiedLinesMap>)> {
+    use_merge_base: bool,
+) -> Result<BranchChanges> {
+    let merge_base = if use_merge_base {
+        match git::get_merge_base(root, "HEAD", branch_name) {
+            Ok(sha) => Some(sha),
+            Err(e) => fatal_error_and_exit(
+                &format!("Error finding merge base with {}: {}", branch_name, e),
+                &["Use --no-merge-base to diff against the branch tip instead"],
+            ),
+        }
+    } else {
+        None
+    };
+    let base = merge_base.as_deref().unwrap_or(branch_name);
+
+    match (
+        git::get_changed_files(root, base),
+        git::get_modified_lines(root, base),
+    ) {
+        (Ok(files), Ok(lines)) => {
+            log_info(&format!(
+                "cmd_check: Found {} changed files vs {} (base: {})",
+                files.len(),
+                branch_name,
+                base
+            ));
+            Ok((merge_base, Some(files), Some(lines)))
+        }
+        (Err(e), _) | (_, Err(e)) => {
+            fatal_error_and_exit(&format!("Error getting changed files: {}", e), &[]);
+        }
+    }
+}
+
+fn get_range_changes(
+    root: &Path,
+    range_spec: &str,
+) -> (git::RevisionRange, HashSet<PathBuf>, ModifiedLinesMap) {
+    let revision_range = git::resolve_range(root, range_spec)
+        .unwrap_or_else(|e| fatal_error_and_exit(&format!("Error resolving range: {}", e), &[]));
+
     match (
-        git::get_changed_files(root, branch_name),
-        git::get_modified_lines(root, branch_name),
+        git::get_range_changed_files(root, &revision_range),
+        git::get_range_modified_lines(root, &revision_range),
     ) {
         (Ok(files), Ok(lines)) => {
             log_info(&format!(
-                "cmd_check: Found {} changed files vs {}",
+                "cmd_check: Found {} changed files in {} ({}..{})",
                 files.len(),
-                branch_name
+                range_spec,
+                revision_range.base,
+                revision_range.head
             ));
-            Ok((Some(files), Some(lines)))
+            (revision_range, files, lines)
         }
         (Err(e), _) | (_, Err(e)) => {
             fatal_error_and_exit(&format!("Error getting changed files: {}", e), &[]);
@@ -552,6 +804,30 @@ fn get_branch_changes(
     }
 }
 
+fn get_diff_changes(root: &Path, diff_source: &Path) -> Result<ModifiedLinesMap> {
+    let diff_text = if diff_source.as_os_str() == "-" {
+        let mut buffer = String::new();
+        io::stdin()
+            .read_to_string(&mut buffer)
+            .context("Failed to read diff from stdin")?;
+        buffer
+    } else {
+        fs::read_to_string(diff_source)
+            .context(format!("Failed to read diff: {}", diff_source.display()))?
+    };
+
+    let lines: ModifiedLinesMap = git::parse_modified_lines(&diff_text)
+        .into_iter()
+        .map(|(file, lines)| (root.join(file), lines))
+        .collect();
+    log_info(&format!(
+        "cmd_check: Found {} files in diff {}",
+        lines.len(),
+        diff_source.display()
+    ));
+    Ok(lines)
+}
+
 fn render_ai_progress(
     states: &HashMap<usize, (String, AiRuleStatus)>,
     total: usize,
@@ -580,10 +856,39 @@ fn render_ai_progress(
             eprint!("\x1B[1A");
             eprint!("\x1B[0J");
         }
+        let (done_batches, total_batches) = states
+            .values()
+            .filter_map(|(_, s)| match s {
+                AiRuleStatus::Running {
+                    completed_batches,
+                    total_batches,
+                } if *total_batches > 1 => Some((*completed_batches, *total_batches)),
+                _ => None,
+            })
+            .fold((0, 0), |(done, all), (d, a)| (done + d, all + a));
+        let batches = if total_batches > 0 {
+            format!(" - batches {}/{}", done_batches, total_batches)
+        } else {
+            String::new()
+        };
+        let retrying = states
+            .values()
+            .filter_map(|(_, s)| match s {
+                AiRuleStatus::Retrying {
+                    attempt,
+                    max_retries,
+                } => Some((*attempt, *max_retries)),
+                _ => None,
+            })
+            .max()
+            .map(|(attempt, max_retries)| format!(" - retrying ({}/{})", attempt, max_retries))
+            .unwrap_or_default();
         eprintln!(
             "{} {} {}",
             icon_progress(),
-            format!("AI rules ({}/{})", completed, total).cyan().bold(),
+            format!("AI rules ({}/{}){}{}", completed, total, batches, retrying)
+                .cyan()
+                .bold(),
             format_duration(elapsed_ms).dimmed()
         );
     }
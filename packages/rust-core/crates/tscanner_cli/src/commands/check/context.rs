@@ -1,12 +1,45 @@
 use super::command::CliOptions;
+use std::fs;
+use std::path::PathBuf;
+use tscanner_cli_output::RuleDescriptor;
+use tscanner_scanner::GitContentSource;
 
 #[derive(Clone)]
 pub struct CheckContext {
     pub cli_options: CliOptions,
+    pub root: PathBuf,
+    pub rule_descriptors: Vec<RuleDescriptor>,
+    pub link_template: Option<String>,
+    pub content_source: Option<GitContentSource>,
 }
 
 impl CheckContext {
-    pub fn new(cli_options: CliOptions) -> Self {
-        Self { cli_options }
+    pub fn new(
+        cli_options: CliOptions,
+        root: PathBuf,
+        rule_descriptors: Vec<RuleDescriptor>,
+        link_template: Option<String>,
+    ) -> Self {
+        Self {
+            cli_options,
+            root,
+            rule_descriptors,
+            link_template,
+            content_source: None,
+        }
+    }
+
+    pub fn with_content_source(mut self, content_source: Option<GitContentSource>) -> Self {
+        self.content_source = content_source;
+        self
+    }
+
+    pub fn read_source_lines(&self, file: &str) -> Option<Vec<String>> {
+        let path = self.root.join(file);
+        let content = match &self.content_source {
+            Some(source) => source.read(&self.root, &path).ok()?,
+            None => fs::read_to_string(path).ok()?,
+        };
+        Some(content.lines().map(|l| l.to_string()).collect())
     }
 }
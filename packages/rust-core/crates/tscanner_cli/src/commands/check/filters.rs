@@ -20,6 +20,35 @@ pub fn apply_rule_type_filter(result: &mut ScanResult, rule_type: IssueRuleType)
     result.filter_by_rule_type(rule_type);
 }
 
+pub fn apply_author_filter(result: &mut ScanResult, author: &str) {
+    result.filter_by_author(author);
+}
+
+pub fn apply_since_filter(result: &mut ScanResult, since_timestamp: i64) {
+    result.filter_by_blame_since(since_timestamp);
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ResultFilters {
+    pub rule: Option<String>,
+    pub severity: Option<Severity>,
+    pub rule_type: Option<IssueRuleType>,
+}
+
+impl ResultFilters {
+    pub fn apply(&self, result: &mut ScanResult) {
+        if let Some(ref rule_name) = self.rule {
+            apply_rule_filter(result, rule_name);
+        }
+        if let Some(severity) = self.severity {
+            apply_severity_filter(result, severity);
+        }
+        if let Some(rule_type) = self.rule_type {
+            apply_rule_type_filter(result, rule_type);
+        }
+    }
+}
+
 pub fn get_files_to_scan_multi(
     paths: &[PathBuf],
     file_pattern: Option<&str>,
@@ -1,4 +1,6 @@
 pub use tscanner_git::{
-    get_changed_files, get_modified_lines, get_staged_files, get_staged_modified_lines,
-    get_uncommitted_files, get_uncommitted_modified_lines,
+    get_blame, get_blame_for_contents, get_changed_files, get_head_commit, get_merge_base,
+    get_modified_lines, get_range_changed_files, get_range_modified_lines, get_staged_files,
+    get_staged_modified_lines, get_uncommitted_files, get_uncommitted_modified_lines,
+    parse_modified_lines, resolve_range, BlameInfo, RevisionRange,
 };
@@ -1,7 +1,10 @@
+mod blame;
 pub mod command;
 pub mod context;
 mod filters;
 mod git;
 mod output;
+mod record;
+mod watch;
 
 pub use command::cmd_check;
@@ -1,8 +1,9 @@
-mod json;
 mod renderer;
+mod report;
 mod text;
 
 pub use renderer::get_renderer;
+pub use report::{build_rule_descriptors, write_reports};
 pub use text::render_summary;
 
 use super::context::CheckContext;
@@ -2,7 +2,7 @@ use crate::shared::FormattedOutput;
 use tscanner_cli::OutputFormat;
 use tscanner_types::ScanResult;
 
-use super::json::JsonRenderer;
+use super::report::ReportRenderer;
 use super::text::TextRenderer;
 use super::CheckContext;
 
@@ -11,8 +11,8 @@ pub trait OutputRenderer {
 }
 
 pub fn get_renderer(format: &OutputFormat) -> Box<dyn OutputRenderer> {
-    match format {
-        OutputFormat::Json => Box::new(JsonRenderer),
-        OutputFormat::Text => Box::new(TextRenderer),
+    match format.report_format() {
+        Some(report_format) => Box::new(ReportRenderer::new(report_format)),
+        None => Box::new(TextRenderer),
     }
 }
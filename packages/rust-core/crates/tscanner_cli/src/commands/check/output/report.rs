@@ -0,0 +1,112 @@
+use anyhow::{Context, Result};
+use std::fs;
+
+use super::renderer::OutputRenderer;
+use super::CheckContext;
+use crate::shared::FormattedOutput;
+use tscanner_cli::ReportTarget;
+use tscanner_cli_output::{get_reporter, ReportContext, ReportFormat, RuleDescriptor};
+use tscanner_config::TscannerConfig;
+use tscanner_rules::get_all_rule_metadata;
+use tscanner_service::log_info;
+use tscanner_types::ScanResult;
+
+pub struct ReportRenderer {
+    format: ReportFormat,
+}
+
+impl ReportRenderer {
+    pub fn new(format: ReportFormat) -> Self {
+        Self { format }
+    }
+}
+
+impl OutputRenderer for ReportRenderer {
+    fn render(&self, ctx: &CheckContext, output: &FormattedOutput, result: &ScanResult) {
+        if let Some(report) = build_report(self.format, ctx, output, result) {
+            println!("{}", report);
+        }
+    }
+}
+
+fn build_report(
+    format: ReportFormat,
+    ctx: &CheckContext,
+    output: &FormattedOutput,
+    result: &ScanResult,
+) -> Option<String> {
+    let report_ctx = ReportContext {
+        root: &ctx.root,
+        result,
+        output,
+        rules: &ctx.rule_descriptors,
+        link_template: ctx.link_template.as_deref(),
+    };
+    get_reporter(format).report(&report_ctx)
+}
+
+pub fn write_reports(
+    targets: &[ReportTarget],
+    ctx: &CheckContext,
+    output: &FormattedOutput,
+    result: &ScanResult,
+) -> Result<()> {
+    for target in targets {
+        if let Some(report) = build_report(target.format, ctx, output, result) {
+            fs::write(&target.path, report).context(format!(
+                "Failed to write {} report to {:?}",
+                target.format.as_str(),
+                target.path
+            ))?;
+            log_info(&format!(
+                "cmd_check: {} report written to {:?}",
+                target.format.as_str(),
+                target.path
+            ));
+        }
+    }
+    Ok(())
+}
+
+pub fn build_rule_descriptors(config: &TscannerConfig) -> Vec<RuleDescriptor> {
+    let mut descriptors: Vec<RuleDescriptor> = get_all_rule_metadata()
+        .into_iter()
+        .filter(|meta| config.rules.builtin.contains_key(meta.name))
+        .map(|meta| RuleDescriptor {
+            name: meta.name.to_string(),
+            description: meta.description.to_string(),
+            help_uri: meta.equivalent_eslint_rule.map(|url| url.to_string()),
+            default_severity: meta.default_severity,
+        })
+        .collect();
+
+    for (name, rule) in &config.rules.regex {
+        descriptors.push(RuleDescriptor {
+            name: name.clone(),
+            description: rule.message.clone(),
+            help_uri: None,
+            default_severity: rule.severity,
+        });
+    }
+
+    for (name, rule) in &config.rules.script {
+        descriptors.push(RuleDescriptor {
+            name: name.clone(),
+            description: rule.message.clone(),
+            help_uri: None,
+            default_severity: rule.severity,
+        });
+    }
+
+    for (name, rule) in &config.ai_rules {
+        descriptors.push(RuleDescriptor {
+            name: name.clone(),
+            description: rule.message.clone(),
+            help_uri: None,
+            default_severity: rule.severity,
+        });
+    }
+
+    descriptors.sort_by(|a, b| a.name.cmp(&b.name));
+    descriptors
+}
@@ -2,14 +2,15 @@ use super::renderer::OutputRenderer;
 use super::CheckContext;
 use crate::shared::{
     format_duration, print_section_header, print_section_title, rule_type_icon, severity_icon,
-    FormattedOutput, OutputFileGroup, OutputRuleGroup, OutputSummary,
+    FormattedOutput, OutputAuthorGroup, OutputFileGroup, OutputRuleGroup, OutputSummary,
 };
 use colored::*;
 use std::collections::HashMap;
+use tscanner_cli_output::OutputRelatedLocation;
 use tscanner_constants::{
     icon_ai, icon_builtin, icon_error, icon_hint, icon_info, icon_regex, icon_script, icon_warning,
 };
-use tscanner_types::{IssueRuleType, ScanResult};
+use tscanner_types::{IssueBlame, IssueRuleType, ScanResult};
 
 fn get_severity_icon(severity: &str) -> ColoredString {
     let icon = severity_icon(severity);
@@ -26,7 +27,13 @@ trait IssueDisplay {
     fn severity(&self) -> &str;
     fn line(&self) -> usize;
     fn column(&self) -> usize;
+    fn end_line(&self) -> usize;
+    fn end_column(&self) -> usize;
     fn line_text(&self) -> Option<&str>;
+    fn fix_title(&self) -> Option<&str>;
+    fn note(&self) -> Option<&str>;
+    fn related(&self) -> &[OutputRelatedLocation];
+    fn blame(&self) -> Option<&IssueBlame>;
 }
 
 impl IssueDisplay for tscanner_cli_output::OutputIssue {
@@ -39,9 +46,27 @@ impl IssueDisplay for tscanner_cli_output::OutputIssue {
     fn column(&self) -> usize {
         self.column
     }
+    fn end_line(&self) -> usize {
+        self.end_line
+    }
+    fn end_column(&self) -> usize {
+        self.end_column
+    }
     fn line_text(&self) -> Option<&str> {
         self.line_text.as_deref()
     }
+    fn fix_title(&self) -> Option<&str> {
+        self.fix_title.as_deref()
+    }
+    fn note(&self) -> Option<&str> {
+        self.note.as_deref()
+    }
+    fn related(&self) -> &[OutputRelatedLocation] {
+        &self.related
+    }
+    fn blame(&self) -> Option<&IssueBlame> {
+        self.blame.as_ref()
+    }
 }
 
 impl IssueDisplay for tscanner_cli_output::OutputRuleIssue {
@@ -54,28 +79,173 @@ impl IssueDisplay for tscanner_cli_output::OutputRuleIssue {
     fn column(&self) -> usize {
         self.column
     }
+    fn end_line(&self) -> usize {
+        self.end_line
+    }
+    fn end_column(&self) -> usize {
+        self.end_column
+    }
     fn line_text(&self) -> Option<&str> {
         self.line_text.as_deref()
     }
+    fn fix_title(&self) -> Option<&str> {
+        self.fix_title.as_deref()
+    }
+    fn note(&self) -> Option<&str> {
+        self.note.as_deref()
+    }
+    fn related(&self) -> &[OutputRelatedLocation] {
+        &self.related
+    }
+    fn blame(&self) -> Option<&IssueBlame> {
+        self.blame.as_ref()
+    }
 }
 
 fn render_issue_location<T: IssueDisplay>(issue: &T) {
     let severity_icon = get_severity_icon(issue.severity());
     let location = format!("{}:{}", issue.line(), issue.column());
+    let trimmed = issue.line_text().map(str::trim).unwrap_or_default();
+
+    if trimmed.is_empty() {
+        println!("    {} {}", severity_icon, location.dimmed());
+    } else {
+        println!(
+            "    {} {} → {}",
+            severity_icon,
+            location.dimmed(),
+            trimmed.dimmed()
+        );
+    }
+
+    render_issue_notes(issue, "      ");
+}
+
+fn render_issue_notes<T: IssueDisplay>(issue: &T, indent: &str) {
+    if let Some(note) = issue.note() {
+        println!("{}{} {}", indent, "= note:".dimmed(), note);
+    }
+    for related in issue.related() {
+        println!(
+            "{}{} {} {}",
+            indent,
+            "= related:".dimmed(),
+            related.message,
+            format!("({}:{}:{})", related.file, related.line, related.column).dimmed()
+        );
+    }
+    if let Some(blame) = issue.blame() {
+        println!(
+            "{}{} {} {}",
+            indent,
+            "= blame:".dimmed(),
+            blame.author,
+            format!(
+                "({}, {})",
+                blame.commit.get(..7).unwrap_or(&blame.commit),
+                blame.date
+            )
+            .dimmed()
+        );
+    }
+}
 
-    if let Some(line_text) = issue.line_text() {
-        let trimmed = line_text.trim();
-        if !trimmed.is_empty() {
+fn frame_matches_issue<T: IssueDisplay>(issue: &T, source: &[String]) -> bool {
+    let Some(text) = source.get(issue.line().saturating_sub(1)) else {
+        return false;
+    };
+    match issue.line_text() {
+        Some(expected) => text.trim() == expected.trim(),
+        None => true,
+    }
+}
+
+fn caret_padding(line: &str, column: usize) -> String {
+    line.chars()
+        .take(column.saturating_sub(1))
+        .map(|c| if c == '\t' { '\t' } else { ' ' })
+        .collect()
+}
+
+fn caret_span<T: IssueDisplay>(issue: &T, current: usize, text: &str) -> (usize, usize) {
+    let first_line = current == issue.line();
+    let last_line = current >= issue.end_line();
+    let start = if first_line {
+        issue.column()
+    } else {
+        text.chars().take_while(|c| c.is_whitespace()).count() + 1
+    };
+    let end = if last_line {
+        issue.end_column()
+    } else {
+        text.chars().count() + 1
+    };
+    (start, end.saturating_sub(start).max(1))
+}
+
+fn render_code_frame<T: IssueDisplay>(issue: &T, source: &[String], context_lines: usize) {
+    let severity_icon = get_severity_icon(issue.severity());
+    println!(
+        "    {} {}",
+        severity_icon,
+        format!("{}:{}", issue.line(), issue.column()).dimmed()
+    );
+
+    let line = issue.line().max(1);
+    if line > source.len() {
+        return;
+    }
+    let end_line = issue.end_line().clamp(line, source.len());
+
+    let first = line.saturating_sub(context_lines).max(1);
+    let last = (end_line + context_lines).min(source.len());
+    let gutter_width = last.to_string().len();
+    let gutter = format!("      {:>width$} │", "", width = gutter_width);
+
+    println!("{}", gutter.dimmed());
+    for current in first..=last {
+        let text = &source[current - 1];
+        let number = format!("      {:>width$} │", current, width = gutter_width);
+        if (line..=end_line).contains(&current) {
+            println!("{} {}", number.bold(), text);
+            let (start, caret_count) = caret_span(issue, current, text);
+            let carets = "^".repeat(caret_count);
+            let carets = match issue.severity() {
+                "error" => carets.red(),
+                "warning" => carets.yellow(),
+                "info" => carets.blue(),
+                _ => carets.dimmed(),
+            };
             println!(
-                "    {} {} → {}",
-                severity_icon,
-                location.dimmed(),
-                trimmed.dimmed()
+                "{} {}{}",
+                gutter.dimmed(),
+                caret_padding(text, start),
+                carets.bold()
             );
-            return;
+        } else {
+            println!("{} {}", number.dimmed(), text.dimmed());
+        }
+    }
+
+    let indent = format!("      {:>width$} ", "", width = gutter_width);
+    render_issue_notes(issue, &indent);
+    if let Some(fix_title) = issue.fix_title() {
+        println!(
+            "{}{} {}",
+            indent,
+            "=".dimmed(),
+            format!("fix available: {}", fix_title).green()
+        );
+    }
+}
+
+fn render_issue<T: IssueDisplay>(issue: &T, source: Option<&[String]>, code_frame: Option<usize>) {
+    match (code_frame, source) {
+        (Some(context_lines), Some(source)) if frame_matches_issue(issue, source) => {
+            render_code_frame(issue, source, context_lines)
         }
+        _ => render_issue_location(issue),
     }
-    println!("    {} {}", severity_icon, location.dimmed());
 }
 
 pub struct TextRenderer;
@@ -88,7 +258,7 @@ impl OutputRenderer for TextRenderer {
         match output {
             FormattedOutput::ByFile { files, summary } => {
                 self.render_rules_triggered_by_file(files);
-                self.render_by_file(files);
+                self.render_by_file(ctx, files);
                 println!();
                 self.render_messages(result);
                 if ctx.cli_options.show_summary {
@@ -97,7 +267,16 @@ impl OutputRenderer for TextRenderer {
             }
             FormattedOutput::ByRule { rules, summary } => {
                 self.render_rules_triggered_by_rule(rules);
-                self.render_by_rule(rules);
+                self.render_by_rule(ctx, rules);
+                println!();
+                self.render_messages(result);
+                if ctx.cli_options.show_summary {
+                    self.render_summary(summary);
+                }
+            }
+            FormattedOutput::ByAuthor { authors, summary } => {
+                self.render_authors_triggered(authors);
+                self.render_by_author(ctx, authors);
                 println!();
                 self.render_messages(result);
                 if ctx.cli_options.show_summary {
@@ -129,7 +308,7 @@ impl TextRenderer {
         println!();
 
         let mut sorted_rules: Vec<_> = rules_map.iter().collect();
-        sorted_rules.sort_by(|a, b| b.1 .2.cmp(&a.1 .2));
+        sorted_rules.sort_by_key(|(_, (_, _, count))| std::cmp::Reverse(*count));
 
         let max_rule_len = sorted_rules
             .iter()
@@ -170,7 +349,7 @@ impl TextRenderer {
         println!();
 
         let mut sorted_rules: Vec<_> = rules.iter().collect();
-        sorted_rules.sort_by(|a, b| b.count.cmp(&a.count));
+        sorted_rules.sort_by_key(|r| std::cmp::Reverse(r.count));
 
         let max_rule_len = sorted_rules.iter().map(|r| r.rule.len()).max().unwrap_or(0);
         let max_count_len = sorted_rules
@@ -196,46 +375,106 @@ impl TextRenderer {
         println!("Issues grouped by rule:");
     }
 
-    fn render_by_file(&self, files: &[OutputFileGroup]) {
+    fn render_authors_triggered(&self, authors: &[OutputAuthorGroup]) {
+        if authors.is_empty() {
+            return;
+        }
+
+        println!();
+        println!("Authors:");
+        println!();
+
+        let names: Vec<String> = authors.iter().map(|a| a.display_name()).collect();
+        let max_name_len = names.iter().map(|n| n.len()).max().unwrap_or(0);
+        let max_count_len = authors
+            .iter()
+            .map(|a| a.count.to_string().len())
+            .max()
+            .unwrap_or(0);
+
+        for (author, name) in authors.iter().zip(&names) {
+            println!(
+                "  {:<name_width$} | {:>count_width$} | {} files",
+                name,
+                author.count,
+                author.files.len(),
+                name_width = max_name_len,
+                count_width = max_count_len
+            );
+        }
+
+        println!();
+        println!("Issues grouped by author:");
+    }
+
+    fn render_by_file(&self, ctx: &CheckContext, files: &[OutputFileGroup]) {
         let mut sorted_files: Vec<_> = files.iter().collect();
-        sorted_files.sort_by(|a, b| b.issues.len().cmp(&a.issues.len()));
+        sorted_files.sort_by_key(|f| std::cmp::Reverse(f.issues.len()));
 
         for file in sorted_files {
-            let mut issues_by_rule: HashMap<&str, Vec<_>> = HashMap::new();
-            for issue in &file.issues {
-                issues_by_rule.entry(&issue.rule).or_default().push(issue);
+            self.render_file_group(ctx, file);
+        }
+    }
+
+    fn render_file_group(&self, ctx: &CheckContext, file: &OutputFileGroup) {
+        let code_frame = ctx.cli_options.code_frame;
+
+        let mut issues_by_rule: HashMap<&str, Vec<_>> = HashMap::new();
+        for issue in &file.issues {
+            issues_by_rule.entry(&issue.rule).or_default().push(issue);
+        }
+
+        let unique_rules = issues_by_rule.len();
+        println!();
+        println!(
+            "{} - {} issues - {} rules",
+            file.file.bold(),
+            file.issues.len(),
+            unique_rules
+        );
+
+        let source = code_frame.and_then(|_| ctx.read_source_lines(&file.file));
+
+        let mut sorted_rules: Vec<_> = issues_by_rule.keys().collect();
+        sorted_rules.sort();
+
+        for rule_name in sorted_rules {
+            let issues = &issues_by_rule[rule_name];
+            let rule_type = issues.first().map(|i| i.rule_type).unwrap_or_default();
+            let icon = rule_type_icon(rule_type);
+
+            println!();
+            println!("  {} {} ({} issues)", icon, rule_name, issues.len());
+
+            for issue in issues {
+                render_issue(*issue, source.as_deref(), code_frame);
             }
+        }
+    }
 
-            let unique_rules = issues_by_rule.len();
+    fn render_by_author(&self, ctx: &CheckContext, authors: &[OutputAuthorGroup]) {
+        for author in authors {
             println!();
             println!(
-                "{} - {} issues - {} rules",
-                file.file.bold(),
-                file.issues.len(),
-                unique_rules
+                "{} {}",
+                author.display_name().cyan().bold(),
+                format!("({} issues, {} files)", author.count, author.files.len()).dimmed()
             );
 
-            let mut sorted_rules: Vec<_> = issues_by_rule.keys().collect();
-            sorted_rules.sort();
+            let mut sorted_files: Vec<_> = author.files.iter().collect();
+            sorted_files.sort_by_key(|f| std::cmp::Reverse(f.issues.len()));
 
-            for rule_name in sorted_rules {
-                let issues = &issues_by_rule[rule_name];
-                let rule_type = issues.first().map(|i| i.rule_type).unwrap_or_default();
-                let icon = rule_type_icon(rule_type);
-
-                println!();
-                println!("  {} {} ({} issues)", icon, rule_name, issues.len());
-
-                for issue in issues {
-                    render_issue_location(*issue);
-                }
+            for file in sorted_files {
+                self.render_file_group(ctx, file);
             }
         }
     }
 
-    fn render_by_rule(&self, rules: &[OutputRuleGroup]) {
+    fn render_by_rule(&self, ctx: &CheckContext, rules: &[OutputRuleGroup]) {
+        let code_frame = ctx.cli_options.code_frame;
+
         let mut sorted_rules: Vec<_> = rules.iter().collect();
-        sorted_rules.sort_by(|a, b| b.count.cmp(&a.count));
+        sorted_rules.sort_by_key(|r| std::cmp::Reverse(r.count));
 
         for rule in sorted_rules {
             let icon = rule_type_icon(rule.rule_type);
@@ -257,14 +496,15 @@ impl TextRenderer {
             );
 
             let mut sorted_files: Vec<_> = files_map.iter().collect();
-            sorted_files.sort_by(|a, b| b.1.len().cmp(&a.1.len()));
+            sorted_files.sort_by_key(|(_, issues)| std::cmp::Reverse(issues.len()));
 
             for (file, issues) in sorted_files {
                 println!();
                 println!("  {} ({} issues)", file, issues.len());
 
+                let source = code_frame.and_then(|_| ctx.read_source_lines(file));
                 for issue in issues {
-                    render_issue_location(*issue);
+                    render_issue(*issue, source.as_deref(), code_frame);
                 }
             }
         }
@@ -0,0 +1,34 @@
+use anyhow::Result;
+use std::path::{Path, PathBuf};
+use time::format_description::well_known::Rfc3339;
+use time::OffsetDateTime;
+
+use crate::shared::{append_history, history_path, HistoryRecord, OutputSummary};
+use tscanner_service::log_info;
+use tscanner_types::ScanResult;
+
+pub fn record_scan(
+    root: &Path,
+    resolved_config_path: &str,
+    result: &ScanResult,
+    summary: &OutputSummary,
+) -> Result<PathBuf> {
+    let config_dir = Path::new(resolved_config_path)
+        .parent()
+        .map(|p| p.to_path_buf())
+        .unwrap_or_else(|| root.to_path_buf());
+    let path = history_path(&config_dir);
+
+    let timestamp = OffsetDateTime::now_utc().format(&Rfc3339)?;
+    let commit = tscanner_git::get_head_commit(root).ok();
+    let record = HistoryRecord::from_summary(root, result, summary, timestamp, commit);
+
+    append_history(&path, &record)?;
+    log_info(&format!(
+        "cmd_check: Recorded scan to {} ({} issues)",
+        path.display(),
+        record.total_issues
+    ));
+
+    Ok(path)
+}
@@ -0,0 +1,406 @@
+use anyhow::{Context, Result};
+use colored::*;
+use notify::{Event, EventKind, RecursiveMode, Watcher};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::time::{Duration, Instant};
+
+use crate::config_loader::load_config_with_custom;
+use crate::shared::{format_duration, render_warnings, severity_icon};
+use tscanner_constants::{ai_rules_dir, icon_error, icon_success, icon_warning, script_rules_dir};
+use tscanner_scanner::{IncrementalScanResult, Scanner};
+use tscanner_service::{log_error, log_info};
+use tscanner_types::enums::{IssueRuleType, Severity};
+use tscanner_types::{FileResult, Issue, ScanResult};
+
+use super::command::build_scanner;
+use super::filters::{self, ResultFilters};
+
+const DEBOUNCE_MS: u64 = 200;
+
+type IssueKey = (String, usize, usize, String);
+type IssueSnapshot = BTreeMap<PathBuf, Vec<Issue>>;
+
+pub struct WatchSession {
+    pub root: PathBuf,
+    pub scan_paths: Vec<PathBuf>,
+    pub config_path: Option<PathBuf>,
+    pub resolved_config_path: String,
+    pub no_cache: bool,
+    pub cache_dir: Option<PathBuf>,
+    pub glob_filter: Option<String>,
+    pub result_filters: ResultFilters,
+    pub scanner: Scanner,
+    pub initial_result: ScanResult,
+}
+
+#[derive(Default)]
+struct WatchState {
+    builtin: HashMap<PathBuf, Vec<Issue>>,
+    script: HashMap<String, Vec<Issue>>,
+}
+
+impl WatchState {
+    fn from_result(result: ScanResult) -> Self {
+        let mut state = WatchState::default();
+        for file_result in result.files {
+            for issue in file_result.issues {
+                state.insert(issue);
+            }
+        }
+        state
+    }
+
+    fn insert(&mut self, issue: Issue) {
+        if issue.rule_type == IssueRuleType::CustomScript {
+            self.script
+                .entry(issue.rule.clone())
+                .or_default()
+                .push(issue);
+        } else {
+            self.builtin
+                .entry(issue.file.clone())
+                .or_default()
+                .push(issue);
+        }
+    }
+
+    fn apply(&mut self, incremental: IncrementalScanResult) {
+        for path in &incremental.removed_files {
+            self.builtin.remove(path);
+        }
+        for file_result in incremental.files {
+            self.builtin.insert(file_result.file, file_result.issues);
+        }
+        for rule in &incremental.script_rules {
+            self.script.remove(rule);
+        }
+        for issue in incremental.script_issues {
+            self.insert(issue);
+        }
+    }
+
+    fn tracks(&self, path: &Path) -> bool {
+        self.builtin.contains_key(path)
+            || self
+                .script
+                .values()
+                .any(|issues| issues.iter().any(|i| i.file == path))
+    }
+
+    fn snapshot(&self, result_filters: &ResultFilters) -> IssueSnapshot {
+        let mut by_file: HashMap<PathBuf, Vec<Issue>> = HashMap::new();
+        for issue in self.builtin.values().chain(self.script.values()).flatten() {
+            by_file
+                .entry(issue.file.clone())
+                .or_default()
+                .push(issue.clone());
+        }
+
+        let files: Vec<FileResult> = by_file
+            .into_iter()
+            .map(|(file, issues)| FileResult { file, issues })
+            .collect();
+        let mut result = ScanResult {
+            total_issues: files.iter().map(|f| f.issues.len()).sum(),
+            files,
+            duration_ms: 0,
+            regular_rules_duration_ms: 0,
+            ai_rules_duration_ms: 0,
+            total_files: 0,
+            cached_files: 0,
+            scanned_files: 0,
+            notes: Vec::new(),
+            warnings: Vec::new(),
+            errors: Vec::new(),
+        };
+        result_filters.apply(&mut result);
+
+        result
+            .files
+            .into_iter()
+            .filter(|f| !f.issues.is_empty())
+            .map(|mut f| {
+                f.issues.sort_by_key(|i| (i.line, i.column));
+                (f.file, f.issues)
+            })
+            .collect()
+    }
+}
+
+pub fn run_watch(session: WatchSession) -> Result<()> {
+    let WatchSession {
+        root,
+        scan_paths,
+        config_path,
+        mut resolved_config_path,
+        no_cache,
+        cache_dir,
+        glob_filter,
+        result_filters,
+        mut scanner,
+        initial_result,
+    } = session;
+
+    let (tx, rx) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(tx).context("Failed to start file watcher")?;
+    let config_dir = config_dir_of(&resolved_config_path, &root);
+    let mut config_inputs = config_inputs_of(&resolved_config_path, &root);
+
+    for path in &scan_paths {
+        watcher
+            .watch(path, RecursiveMode::Recursive)
+            .context(format!("Failed to watch path: {}", path.display()))?;
+    }
+    if !scan_paths.iter().any(|p| config_dir.starts_with(p)) {
+        watcher
+            .watch(&config_dir, RecursiveMode::Recursive)
+            .context(format!("Failed to watch path: {}", config_dir.display()))?;
+    }
+
+    let full_scan = |scanner: &Scanner| {
+        let files = filters::get_files_to_scan_multi(&scan_paths, glob_filter.as_deref(), None);
+        WatchState::from_result(scanner.scan_codebase_with_filter(&scan_paths, files.as_ref()))
+    };
+
+    let mut state = WatchState::from_result(initial_result);
+    let mut previous = state.snapshot(&result_filters);
+    render_watching();
+
+    while let Some(changed) = collect_changes(&rx) {
+        let start = Instant::now();
+
+        if changed.iter().any(|p| config_inputs.affects(p)) {
+            log_info("watch: Config changed, reloading");
+            match reload_scanner(&root, config_path.clone(), no_cache, cache_dir.as_deref()) {
+                Ok((new_scanner, new_config_path)) => {
+                    scanner = new_scanner;
+                    resolved_config_path = new_config_path;
+                    config_inputs = config_inputs_of(&resolved_config_path, &root);
+                    state = full_scan(&scanner);
+                    println!();
+                    println!("{}", "Configuration reloaded".cyan().bold());
+                }
+                Err(e) => {
+                    log_error(&format!("watch: Config reload failed: {}", e));
+                    println!();
+                    println!("  {} {}", icon_error().red(), format!("{}", e).red());
+                    render_watching();
+                    continue;
+                }
+            }
+        } else {
+            let relevant = filter_changed_files(&scan_paths, glob_filter.as_deref(), changed);
+            let incremental = scanner.scan_changed_files(&relevant);
+            let touched = incremental.files.len()
+                + incremental
+                    .removed_files
+                    .iter()
+                    .filter(|p| state.tracks(p))
+                    .count();
+            if touched == 0 && incremental.script_rules.is_empty() {
+                continue;
+            }
+
+            let warnings = incremental.warnings.clone();
+            state.apply(incremental);
+            println!();
+            println!(
+                "{} {}",
+                format!("Re-checked {} file(s)", touched).cyan().bold(),
+                format_duration(start.elapsed().as_millis()).dimmed()
+            );
+            render_warnings(&warnings);
+        }
+
+        let current = state.snapshot(&result_filters);
+        render_delta(&root, &previous, &current);
+        previous = current;
+        render_watching();
+    }
+
+    Ok(())
+}
+
+fn collect_changes(rx: &Receiver<notify::Result<Event>>) -> Option<HashSet<PathBuf>> {
+    let mut changed = HashSet::new();
+    let mut add_event = |event: notify::Result<Event>| match event {
+        Ok(event) if !matches!(event.kind, EventKind::Access(_)) => changed.extend(event.paths),
+        Ok(_) => {}
+        Err(e) => log_error(&format!("watch: Watcher error: {}", e)),
+    };
+
+    add_event(rx.recv().ok()?);
+    loop {
+        match rx.recv_timeout(Duration::from_millis(DEBOUNCE_MS)) {
+            Ok(event) => add_event(event),
+            Err(RecvTimeoutError::Timeout) => break,
+            Err(RecvTimeoutError::Disconnected) => return None,
+        }
+    }
+
+    Some(changed)
+}
+
+fn filter_changed_files(
+    scan_paths: &[PathBuf],
+    glob_filter: Option<&str>,
+    changed: HashSet<PathBuf>,
+) -> HashSet<PathBuf> {
+    let in_scan_paths: HashSet<PathBuf> = changed
+        .into_iter()
+        .filter(|p| scan_paths.iter().any(|scan_path| p.starts_with(scan_path)))
+        .collect();
+    filters::get_files_to_scan_multi(scan_paths, glob_filter, Some(in_scan_paths))
+        .unwrap_or_default()
+}
+
+fn reload_scanner(
+    root: &Path,
+    config_path: Option<PathBuf>,
+    no_cache: bool,
+    cache_dir: Option<&Path>,
+) -> Result<(Scanner, String)> {
+    let (config, resolved_config_path, _) = load_config_with_custom(root, config_path)?
+        .ok_or_else(|| anyhow::anyhow!("Configuration not found"))?;
+    let scanner = build_scanner(config, &resolved_config_path, root, no_cache, cache_dir)?;
+    scanner.clear_script_cache();
+    Ok((scanner, resolved_config_path))
+}
+
+fn config_dir_of(resolved_config_path: &str, root: &Path) -> PathBuf {
+    root.join(resolved_config_path)
+        .parent()
+        .map(|p| p.to_path_buf())
+        .unwrap_or_else(|| root.to_path_buf())
+}
+
+struct ConfigInputs {
+    config_file: PathBuf,
+    rule_dirs: Vec<PathBuf>,
+}
+
+impl ConfigInputs {
+    fn affects(&self, path: &Path) -> bool {
+        path == self.config_file || self.rule_dirs.iter().any(|dir| path.starts_with(dir))
+    }
+}
+
+fn config_inputs_of(resolved_config_path: &str, root: &Path) -> ConfigInputs {
+    let config_dir = config_dir_of(resolved_config_path, root);
+    ConfigInputs {
+        config_file: root.join(resolved_config_path),
+        rule_dirs: vec![
+            config_dir.join(script_rules_dir()),
+            config_dir.join(ai_rules_dir()),
+        ],
+    }
+}
+
+fn issue_key(issue: &Issue) -> IssueKey {
+    (
+        issue.rule.clone(),
+        issue.line,
+        issue.column,
+        issue.message.clone(),
+    )
+}
+
+fn render_delta(root: &Path, previous: &IssueSnapshot, current: &IssueSnapshot) {
+    let empty = Vec::new();
+    let files: BTreeSet<&PathBuf> = previous.keys().chain(current.keys()).collect();
+    let mut added_total = 0;
+    let mut fixed_total = 0;
+
+    for file in files {
+        let before = previous.get(file).unwrap_or(&empty);
+        let after = current.get(file).unwrap_or(&empty);
+        let before_keys: HashSet<IssueKey> = before.iter().map(issue_key).collect();
+        let after_keys: HashSet<IssueKey> = after.iter().map(issue_key).collect();
+
+        let added: Vec<&Issue> = after
+            .iter()
+            .filter(|i| !before_keys.contains(&issue_key(i)))
+            .collect();
+        let fixed: Vec<&Issue> = before
+            .iter()
+            .filter(|i| !after_keys.contains(&issue_key(i)))
+            .collect();
+        if added.is_empty() && fixed.is_empty() {
+            continue;
+        }
+
+        let relative = pathdiff::diff_paths(file, root).unwrap_or_else(|| file.to_path_buf());
+        println!("  {}", relative.display().to_string().bold());
+        for issue in &added {
+            println!("    {} {}", "+".red().bold(), format_issue(issue));
+        }
+        for issue in &fixed {
+            println!(
+                "    {} {}",
+                "-".green().bold(),
+                format!(
+                    "{}:{} {} {}",
+                    issue.line, issue.column, issue.rule, issue.message
+                )
+                .dimmed()
+            );
+        }
+        added_total += added.len();
+        fixed_total += fixed.len();
+    }
+
+    let issues: Vec<&Issue> = current.values().flatten().collect();
+    let errors = issues
+        .iter()
+        .filter(|i| i.severity == Severity::Error)
+        .count();
+    let warnings = issues
+        .iter()
+        .filter(|i| i.severity == Severity::Warning)
+        .count();
+    let status_icon = if errors > 0 {
+        icon_error().red()
+    } else if warnings > 0 {
+        icon_warning().yellow()
+    } else {
+        icon_success().green()
+    };
+
+    println!(
+        "{} {} {} {}",
+        status_icon,
+        format!(
+            "{} issues ({} errors, {} warnings)",
+            issues.len(),
+            errors,
+            warnings
+        )
+        .bold(),
+        format!("+{} new", added_total).red(),
+        format!("-{} fixed", fixed_total).green()
+    );
+}
+
+fn format_issue(issue: &Issue) -> String {
+    let location = format!("{}:{}", issue.line, issue.column);
+    let icon = severity_icon(issue.severity.as_str());
+    let icon = match issue.severity {
+        Severity::Error => icon.red(),
+        Severity::Warning => icon.yellow(),
+        Severity::Info | Severity::Hint => icon.blue(),
+    };
+    format!(
+        "{} {} {} {}",
+        icon,
+        location.dimmed(),
+        issue.rule.cyan(),
+        issue.message
+    )
+}
+
+fn render_watching() {
+    println!();
+    println!("{}", "Watching for changes... (Ctrl+C to stop)".dimmed());
+}
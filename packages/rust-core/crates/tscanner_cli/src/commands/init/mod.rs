@@ -2,3 +2,4 @@ mod command;
 mod config_generator;
 
 pub use command::cmd_init;
+pub use config_generator::get_default_config;
@@ -0,0 +1,117 @@
+use anyhow::{Context, Result};
+use serde_json::Value;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use tscanner_types::enums::Severity;
+
+use super::plan::{RuleLevel, SourceConfig, SourceRule};
+
+const CONFIG_FILES: &[&str] = &["biome.json", "biome.jsonc"];
+const PRESET_KEYS: &[&str] = &["recommended", "all"];
+
+pub(super) fn find_config(root: &Path) -> Result<PathBuf> {
+    CONFIG_FILES
+        .iter()
+        .map(|f| root.join(f))
+        .find(|p| p.is_file())
+        .ok_or_else(|| anyhow::anyhow!("No Biome config found in {}", root.display()))
+}
+
+pub(super) fn load_config(path: &Path) -> Result<SourceConfig> {
+    let content = fs::read_to_string(path).context(format!("Failed to read {}", path.display()))?;
+    let stripped = json_comments::StripComments::new(content.as_bytes());
+    let json: Value =
+        serde_json::from_reader(stripped).context(format!("Failed to parse {}", path.display()))?;
+
+    let mut config = SourceConfig {
+        rules: Vec::new(),
+        notes: Vec::new(),
+    };
+
+    let linter = json.get("linter");
+    if linter
+        .and_then(|l| l.get("enabled"))
+        .and_then(|e| e.as_bool())
+        == Some(false)
+    {
+        config
+            .notes
+            .push("The Biome linter is disabled in this config".to_string());
+    }
+
+    let Some(groups) = linter
+        .and_then(|l| l.get("rules"))
+        .and_then(|r| r.as_object())
+    else {
+        return Ok(config);
+    };
+
+    for (group, rules) in groups {
+        if PRESET_KEYS.contains(&group.as_str()) {
+            config.notes.push(format!(
+                "The '{}' preset is not expanded; only explicitly configured rules were migrated",
+                group
+            ));
+            continue;
+        }
+        let Some(rules) = rules.as_object() else {
+            continue;
+        };
+
+        for (name, value) in rules {
+            if PRESET_KEYS.contains(&name.as_str()) {
+                continue;
+            }
+            let (level, options) = match value {
+                Value::Object(obj) => (
+                    obj.get("level").cloned().unwrap_or(Value::Null),
+                    obj.get("options").cloned().into_iter().collect(),
+                ),
+                other => (other.clone(), Vec::new()),
+            };
+            config.rules.push(SourceRule {
+                name: name.clone(),
+                level: parse_level(&level),
+                options,
+            });
+        }
+    }
+
+    Ok(config)
+}
+
+fn parse_level(level: &Value) -> RuleLevel {
+    match level.as_str() {
+        Some("error") => RuleLevel::Severity(Severity::Error),
+        Some("warn") => RuleLevel::Severity(Severity::Warning),
+        Some("info") => RuleLevel::Severity(Severity::Info),
+        Some("on") => RuleLevel::Default,
+        _ => RuleLevel::Off,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn parse_level_maps_biome_levels() {
+        assert_eq!(
+            parse_level(&json!("error")),
+            RuleLevel::Severity(Severity::Error)
+        );
+        assert_eq!(
+            parse_level(&json!("warn")),
+            RuleLevel::Severity(Severity::Warning)
+        );
+        assert_eq!(
+            parse_level(&json!("info")),
+            RuleLevel::Severity(Severity::Info)
+        );
+        assert_eq!(parse_level(&json!("on")), RuleLevel::Default);
+        assert_eq!(parse_level(&json!("off")), RuleLevel::Off);
+        assert_eq!(parse_level(&Value::Null), RuleLevel::Off);
+    }
+}
@@ -0,0 +1,245 @@
+use anyhow::{Context, Result};
+use colored::*;
+use serde_json::{json, Map, Value};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::commands::init::get_default_config;
+use crate::shared::{fatal_error_and_exit, print_section_title};
+use tscanner_cli::MigrateSource;
+use tscanner_config::{BuiltinRuleConfig, RegexRuleConfig};
+use tscanner_constants::{config_dir_name, config_file_name, icon_success, icon_warning};
+use tscanner_service::log_info;
+
+use super::plan::{build_plan, MigrationPlan};
+use super::report::render_report;
+use super::{biome, eslint};
+
+const REPORT_FILE_NAME: &str = "migration-report.md";
+const MIGRATED_CONFIG_FILE_NAME: &str = "config.migrated.json";
+
+pub fn cmd_migrate(
+    from: MigrateSource,
+    input: Option<PathBuf>,
+    force: bool,
+    dry_run: bool,
+    config_path: Option<PathBuf>,
+) -> Result<()> {
+    log_info(&format!(
+        "cmd_migrate: from={}, input={:?}, force={}, dry_run={}, config_path={:?}",
+        from.as_str(),
+        input,
+        force,
+        dry_run,
+        config_path
+    ));
+
+    let root = std::env::current_dir().context("Failed to get current directory")?;
+    let source_path = match input {
+        Some(path) => path,
+        None => {
+            let found = match from {
+                MigrateSource::Eslint => eslint::find_config(&root),
+                MigrateSource::Biome => biome::find_config(&root),
+            };
+            match found {
+                Ok(path) => path,
+                Err(e) => fatal_error_and_exit(&e.to_string(), &[]),
+            }
+        }
+    };
+
+    let source = match from {
+        MigrateSource::Eslint => eslint::load_config(&source_path)?,
+        MigrateSource::Biome => biome::load_config(&source_path)?,
+    };
+    let mut plan = build_plan(from, source);
+
+    let config_dir = match config_path {
+        Some(dir) if dir.is_absolute() => dir,
+        Some(dir) => root.join(dir),
+        None => root.join(config_dir_name()),
+    };
+    let config_file = config_dir.join(config_file_name());
+
+    let config_exists = config_file.exists();
+    let mut config = if config_exists {
+        let content = fs::read_to_string(&config_file)
+            .context(format!("Failed to read config: {}", config_file.display()))?;
+        let stripped = json_comments::StripComments::new(content.as_bytes());
+        serde_json::from_reader(stripped).context("Failed to parse config JSON")?
+    } else {
+        let default_config = get_default_config();
+        let stripped = json_comments::StripComments::new(default_config.as_bytes());
+        let mut default: Value =
+            serde_json::from_reader(stripped).context("Failed to parse default config")?;
+        default["rules"]["builtin"] = json!({});
+        default
+    };
+
+    merge_plan(&mut config, &mut plan, force)?;
+
+    let relative_source = pathdiff::diff_paths(&source_path, &root)
+        .unwrap_or_else(|| source_path.clone())
+        .display()
+        .to_string();
+    let report = render_report(from, &relative_source, &plan);
+
+    render_summary(&plan);
+
+    if dry_run {
+        println!();
+        print_section_title("Rules (dry run):");
+        println!("{}", serde_json::to_string_pretty(&config["rules"])?);
+        return Ok(());
+    }
+
+    let output_file = if config_exists {
+        config_dir.join(MIGRATED_CONFIG_FILE_NAME)
+    } else {
+        config_file.clone()
+    };
+
+    fs::create_dir_all(&config_dir).context("Failed to create config directory")?;
+    fs::write(&output_file, serde_json::to_string_pretty(&config)? + "\n")
+        .context("Failed to write config")?;
+    let report_path = config_dir.join(REPORT_FILE_NAME);
+    fs::write(&report_path, report).context("Failed to write migration report")?;
+
+    println!();
+    println!("  {} {}", "→".dimmed(), display_path(&root, &output_file));
+    println!("  {} {}", "→".dimmed(), display_path(&root, &report_path));
+
+    if config_exists {
+        println!();
+        println!(
+            "  {} {}",
+            "ℹ".blue(),
+            format!(
+                "{} was left untouched; review {} and merge its rules by hand",
+                display_path(&root, &config_file),
+                MIGRATED_CONFIG_FILE_NAME
+            )
+            .dimmed()
+        );
+    }
+
+    Ok(())
+}
+
+fn display_path(root: &Path, path: &Path) -> String {
+    pathdiff::diff_paths(path, root)
+        .unwrap_or_else(|| path.to_path_buf())
+        .display()
+        .to_string()
+}
+
+fn section<'a>(config: &'a mut Value, key: &str) -> Result<&'a mut Map<String, Value>> {
+    if !config["rules"][key].is_object() {
+        config["rules"][key] = json!({});
+    }
+    config["rules"][key]
+        .as_object_mut()
+        .context(format!("Invalid 'rules.{}' section in config", key))
+}
+
+fn merge_plan(config: &mut Value, plan: &mut MigrationPlan, force: bool) -> Result<()> {
+    if !config.is_object() {
+        anyhow::bail!("Config root must be an object");
+    }
+
+    let mut skipped = Vec::new();
+
+    let builtin = section(config, "builtin")?;
+    for rule in &plan.builtin {
+        if builtin.contains_key(&rule.target) && !force {
+            skipped.push(rule.target.clone());
+            continue;
+        }
+        let rule_config = BuiltinRuleConfig {
+            severity: rule.severity,
+            include: Vec::new(),
+            exclude: Vec::new(),
+            options: rule.options.clone().into_iter().collect(),
+        };
+        builtin.insert(rule.target.clone(), serde_json::to_value(rule_config)?);
+    }
+
+    let regex = section(config, "regex")?;
+    for rule in &plan.regex {
+        if regex.contains_key(&rule.name) && !force {
+            skipped.push(rule.name.clone());
+            continue;
+        }
+        let rule_config = RegexRuleConfig {
+            pattern: rule.pattern.clone(),
+            message: rule.message.clone(),
+            severity: rule.severity,
+            ..Default::default()
+        };
+        regex.insert(rule.name.clone(), serde_json::to_value(rule_config)?);
+    }
+
+    if !skipped.is_empty() {
+        plan.notes.push(format!(
+            "Kept existing config for {} (use --force to overwrite)",
+            skipped.join(", ")
+        ));
+    }
+
+    Ok(())
+}
+
+fn render_summary(plan: &MigrationPlan) {
+    println!(
+        "{} {}",
+        icon_success().green(),
+        format!(
+            "Migrated {} builtin rule(s) and {} regex rule(s)",
+            plan.builtin.len(),
+            plan.regex.len()
+        )
+        .green()
+        .bold()
+    );
+    for rule in &plan.builtin {
+        println!(
+            "  {} {} {} {}",
+            rule.source.dimmed(),
+            "→".dimmed(),
+            rule.target,
+            format!("({})", rule.severity.as_str()).dimmed()
+        );
+    }
+    for rule in &plan.regex {
+        println!(
+            "  {} {} {} {}",
+            rule.source.dimmed(),
+            "→".dimmed(),
+            rule.name,
+            "(regex)".dimmed()
+        );
+    }
+
+    if !plan.unmapped.is_empty() {
+        println!();
+        println!(
+            "{} {}",
+            icon_warning().yellow(),
+            format!("{} rule(s) could not be migrated", plan.unmapped.len())
+                .yellow()
+                .bold()
+        );
+        for rule in &plan.unmapped {
+            println!(
+                "  {} {}",
+                rule.source,
+                format!("- {}", rule.reason).dimmed()
+            );
+        }
+    }
+
+    for note in &plan.notes {
+        println!("  {} {}", "ℹ".blue(), note.dimmed());
+    }
+}
@@ -0,0 +1,226 @@
+use anyhow::{Context, Result};
+use serde_json::Value;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use tscanner_types::enums::Severity;
+
+use super::plan::{RuleLevel, SourceConfig, SourceRule};
+
+const JSON_CONFIG_FILES: &[&str] = &[".eslintrc.json", ".eslintrc"];
+const UNSUPPORTED_CONFIG_FILES: &[&str] = &[
+    "eslint.config.js",
+    "eslint.config.mjs",
+    "eslint.config.cjs",
+    "eslint.config.ts",
+    "eslint.config.mts",
+    ".eslintrc.js",
+    ".eslintrc.cjs",
+    ".eslintrc.yaml",
+    ".eslintrc.yml",
+];
+
+pub(super) fn find_config(root: &Path) -> Result<PathBuf> {
+    if let Some(path) = JSON_CONFIG_FILES
+        .iter()
+        .map(|f| root.join(f))
+        .find(|p| p.is_file())
+    {
+        return Ok(path);
+    }
+
+    let package_json = root.join("package.json");
+    if package_json.is_file() && read_json(&package_json)?.get("eslintConfig").is_some() {
+        return Ok(package_json);
+    }
+
+    if let Some(path) = UNSUPPORTED_CONFIG_FILES
+        .iter()
+        .map(|f| root.join(f))
+        .find(|p| p.is_file())
+    {
+        anyhow::bail!(
+            "{} is not a JSON config. Export it with 'npx eslint --print-config <file> > eslint.json' and pass '--input eslint.json'",
+            path.display()
+        );
+    }
+
+    anyhow::bail!("No ESLint config found in {}", root.display())
+}
+
+fn read_json(path: &Path) -> Result<Value> {
+    let content = fs::read_to_string(path).context(format!("Failed to read {}", path.display()))?;
+    let stripped = json_comments::StripComments::new(content.as_bytes());
+    serde_json::from_reader(stripped).context(format!("Failed to parse {}", path.display()))
+}
+
+pub(super) fn load_config(path: &Path) -> Result<SourceConfig> {
+    let mut json = read_json(path)?;
+    if path.file_name().is_some_and(|n| n == "package.json") {
+        json = json.get("eslintConfig").cloned().unwrap_or(Value::Null);
+    }
+
+    let mut config = SourceConfig {
+        rules: Vec::new(),
+        notes: Vec::new(),
+    };
+
+    match &json {
+        Value::Array(blocks) => {
+            for block in blocks {
+                collect_block(block, &mut config);
+            }
+        }
+        block => collect_block(block, &mut config),
+    }
+
+    Ok(config)
+}
+
+fn collect_block(block: &Value, config: &mut SourceConfig) {
+    if let Some(extends) = block.get("extends") {
+        config.notes.push(format!(
+            "Extended configs are not resolved ({}); use 'npx eslint --print-config' to include them",
+            extends
+        ));
+    }
+    if let Some(overrides) = block.get("overrides").and_then(|o| o.as_array()) {
+        config.notes.push(format!(
+            "{} override block(s) were skipped",
+            overrides.len()
+        ));
+    }
+    if block.get("files").is_some() {
+        config
+            .notes
+            .push("File-scoped rules were applied to all files".to_string());
+    }
+
+    let Some(rules) = block.get("rules").and_then(|r| r.as_object()) else {
+        return;
+    };
+
+    for (name, value) in rules {
+        let (level, options) = match value {
+            Value::Array(items) => (
+                items.first().cloned().unwrap_or(Value::Null),
+                items.iter().skip(1).cloned().collect(),
+            ),
+            other => (other.clone(), Vec::new()),
+        };
+
+        let rule = SourceRule {
+            name: name.clone(),
+            level: parse_level(&level),
+            options,
+        };
+        match config.rules.iter_mut().find(|r| r.name == rule.name) {
+            Some(existing) => *existing = rule,
+            None => config.rules.push(rule),
+        }
+    }
+}
+
+fn parse_level(level: &Value) -> RuleLevel {
+    match level {
+        Value::String(s) => match s.as_str() {
+            "warn" => RuleLevel::Severity(Severity::Warning),
+            "error" => RuleLevel::Severity(Severity::Error),
+            _ => RuleLevel::Off,
+        },
+        Value::Number(n) => match n.as_u64() {
+            Some(1) => RuleLevel::Severity(Severity::Warning),
+            Some(2) => RuleLevel::Severity(Severity::Error),
+            _ => RuleLevel::Off,
+        },
+        _ => RuleLevel::Off,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::migrate::plan::build_plan;
+    use serde_json::json;
+    use tscanner_cli::MigrateSource;
+
+    #[test]
+    fn parse_level_maps_eslint_levels() {
+        assert_eq!(
+            parse_level(&json!("warn")),
+            RuleLevel::Severity(Severity::Warning)
+        );
+        assert_eq!(
+            parse_level(&json!("error")),
+            RuleLevel::Severity(Severity::Error)
+        );
+        assert_eq!(
+            parse_level(&json!(1)),
+            RuleLevel::Severity(Severity::Warning)
+        );
+        assert_eq!(parse_level(&json!(2)), RuleLevel::Severity(Severity::Error));
+        assert_eq!(parse_level(&json!("off")), RuleLevel::Off);
+        assert_eq!(parse_level(&json!(0)), RuleLevel::Off);
+        assert_eq!(parse_level(&json!(true)), RuleLevel::Off);
+    }
+
+    #[test]
+    fn eslint_config_round_trips_into_plan() {
+        let dir = std::env::temp_dir().join(format!("tscanner-migrate-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(".eslintrc.json");
+        fs::write(
+            &path,
+            r#"{
+                // comments are allowed in .eslintrc.json
+                "extends": ["eslint:recommended"],
+                "rules": {
+                    "no-console": ["warn", { "allow": ["error", "warn"] }],
+                    "no-var": 2,
+                    "semi": "off",
+                    "eqeqeq": "error",
+                    "no-restricted-syntax": ["error", "DebuggerStatement", "LabeledStatement"],
+                    "no-restricted-imports": ["error", { "patterns": ["lodash/*"] }]
+                }
+            }"#,
+        )
+        .unwrap();
+
+        let source = load_config(&path).unwrap();
+        let _ = fs::remove_dir_all(&dir);
+        let plan = build_plan(MigrateSource::Eslint, source);
+
+        let builtin: Vec<_> = plan
+            .builtin
+            .iter()
+            .map(|r| (r.target.as_str(), r.severity))
+            .collect();
+        assert_eq!(
+            builtin,
+            vec![
+                ("no-console", Severity::Warning),
+                ("no-var", Severity::Error)
+            ]
+        );
+        let methods = plan.builtin[0].options["methods"].as_array().unwrap();
+        assert!(methods.contains(&json!("log")));
+        assert!(!methods.contains(&json!("error")));
+        assert!(!methods.contains(&json!("warn")));
+
+        let regex: Vec<_> = plan.regex.iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(
+            regex,
+            vec![
+                "restricted-import-lodash",
+                "restricted-syntax-debuggerstatement"
+            ]
+        );
+        assert!(plan.regex.iter().all(|r| r.severity == Severity::Error));
+
+        let unmapped: Vec<_> = plan.unmapped.iter().map(|r| r.source.as_str()).collect();
+        assert!(unmapped.contains(&"eqeqeq"));
+        assert!(unmapped.contains(&"no-restricted-syntax (LabeledStatement)"));
+        assert_eq!(plan.disabled, vec!["semi"]);
+        assert!(plan.notes.iter().any(|n| n.contains("Extended configs")));
+    }
+}
@@ -0,0 +1,8 @@
+mod biome;
+mod command;
+mod eslint;
+mod plan;
+mod report;
+mod restricted;
+
+pub use command::cmd_migrate;
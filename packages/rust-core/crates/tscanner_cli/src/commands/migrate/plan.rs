@@ -0,0 +1,306 @@
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+
+use tscanner_cli::MigrateSource;
+use tscanner_rules::{get_all_rule_metadata, RuleMetadata, RuleOptionSchema};
+use tscanner_types::enums::Severity;
+
+use super::restricted;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum RuleLevel {
+    Off,
+    Default,
+    Severity(Severity),
+}
+
+impl RuleLevel {
+    pub fn resolve(self, default: Severity) -> Option<Severity> {
+        match self {
+            RuleLevel::Off => None,
+            RuleLevel::Default => Some(default),
+            RuleLevel::Severity(severity) => Some(severity),
+        }
+    }
+}
+
+pub(super) struct SourceRule {
+    pub name: String,
+    pub level: RuleLevel,
+    pub options: Vec<Value>,
+}
+
+pub(super) struct SourceConfig {
+    pub rules: Vec<SourceRule>,
+    pub notes: Vec<String>,
+}
+
+pub(super) struct MigratedRule {
+    pub source: String,
+    pub target: String,
+    pub severity: Severity,
+    pub options: Map<String, Value>,
+}
+
+pub(super) struct MigratedRegexRule {
+    pub source: String,
+    pub name: String,
+    pub pattern: String,
+    pub message: String,
+    pub severity: Severity,
+}
+
+pub(super) struct UnmappedRule {
+    pub source: String,
+    pub reason: String,
+}
+
+#[derive(Default)]
+pub(super) struct MigrationPlan {
+    pub builtin: Vec<MigratedRule>,
+    pub regex: Vec<MigratedRegexRule>,
+    pub unmapped: Vec<UnmappedRule>,
+    pub disabled: Vec<String>,
+    pub notes: Vec<String>,
+}
+
+fn source_rule_name(from: MigrateSource, url: &str) -> Option<String> {
+    let last = url.trim_end_matches('/').rsplit('/').next()?;
+    match from {
+        MigrateSource::Eslint if url.contains("typescript-eslint.io") => {
+            Some(format!("@typescript-eslint/{}", last))
+        }
+        MigrateSource::Eslint => Some(last.to_string()),
+        MigrateSource::Biome => Some(kebab_to_camel(last)),
+    }
+}
+
+fn kebab_to_camel(value: &str) -> String {
+    let mut result = String::new();
+    let mut upper = false;
+    for c in value.chars() {
+        if c == '-' {
+            upper = true;
+        } else if upper {
+            result.extend(c.to_uppercase());
+            upper = false;
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
+
+fn build_equivalence_index(from: MigrateSource) -> HashMap<String, Vec<RuleMetadata>> {
+    let mut index: HashMap<String, Vec<RuleMetadata>> = HashMap::new();
+    for metadata in get_all_rule_metadata() {
+        let url = match from {
+            MigrateSource::Eslint => metadata.equivalent_eslint_rule,
+            MigrateSource::Biome => metadata.equivalent_biome_rule,
+        };
+        if let Some(name) = url.and_then(|u| source_rule_name(from, u)) {
+            index.entry(name).or_default().push(metadata);
+        }
+    }
+    index
+}
+
+fn lookup<'a>(
+    index: &'a HashMap<String, Vec<RuleMetadata>>,
+    name: &str,
+) -> Option<&'a Vec<RuleMetadata>> {
+    index.get(name).or_else(|| {
+        name.strip_prefix("@typescript-eslint/")
+            .and_then(|base| index.get(base))
+    })
+}
+
+pub(super) fn build_plan(from: MigrateSource, source: SourceConfig) -> MigrationPlan {
+    let index = build_equivalence_index(from);
+    let mut plan = MigrationPlan {
+        notes: source.notes,
+        ..Default::default()
+    };
+
+    for rule in source.rules {
+        if rule.level == RuleLevel::Off {
+            plan.disabled.push(rule.name);
+            continue;
+        }
+
+        if let Some((regex_rules, unmapped)) = restricted::convert(from, &rule) {
+            plan.regex.extend(regex_rules);
+            plan.unmapped.extend(unmapped);
+            continue;
+        }
+
+        let Some(targets) = lookup(&index, &rule.name) else {
+            plan.unmapped.push(UnmappedRule {
+                source: rule.name,
+                reason: "no equivalent tscanner rule".to_string(),
+            });
+            continue;
+        };
+
+        for metadata in targets {
+            let Some(severity) = rule.level.resolve(metadata.default_severity) else {
+                continue;
+            };
+            let (options, ignored) = map_options(metadata, &rule.options);
+            if ignored {
+                plan.notes.push(format!(
+                    "{}: options were not migrated to {}",
+                    rule.name, metadata.name
+                ));
+            }
+
+            match plan.builtin.iter_mut().find(|m| m.target == metadata.name) {
+                Some(existing) => {
+                    if severity == Severity::Error {
+                        existing.severity = severity;
+                    }
+                    existing.options.extend(options);
+                }
+                None => plan.builtin.push(MigratedRule {
+                    source: rule.name.clone(),
+                    target: metadata.name.to_string(),
+                    severity,
+                    options,
+                }),
+            }
+        }
+    }
+
+    let mut seen: HashMap<String, usize> = HashMap::new();
+    for rule in &mut plan.regex {
+        let count = seen.entry(rule.name.clone()).or_insert(0);
+        *count += 1;
+        if *count > 1 {
+            rule.name = format!("{}-{}", rule.name, count);
+        }
+    }
+
+    plan.builtin.sort_by(|a, b| a.target.cmp(&b.target));
+    plan.regex.sort_by(|a, b| a.name.cmp(&b.name));
+    plan
+}
+
+fn first_object(options: &[Value]) -> Option<&Map<String, Value>> {
+    options.iter().find_map(|o| o.as_object())
+}
+
+fn max_option(options: &[Value], keys: &[&str]) -> Option<Value> {
+    options.iter().find_map(|option| match option {
+        Value::Number(n) => Some(Value::Number(n.clone())),
+        Value::Object(obj) => keys.iter().find_map(|k| obj.get(*k).cloned()),
+        _ => None,
+    })
+}
+
+fn array_default(metadata: &RuleMetadata, name: &str) -> Vec<&'static str> {
+    metadata
+        .options
+        .iter()
+        .find(|o| o.name == name)
+        .and_then(|o| match o.schema {
+            RuleOptionSchema::Array { default, .. } => Some(default.to_vec()),
+            _ => None,
+        })
+        .unwrap_or_default()
+}
+
+fn map_options(metadata: &RuleMetadata, options: &[Value]) -> (Map<String, Value>, bool) {
+    let mut mapped = Map::new();
+    let has_options = options
+        .iter()
+        .any(|o| !o.is_null() && o.as_object().map(|m| !m.is_empty()).unwrap_or(true));
+
+    match metadata.name {
+        "max-params" => {
+            if let Some(max) = max_option(options, &["max", "maximum"]) {
+                mapped.insert("maxParams".to_string(), max);
+            }
+        }
+        "max-function-length" => {
+            if let Some(max) = max_option(options, &["max"]) {
+                mapped.insert("maxLength".to_string(), max);
+            }
+        }
+        "no-todo-comments" => {
+            if let Some(terms) = first_object(options).and_then(|o| o.get("terms")) {
+                mapped.insert("keywords".to_string(), terms.clone());
+            }
+        }
+        "no-console" => {
+            if let Some(allow) = first_object(options)
+                .and_then(|o| o.get("allow"))
+                .and_then(|a| a.as_array())
+            {
+                let allowed: Vec<&str> = allow.iter().filter_map(|v| v.as_str()).collect();
+                let methods: Vec<Value> = array_default(metadata, "methods")
+                    .into_iter()
+                    .filter(|m| !allowed.contains(m))
+                    .map(|m| Value::String(m.to_string()))
+                    .collect();
+                mapped.insert("methods".to_string(), Value::Array(methods));
+            }
+        }
+        _ => {}
+    }
+
+    let ignored = has_options && mapped.is_empty();
+    (mapped, ignored)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn metadata(name: &str) -> RuleMetadata {
+        get_all_rule_metadata()
+            .into_iter()
+            .find(|m| m.name == name)
+            .unwrap()
+    }
+
+    #[test]
+    fn kebab_to_camel_converts_rule_names() {
+        assert_eq!(kebab_to_camel("no-console"), "noConsole");
+        assert_eq!(kebab_to_camel("use-optional-chain"), "useOptionalChain");
+        assert_eq!(kebab_to_camel("noDebugger"), "noDebugger");
+    }
+
+    #[test]
+    fn map_options_translates_known_options() {
+        let (mapped, ignored) = map_options(&metadata("max-params"), &[json!(3)]);
+        assert_eq!(mapped.get("maxParams"), Some(&json!(3)));
+        assert!(!ignored);
+
+        let (mapped, _) = map_options(&metadata("max-params"), &[json!({ "maximum": 5 })]);
+        assert_eq!(mapped.get("maxParams"), Some(&json!(5)));
+
+        let (mapped, _) = map_options(
+            &metadata("max-function-length"),
+            &[json!({ "max": 20, "skipComments": true })],
+        );
+        assert_eq!(mapped.get("maxLength"), Some(&json!(20)));
+
+        let (mapped, _) = map_options(
+            &metadata("no-todo-comments"),
+            &[json!({ "terms": ["todo", "fixme"] })],
+        );
+        assert_eq!(mapped.get("keywords"), Some(&json!(["todo", "fixme"])));
+    }
+
+    #[test]
+    fn map_options_flags_unsupported_options() {
+        let (mapped, ignored) = map_options(&metadata("no-var"), &[json!({ "unknown": true })]);
+        assert!(mapped.is_empty());
+        assert!(ignored);
+
+        let (mapped, ignored) = map_options(&metadata("no-var"), &[json!({}), Value::Null]);
+        assert!(mapped.is_empty());
+        assert!(!ignored);
+    }
+}
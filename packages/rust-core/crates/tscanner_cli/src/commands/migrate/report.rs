@@ -0,0 +1,87 @@
+use tscanner_cli::MigrateSource;
+
+use super::plan::MigrationPlan;
+
+fn escape_cell(value: &str) -> String {
+    value.replace('|', "\\|")
+}
+
+pub(super) fn render_report(from: MigrateSource, source: &str, plan: &MigrationPlan) -> String {
+    let mut lines = vec![
+        format!("# Migration report ({})", from.as_str()),
+        String::new(),
+        format!("Source: `{}`", source),
+        String::new(),
+        format!("## Migrated rules ({})", plan.builtin.len()),
+        String::new(),
+    ];
+
+    if !plan.builtin.is_empty() {
+        lines.push("| Source rule | TScanner rule | Severity |".to_string());
+        lines.push("|---|---|---|".to_string());
+        for rule in &plan.builtin {
+            lines.push(format!(
+                "| `{}` | `{}` | {} |",
+                rule.source,
+                rule.target,
+                rule.severity.as_str()
+            ));
+        }
+        lines.push(String::new());
+    }
+
+    lines.push(format!("## Regex rules ({})", plan.regex.len()));
+    lines.push(String::new());
+    if !plan.regex.is_empty() {
+        lines.push("| Source rule | TScanner rule | Pattern |".to_string());
+        lines.push("|---|---|---|".to_string());
+        for rule in &plan.regex {
+            lines.push(format!(
+                "| `{}` | `{}` | `{}` |",
+                rule.source,
+                rule.name,
+                escape_cell(&rule.pattern)
+            ));
+        }
+        lines.push(String::new());
+    }
+
+    lines.push(format!("## Unmapped rules ({})", plan.unmapped.len()));
+    lines.push(String::new());
+    if !plan.unmapped.is_empty() {
+        lines.push("| Rule | Reason |".to_string());
+        lines.push("|---|---|".to_string());
+        for rule in &plan.unmapped {
+            lines.push(format!(
+                "| `{}` | {} |",
+                escape_cell(&rule.source),
+                rule.reason
+            ));
+        }
+        lines.push(String::new());
+    }
+
+    if !plan.disabled.is_empty() {
+        lines.push(format!("## Disabled rules ({})", plan.disabled.len()));
+        lines.push(String::new());
+        lines.push(
+            plan.disabled
+                .iter()
+                .map(|r| format!("`{}`", r))
+                .collect::<Vec<_>>()
+                .join(", "),
+        );
+        lines.push(String::new());
+    }
+
+    if !plan.notes.is_empty() {
+        lines.push("## Notes".to_string());
+        lines.push(String::new());
+        for note in &plan.notes {
+            lines.push(format!("- {}", note));
+        }
+        lines.push(String::new());
+    }
+
+    lines.join("\n")
+}
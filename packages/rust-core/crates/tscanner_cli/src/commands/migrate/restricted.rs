@@ -0,0 +1,378 @@
+use regex::Regex;
+use serde_json::Value;
+
+use tscanner_cli::MigrateSource;
+use tscanner_types::enums::Severity;
+
+use super::plan::{MigratedRegexRule, SourceRule, UnmappedRule};
+
+type Converted = (Vec<MigratedRegexRule>, Vec<UnmappedRule>);
+
+pub(super) fn convert(from: MigrateSource, rule: &SourceRule) -> Option<Converted> {
+    let severity = rule.level.resolve(Severity::Warning)?;
+    match (from, rule.name.as_str()) {
+        (MigrateSource::Eslint, "no-restricted-syntax") => {
+            Some(convert_restricted_syntax(rule, severity))
+        }
+        (MigrateSource::Eslint, "no-restricted-imports")
+        | (MigrateSource::Eslint, "@typescript-eslint/no-restricted-imports") => {
+            Some(convert_eslint_restricted_imports(rule, severity))
+        }
+        (MigrateSource::Biome, "noRestrictedImports") => {
+            Some(convert_biome_restricted_imports(rule, severity))
+        }
+        _ => None,
+    }
+}
+
+fn slugify(value: &str) -> String {
+    let mut slug = String::new();
+    for c in value.to_lowercase().chars() {
+        if c.is_ascii_alphanumeric() {
+            slug.push(c);
+        } else if !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    slug.trim_matches('-').to_string()
+}
+
+fn unmapped(rule: &SourceRule, entry: &str, reason: &str) -> UnmappedRule {
+    UnmappedRule {
+        source: format!("{} ({})", rule.name, entry),
+        reason: reason.to_string(),
+    }
+}
+
+fn selector_to_pattern(selector: &str) -> Option<String> {
+    let attr = r#"=\s*['"]?([A-Za-z_$][\w$]*)['"]?\]"#;
+    let identifier = Regex::new(&format!(r"^Identifier\[name{}$", attr)).ok()?;
+    let call = Regex::new(&format!(r"^CallExpression\[callee\.name{}$", attr)).ok()?;
+    let method_call = Regex::new(&format!(
+        r"^CallExpression\[callee\.object\.name{}\[callee\.property\.name{}$",
+        attr, attr
+    ))
+    .ok()?;
+    let member = Regex::new(&format!(
+        r"^MemberExpression\[object\.name{}\[property\.name{}$",
+        attr, attr
+    ))
+    .ok()?;
+
+    let selector = selector.trim();
+    if let Some(c) = identifier.captures(selector) {
+        return Some(format!(r"\b{}\b", regex::escape(&c[1])));
+    }
+    if let Some(c) = call.captures(selector) {
+        return Some(format!(r"\b{}\s*\(", regex::escape(&c[1])));
+    }
+    if let Some(c) = method_call.captures(selector) {
+        return Some(format!(
+            r"\b{}\s*\.\s*{}\s*\(",
+            regex::escape(&c[1]),
+            regex::escape(&c[2])
+        ));
+    }
+    if let Some(c) = member.captures(selector) {
+        return Some(format!(
+            r"\b{}\s*\.\s*{}\b",
+            regex::escape(&c[1]),
+            regex::escape(&c[2])
+        ));
+    }
+
+    let pattern = match selector {
+        "WithStatement" => r"\bwith\s*\(",
+        "DebuggerStatement" => r"\bdebugger\b",
+        "ForInStatement" => r"\bfor\s*\([^)]*\bin\b",
+        "TSEnumDeclaration" => r"\benum\s+[A-Za-z_$][\w$]*\s*\{",
+        _ => return None,
+    };
+    Some(pattern.to_string())
+}
+
+fn convert_restricted_syntax(rule: &SourceRule, severity: Severity) -> Converted {
+    let mut regex_rules = Vec::new();
+    let mut unmapped_rules = Vec::new();
+
+    for option in &rule.options {
+        let (selector, message) = match option {
+            Value::String(selector) => (selector.as_str(), None),
+            Value::Object(obj) => match obj.get("selector").and_then(|s| s.as_str()) {
+                Some(selector) => (selector, obj.get("message").and_then(|m| m.as_str())),
+                None => continue,
+            },
+            _ => continue,
+        };
+
+        match selector_to_pattern(selector) {
+            Some(pattern) => regex_rules.push(MigratedRegexRule {
+                source: rule.name.clone(),
+                name: format!("restricted-syntax-{}", slugify(selector)),
+                pattern,
+                message: message
+                    .map(str::to_string)
+                    .unwrap_or_else(|| format!("Using '{}' is not allowed.", selector)),
+                severity,
+            }),
+            None => unmapped_rules.push(unmapped(
+                rule,
+                selector,
+                "selector cannot be expressed as a regex",
+            )),
+        }
+    }
+
+    (regex_rules, unmapped_rules)
+}
+
+fn import_pattern(module_pattern: &str) -> String {
+    format!(
+        r#"(?:\bfrom\s*|\bimport\s*\(?\s*|\brequire\s*\(\s*)['"]{}['"]"#,
+        module_pattern
+    )
+}
+
+fn glob_to_pattern(glob: &str) -> String {
+    regex::escape(glob)
+        .replace(r"\*\*", r"\*")
+        .replace(r"\*", r#"[^'"]*"#)
+}
+
+fn restricted_import(
+    rule: &SourceRule,
+    module: &str,
+    pattern: String,
+    message: Option<&str>,
+    severity: Severity,
+) -> MigratedRegexRule {
+    MigratedRegexRule {
+        source: rule.name.clone(),
+        name: format!("restricted-import-{}", slugify(module)),
+        pattern: import_pattern(&pattern),
+        message: message
+            .map(str::to_string)
+            .unwrap_or_else(|| format!("'{}' import is restricted.", module)),
+        severity,
+    }
+}
+
+fn convert_eslint_restricted_imports(rule: &SourceRule, severity: Severity) -> Converted {
+    let mut regex_rules = Vec::new();
+    let mut unmapped_rules = Vec::new();
+
+    let mut paths: Vec<&Value> = Vec::new();
+    let mut patterns: Vec<(Vec<String>, Option<String>)> = Vec::new();
+    for option in &rule.options {
+        match option {
+            Value::Object(obj) if obj.contains_key("paths") || obj.contains_key("patterns") => {
+                for path in obj
+                    .get("paths")
+                    .and_then(|p| p.as_array())
+                    .into_iter()
+                    .flatten()
+                {
+                    paths.push(path);
+                }
+                for pattern in obj
+                    .get("patterns")
+                    .and_then(|p| p.as_array())
+                    .into_iter()
+                    .flatten()
+                {
+                    match pattern {
+                        Value::String(glob) => patterns.push((vec![glob.clone()], None)),
+                        Value::Object(p) => {
+                            let group: Vec<String> = p
+                                .get("group")
+                                .and_then(|g| g.as_array())
+                                .into_iter()
+                                .flatten()
+                                .filter_map(|g| g.as_str().map(str::to_string))
+                                .collect();
+                            let message = p
+                                .get("message")
+                                .and_then(|m| m.as_str())
+                                .map(str::to_string);
+                            if group.is_empty() || p.contains_key("importNames") {
+                                unmapped_rules.push(unmapped(
+                                    rule,
+                                    "patterns",
+                                    "only group patterns without import names can be migrated",
+                                ));
+                            } else {
+                                patterns.push((group, message));
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            other => paths.push(other),
+        }
+    }
+
+    for path in paths {
+        match path {
+            Value::String(name) => regex_rules.push(restricted_import(
+                rule,
+                name,
+                regex::escape(name),
+                None,
+                severity,
+            )),
+            Value::Object(obj) => {
+                let Some(name) = obj.get("name").and_then(|n| n.as_str()) else {
+                    continue;
+                };
+                if obj.contains_key("importNames") || obj.contains_key("allowImportNames") {
+                    unmapped_rules.push(unmapped(
+                        rule,
+                        name,
+                        "import name restrictions cannot be expressed as a regex",
+                    ));
+                    continue;
+                }
+                regex_rules.push(restricted_import(
+                    rule,
+                    name,
+                    regex::escape(name),
+                    obj.get("message").and_then(|m| m.as_str()),
+                    severity,
+                ));
+            }
+            _ => {}
+        }
+    }
+
+    for (globs, message) in patterns {
+        if let Some(negated) = globs.iter().find(|g| g.starts_with('!')) {
+            unmapped_rules.push(unmapped(
+                rule,
+                negated,
+                "negated patterns cannot be expressed as a regex",
+            ));
+            continue;
+        }
+        let module = globs.join(", ");
+        let pattern = format!(
+            "(?:{})",
+            globs
+                .iter()
+                .map(|g| glob_to_pattern(g))
+                .collect::<Vec<_>>()
+                .join("|")
+        );
+        regex_rules.push(restricted_import(
+            rule,
+            &module,
+            pattern,
+            message.as_deref(),
+            severity,
+        ));
+    }
+
+    (regex_rules, unmapped_rules)
+}
+
+fn convert_biome_restricted_imports(rule: &SourceRule, severity: Severity) -> Converted {
+    let mut regex_rules = Vec::new();
+    let mut unmapped_rules = Vec::new();
+
+    let paths = rule
+        .options
+        .iter()
+        .filter_map(|o| o.get("paths").and_then(|p| p.as_object()))
+        .flatten();
+
+    for (name, value) in paths {
+        let message = match value {
+            Value::String(message) => Some(message.as_str()),
+            Value::Object(obj) => {
+                if obj.contains_key("importNames") || obj.contains_key("allowImportNames") {
+                    unmapped_rules.push(unmapped(
+                        rule,
+                        name,
+                        "import name restrictions cannot be expressed as a regex",
+                    ));
+                    continue;
+                }
+                obj.get("message").and_then(|m| m.as_str())
+            }
+            _ => None,
+        };
+        regex_rules.push(restricted_import(
+            rule,
+            name,
+            regex::escape(name),
+            message,
+            severity,
+        ));
+    }
+
+    (regex_rules, unmapped_rules)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matches(pattern: &str, source: &str) -> bool {
+        Regex::new(pattern).unwrap().is_match(source)
+    }
+
+    #[test]
+    fn selector_to_pattern_converts_supported_selectors() {
+        let identifier = selector_to_pattern("Identifier[name='eval']").unwrap();
+        assert_eq!(identifier, r"\beval\b");
+        assert!(matches(&identifier, "const f = eval;"));
+        assert!(!matches(&identifier, "const evaluate = 1;"));
+
+        let call = selector_to_pattern(r#"CallExpression[callee.name="alert"]"#).unwrap();
+        assert!(matches(&call, "alert ('hi');"));
+        assert!(!matches(&call, "const alert = 1;"));
+
+        let method = selector_to_pattern(
+            "CallExpression[callee.object.name='console'][callee.property.name='log']",
+        )
+        .unwrap();
+        assert!(matches(&method, "console . log(1);"));
+        assert!(!matches(&method, "console.logger(1);"));
+
+        let member =
+            selector_to_pattern("MemberExpression[object.name='process'][property.name='env']")
+                .unwrap();
+        assert!(matches(&member, "process.env.HOME"));
+        assert!(!matches(&member, "process.environment"));
+
+        let debugger = selector_to_pattern("  DebuggerStatement ").unwrap();
+        assert!(matches(&debugger, "debugger;"));
+    }
+
+    #[test]
+    fn selector_to_pattern_rejects_unsupported_selectors() {
+        assert_eq!(
+            selector_to_pattern("ArrowFunctionExpression > BlockStatement"),
+            None
+        );
+        assert_eq!(selector_to_pattern("Identifier[name=/^foo/]"), None);
+    }
+
+    #[test]
+    fn glob_to_pattern_matches_module_specifiers() {
+        let pattern = import_pattern(&glob_to_pattern("lodash/*"));
+        assert!(matches(&pattern, "import fp from 'lodash/fp';"));
+        assert!(matches(&pattern, r#"const get = require("lodash/get");"#));
+        assert!(!matches(&pattern, "import lodash from 'lodash';"));
+
+        let scoped = import_pattern(&glob_to_pattern("@internal/**"));
+        assert!(matches(
+            &scoped,
+            "import { a } from '@internal/core/utils';"
+        ));
+        assert!(!matches(&scoped, "import { a } from '@internals/core';"));
+
+        let literal = glob_to_pattern("a.b");
+        assert!(!matches(&import_pattern(&literal), "import x from 'axb';"));
+    }
+}
@@ -1,9 +1,19 @@
+pub mod cache;
 pub mod check;
 pub mod init;
+pub mod migrate;
 pub mod registry;
+pub mod rules;
+pub mod stats;
+pub mod test;
 pub mod validate;
 
+pub use cache::cmd_cache;
 pub use check::cmd_check;
 pub use init::cmd_init;
+pub use migrate::cmd_migrate;
 pub use registry::cmd_registry;
+pub use rules::{cmd_explain, cmd_rules};
+pub use stats::cmd_stats;
+pub use test::cmd_test;
 pub use validate::validate;
@@ -0,0 +1,96 @@
+use anyhow::Result;
+use std::path::PathBuf;
+
+use crate::config_loader::load_config_with_custom;
+use tscanner_config::TscannerConfig;
+use tscanner_rules::{get_all_rule_metadata, RuleMetadata, RuleRegistry};
+use tscanner_types::enums::{IssueRuleType, Severity};
+
+pub(super) struct RuleEntry {
+    pub name: String,
+    pub kind: IssueRuleType,
+    pub category: Option<&'static str>,
+    pub severity: Severity,
+    pub fixable: bool,
+    pub enabled: bool,
+    pub metadata: Option<RuleMetadata>,
+}
+
+pub(super) fn kind_label(kind: IssueRuleType) -> &'static str {
+    match kind {
+        IssueRuleType::Builtin => "builtin",
+        IssueRuleType::CustomRegex => "regex",
+        IssueRuleType::CustomScript => "script",
+        IssueRuleType::Ai => "ai",
+    }
+}
+
+pub(super) fn load_optional_config(config_path: Option<PathBuf>) -> Result<Option<TscannerConfig>> {
+    let root = std::env::current_dir()?;
+    Ok(load_config_with_custom(&root, config_path)?.map(|(config, _, _)| config))
+}
+
+pub(super) fn collect_rules(config: Option<&TscannerConfig>) -> Vec<RuleEntry> {
+    let registry = RuleRegistry::new();
+    let mut metadata = get_all_rule_metadata();
+    metadata.sort_by_key(|m| m.name);
+
+    let mut entries: Vec<RuleEntry> = metadata
+        .into_iter()
+        .map(|meta| {
+            let configured = config.and_then(|c| c.rules.builtin.get(meta.name));
+            RuleEntry {
+                name: meta.name.to_string(),
+                kind: IssueRuleType::Builtin,
+                category: Some(meta.category.as_folder_name()),
+                severity: configured
+                    .map(|c| c.severity)
+                    .unwrap_or(meta.default_severity),
+                fixable: registry
+                    .get_rule(meta.name)
+                    .map(|rule| rule.is_fixable())
+                    .unwrap_or(false),
+                enabled: configured.is_some(),
+                metadata: Some(meta),
+            }
+        })
+        .collect();
+
+    let Some(config) = config else {
+        return entries;
+    };
+
+    let custom = |name: &String, kind: IssueRuleType, severity: Severity| RuleEntry {
+        name: name.clone(),
+        kind,
+        category: None,
+        severity,
+        fixable: false,
+        enabled: true,
+        metadata: None,
+    };
+
+    let mut custom_entries: Vec<RuleEntry> = config
+        .rules
+        .regex
+        .iter()
+        .map(|(name, c)| custom(name, IssueRuleType::CustomRegex, c.severity))
+        .chain(
+            config
+                .rules
+                .script
+                .iter()
+                .map(|(name, c)| custom(name, IssueRuleType::CustomScript, c.severity)),
+        )
+        .chain(
+            config
+                .ai_rules
+                .iter()
+                .map(|(name, c)| custom(name, IssueRuleType::Ai, c.severity)),
+        )
+        .collect();
+    custom_entries.sort_by(|a, b| a.name.cmp(&b.name));
+    entries.extend(custom_entries);
+
+    entries
+}
@@ -0,0 +1,162 @@
+use anyhow::Result;
+use colored::*;
+use std::path::PathBuf;
+
+use crate::shared::{fatal_error_and_exit, print_section_title, print_setting, rule_type_icon};
+use tscanner_config::TscannerConfig;
+use tscanner_constants::{app_name, rule_docs_url};
+use tscanner_rules::{RuleMetadata, RuleOption, RuleOptionSchema};
+use tscanner_service::log_info;
+use tscanner_types::enums::IssueRuleType;
+
+use super::catalog::{collect_rules, kind_label, load_optional_config, RuleEntry};
+
+pub fn cmd_explain(name: &str, config_path: Option<PathBuf>) -> Result<()> {
+    log_info(&format!(
+        "cmd_explain: name={}, config_path={:?}",
+        name, config_path
+    ));
+
+    let config = load_optional_config(config_path)?;
+    let rules = collect_rules(config.as_ref());
+    let Some(rule) = rules.iter().find(|r| r.name == name) else {
+        fatal_error_and_exit(
+            &format!("Rule '{}' not found", name),
+            &[&format!(
+                "Use '{} rules' to see available rules.",
+                app_name()
+            )],
+        );
+    };
+
+    println!();
+    println!(
+        "{} {} {}",
+        rule_type_icon(rule.kind),
+        rule.name.bold(),
+        format!("({})", kind_label(rule.kind)).dimmed()
+    );
+
+    match &rule.metadata {
+        Some(metadata) => render_builtin(rule, metadata),
+        None => {
+            if let Some(config) = &config {
+                render_custom(rule, config);
+            }
+        }
+    }
+
+    println!();
+    Ok(())
+}
+
+fn yes_no(value: bool) -> &'static str {
+    if value {
+        "yes"
+    } else {
+        "no"
+    }
+}
+
+fn render_builtin(rule: &RuleEntry, metadata: &RuleMetadata) {
+    println!("{}", metadata.description);
+    println!();
+
+    print_setting("Category", metadata.category.as_folder_name());
+    print_setting("Default severity", metadata.default_severity.as_str());
+    if rule.enabled {
+        print_setting(
+            "Enabled",
+            &format!("yes ({})", rule.severity.as_str())
+                .green()
+                .to_string(),
+        );
+    } else {
+        print_setting("Enabled", "no");
+    }
+    print_setting("Fixable", yes_no(rule.fixable));
+    print_setting("TypeScript only", yes_no(metadata.typescript_only));
+    if let Some(eslint) = metadata.equivalent_eslint_rule {
+        print_setting("ESLint", eslint);
+    }
+    if let Some(biome) = metadata.equivalent_biome_rule {
+        print_setting("Biome", biome);
+    }
+    print_setting(
+        "Docs",
+        &rule_docs_url(metadata.category.as_folder_name(), metadata.name),
+    );
+
+    if !metadata.options.is_empty() {
+        println!();
+        print_section_title("Options:");
+        for option in metadata.options {
+            println!(
+                "  {} {} - {}",
+                option.name.cyan(),
+                format_option_schema(option).dimmed(),
+                option.description
+            );
+        }
+    }
+
+    if let Some(example) = metadata.example {
+        for (title, code) in [("Invalid:", example.invalid), ("Valid:", example.valid)] {
+            if code.is_empty() {
+                continue;
+            }
+            println!();
+            print_section_title(title);
+            render_code(code);
+        }
+    }
+}
+
+fn format_option_schema(option: &RuleOption) -> String {
+    match &option.schema {
+        RuleOptionSchema::Integer { default, minimum } => match minimum {
+            Some(min) => format!("(integer, default: {}, minimum: {})", default, min),
+            None => format!("(integer, default: {})", default),
+        },
+        RuleOptionSchema::Boolean { default } => format!("(boolean, default: {})", default),
+        RuleOptionSchema::String { default } => format!("(string, default: {:?})", default),
+        RuleOptionSchema::Array { items, default } => {
+            format!("({}[], default: {:?})", items, default)
+        }
+    }
+}
+
+fn render_code(source: &str) {
+    for line in source.trim_end().lines() {
+        println!("  {} {}", "│".dimmed(), line);
+    }
+}
+
+fn render_custom(rule: &RuleEntry, config: &TscannerConfig) {
+    let (message, source, include, exclude) = match rule.kind {
+        IssueRuleType::CustomRegex => {
+            let c = &config.rules.regex[&rule.name];
+            (&c.message, ("Pattern", &c.pattern), &c.include, &c.exclude)
+        }
+        IssueRuleType::CustomScript => {
+            let c = &config.rules.script[&rule.name];
+            (&c.message, ("Command", &c.command), &c.include, &c.exclude)
+        }
+        IssueRuleType::Ai => {
+            let c = &config.ai_rules[&rule.name];
+            (&c.message, ("Prompt", &c.prompt), &c.include, &c.exclude)
+        }
+        IssueRuleType::Builtin => return,
+    };
+
+    println!("{}", message);
+    println!();
+    print_setting("Severity", rule.severity.as_str());
+    print_setting(source.0, source.1);
+    if !include.is_empty() {
+        print_setting("Include", &include.join(", "));
+    }
+    if !exclude.is_empty() {
+        print_setting("Exclude", &exclude.join(", "));
+    }
+}
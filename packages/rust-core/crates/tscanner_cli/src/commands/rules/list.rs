@@ -0,0 +1,94 @@
+use anyhow::Result;
+use colored::*;
+use std::path::PathBuf;
+
+use crate::shared::{print_section_title, rule_type_icon};
+use tscanner_service::log_info;
+use tscanner_types::enums::IssueRuleType;
+
+use super::catalog::{collect_rules, kind_label, load_optional_config, RuleEntry};
+
+pub fn cmd_rules(
+    category: Option<String>,
+    enabled: bool,
+    config_path: Option<PathBuf>,
+) -> Result<()> {
+    log_info(&format!(
+        "cmd_rules: category={:?}, enabled={}, config_path={:?}",
+        category, enabled, config_path
+    ));
+
+    let config = load_optional_config(config_path)?;
+    let category = category.map(|c| c.replace('-', "_").to_lowercase());
+
+    let rules: Vec<RuleEntry> = collect_rules(config.as_ref())
+        .into_iter()
+        .filter(|r| !enabled || r.enabled)
+        .filter(|r| match &category {
+            Some(c) => r.category == Some(c.as_str()),
+            None => true,
+        })
+        .collect();
+
+    if rules.is_empty() {
+        println!(
+            "{}",
+            "No rules found matching the specified filters.".yellow()
+        );
+        return Ok(());
+    }
+
+    let name_width = rules.iter().map(|r| r.name.len()).max().unwrap_or(0);
+    let category_width = rules
+        .iter()
+        .map(|r| r.category.unwrap_or("-").len())
+        .max()
+        .unwrap_or(0);
+    let enabled_count = rules.iter().filter(|r| r.enabled).count();
+
+    for kind in [
+        IssueRuleType::Builtin,
+        IssueRuleType::CustomRegex,
+        IssueRuleType::CustomScript,
+        IssueRuleType::Ai,
+    ] {
+        let group: Vec<&RuleEntry> = rules.iter().filter(|r| r.kind == kind).collect();
+        if group.is_empty() {
+            continue;
+        }
+
+        println!();
+        print_section_title(&format!(
+            "{} {} ({})",
+            rule_type_icon(kind),
+            kind_label(kind),
+            group.len()
+        ));
+        for rule in group {
+            let line = format!(
+                "{:<name_width$}  {:<category_width$}  {:<7}  {}",
+                rule.name,
+                rule.category.unwrap_or("-"),
+                rule.severity.as_str(),
+                if rule.fixable { "fixable" } else { "" },
+                name_width = name_width,
+                category_width = category_width
+            )
+            .trim_end()
+            .to_string();
+            if rule.enabled {
+                println!("  {} {}", "✓".green(), line);
+            } else {
+                println!("  {} {}", "·".dimmed(), line.dimmed());
+            }
+        }
+    }
+
+    println!();
+    println!(
+        "{}",
+        format!("{} rules ({} enabled)", rules.len(), enabled_count).dimmed()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,6 @@
+mod catalog;
+mod explain;
+mod list;
+
+pub use explain::cmd_explain;
+pub use list::cmd_rules;
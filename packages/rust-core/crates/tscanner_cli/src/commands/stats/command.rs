@@ -0,0 +1,157 @@
+use anyhow::{Context, Result};
+use colored::*;
+use std::path::PathBuf;
+
+use crate::shared::{
+    fatal_error_and_exit, history_path, print_section_title, read_history, HistoryRecord,
+};
+use tscanner_constants::{app_name, config_dir_name};
+use tscanner_service::log_info;
+
+use super::csv::to_csv;
+use super::trends::{slowest_improving_directories, top_regressing_rules, CountChange};
+
+pub fn cmd_stats(limit: usize, csv: Option<PathBuf>, config_path: Option<PathBuf>) -> Result<()> {
+    log_info(&format!(
+        "cmd_stats: limit={}, csv={:?}, config_path={:?}",
+        limit, csv, config_path
+    ));
+
+    let root = std::env::current_dir()?;
+    let config_dir = match config_path {
+        Some(dir) if dir.is_absolute() => dir,
+        Some(dir) => root.join(dir),
+        None => root.join(config_dir_name()),
+    };
+    let path = history_path(&config_dir);
+
+    if !path.exists() {
+        fatal_error_and_exit(
+            &format!("No scan history found at {}", path.display()),
+            &[&format!(
+                "Run '{} check --record' to start recording scans.",
+                app_name()
+            )],
+        );
+    }
+
+    let records = read_history(&path)?;
+
+    if let Some(csv_path) = csv {
+        let content = to_csv(&records);
+        if csv_path.as_os_str() == "-" {
+            print!("{}", content);
+            return Ok(());
+        }
+        std::fs::write(&csv_path, content)
+            .context(format!("Failed to write CSV: {}", csv_path.display()))?;
+        println!();
+        println!(
+            "{}",
+            format!("Exported {} scans to {}", records.len(), csv_path.display()).green()
+        );
+    }
+
+    let window = &records[records.len().saturating_sub(limit.max(1))..];
+    let (Some(baseline), Some(latest)) = (window.first(), window.last()) else {
+        fatal_error_and_exit(&format!("{} is empty", path.display()), &[]);
+    };
+
+    println!();
+    println!(
+        "{} {}",
+        "Scan history:".cyan().bold(),
+        format!(
+            "{} recorded scans ({} → {})",
+            records.len(),
+            format_date(&records[0]),
+            format_date(latest)
+        )
+        .dimmed()
+    );
+
+    println!();
+    print_section_title("Trend:");
+    let mut previous: Option<&HistoryRecord> = None;
+    for record in window {
+        let delta = previous
+            .map(|p| format_delta(record.total_issues as i64 - p.total_issues as i64))
+            .unwrap_or_default();
+        println!(
+            "  {}  {}  {:>5} issues  {} errors  {} warnings  {}",
+            format_date(record).dimmed(),
+            format_commit(record).dimmed(),
+            record.total_issues,
+            format!("{:>4}", record.errors).red(),
+            format!("{:>4}", record.warnings).yellow(),
+            delta
+        );
+        previous = Some(record);
+    }
+
+    if window.len() < 2 {
+        println!();
+        println!(
+            "{}",
+            "Record at least two scans to see regressing rules and directories.".dimmed()
+        );
+        println!();
+        return Ok(());
+    }
+
+    println!();
+    print_section_title(&format!(
+        "Top regressing rules (since {}):",
+        format_date(baseline)
+    ));
+    render_changes(&top_regressing_rules(baseline, latest), "No rule regressed");
+
+    println!();
+    print_section_title("Slowest-improving directories:");
+    render_changes(
+        &slowest_improving_directories(baseline, latest),
+        "No directories with remaining issues",
+    );
+    println!();
+
+    Ok(())
+}
+
+fn render_changes(changes: &[CountChange], empty_message: &str) {
+    if changes.is_empty() {
+        println!("  {}", empty_message.dimmed());
+        return;
+    }
+
+    let width = changes.iter().map(|c| c.name.len()).max().unwrap_or(0);
+    for change in changes {
+        println!(
+            "  {:<width$}  {}  {}",
+            change.name,
+            format_delta(change.delta()),
+            format!("({} → {})", change.before, change.after).dimmed(),
+            width = width
+        );
+    }
+}
+
+fn format_date(record: &HistoryRecord) -> String {
+    record
+        .timestamp
+        .get(..16)
+        .map(|t| t.replace('T', " "))
+        .unwrap_or_else(|| record.timestamp.clone())
+}
+
+fn format_commit(record: &HistoryRecord) -> String {
+    let commit = record.commit.as_deref().unwrap_or("-");
+    format!("{:<7}", commit.get(..7).unwrap_or(commit))
+}
+
+fn format_delta(delta: i64) -> String {
+    match delta {
+        d if d > 0 => format!("+{}", d).red().to_string(),
+        d if d < 0 => d.to_string().green().to_string(),
+        _ => "±0".dimmed().to_string(),
+    }
+}
@@ -0,0 +1,26 @@
+use crate::shared::HistoryRecord;
+
+const HEADER: &str = "timestamp,commit,total_files,files_with_issues,total_issues,errors,warnings,infos,hints,duration_ms";
+
+pub(super) fn to_csv(records: &[HistoryRecord]) -> String {
+    let mut output = String::from(HEADER);
+    output.push('\n');
+
+    for record in records {
+        output.push_str(&format!(
+            "{},{},{},{},{},{},{},{},{},{}\n",
+            record.timestamp,
+            record.commit.as_deref().unwrap_or(""),
+            record.total_files,
+            record.files_with_issues,
+            record.total_issues,
+            record.errors,
+            record.warnings,
+            record.infos,
+            record.hints,
+            record.duration_ms
+        ));
+    }
+
+    output
+}
@@ -0,0 +1,5 @@
+mod command;
+mod csv;
+mod trends;
+
+pub use command::cmd_stats;
@@ -0,0 +1,63 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::shared::HistoryRecord;
+
+const TOP_ENTRIES: usize = 5;
+
+pub(super) struct CountChange {
+    pub name: String,
+    pub before: usize,
+    pub after: usize,
+}
+
+impl CountChange {
+    pub fn delta(&self) -> i64 {
+        self.after as i64 - self.before as i64
+    }
+}
+
+fn changes(
+    baseline: &BTreeMap<String, usize>,
+    latest: &BTreeMap<String, usize>,
+) -> Vec<CountChange> {
+    let names: BTreeSet<&String> = baseline.keys().chain(latest.keys()).collect();
+    names
+        .into_iter()
+        .map(|name| CountChange {
+            name: name.clone(),
+            before: baseline.get(name).copied().unwrap_or(0),
+            after: latest.get(name).copied().unwrap_or(0),
+        })
+        .collect()
+}
+
+pub(super) fn top_regressing_rules(
+    baseline: &HistoryRecord,
+    latest: &HistoryRecord,
+) -> Vec<CountChange> {
+    let mut regressing: Vec<CountChange> = changes(&baseline.rules, &latest.rules)
+        .into_iter()
+        .filter(|c| c.delta() > 0)
+        .collect();
+    regressing.sort_by(|a, b| b.delta().cmp(&a.delta()).then(a.name.cmp(&b.name)));
+    regressing.truncate(TOP_ENTRIES);
+    regressing
+}
+
+pub(super) fn slowest_improving_directories(
+    baseline: &HistoryRecord,
+    latest: &HistoryRecord,
+) -> Vec<CountChange> {
+    let mut directories: Vec<CountChange> = changes(&baseline.directories, &latest.directories)
+        .into_iter()
+        .filter(|c| c.after > 0)
+        .collect();
+    directories.sort_by(|a, b| {
+        b.delta()
+            .cmp(&a.delta())
+            .then(b.after.cmp(&a.after))
+            .then(a.name.cmp(&b.name))
+    });
+    directories.truncate(TOP_ENTRIES);
+    directories
+}
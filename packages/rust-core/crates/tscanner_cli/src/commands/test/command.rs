@@ -0,0 +1,277 @@
+use anyhow::{Context, Result};
+use colored::*;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use walkdir::WalkDir;
+
+use crate::config_loader::load_config_with_custom;
+use crate::shared::{fatal_error_and_exit, print_section_title};
+use tscanner_cache::{AiCache, FileCache, ScriptCache};
+use tscanner_config::TscannerConfig;
+use tscanner_constants::{app_name, config_dir_name, icon_error, icon_success, rule_tests_dir};
+use tscanner_scanner::Scanner;
+use tscanner_service::log_info;
+use tscanner_types::Issue;
+
+use super::fixture::{compare, parse_expectations, Failure};
+use super::snapshot::format_snapshot;
+
+const SNAPSHOT_EXTENSION: &str = "snap";
+
+#[derive(Default)]
+struct TestSummary {
+    passed: usize,
+    failed: usize,
+    updated: usize,
+}
+
+pub fn cmd_test(rules: Vec<String>, update: bool, config_path: Option<PathBuf>) -> Result<()> {
+    log_info(&format!(
+        "cmd_test: rules={:?}, update={}, config_path={:?}",
+        rules, update, config_path
+    ));
+
+    let root = std::env::current_dir()?;
+    let Some((config, resolved_config_path, _)) = load_config_with_custom(&root, config_path)?
+    else {
+        fatal_error_and_exit(
+            "No TScanner configuration found",
+            &[&format!(
+                "Run '{} init' to create a configuration.",
+                app_name()
+            )],
+        );
+    };
+
+    let config_dir = Path::new(&resolved_config_path)
+        .parent()
+        .map(|p| p.to_path_buf())
+        .unwrap_or_else(|| root.clone());
+    let tests_dir = config_dir.join(rule_tests_dir());
+
+    let mut rule_dirs: Vec<(String, PathBuf)> = match std::fs::read_dir(&tests_dir) {
+        Ok(entries) => entries
+            .filter_map(|e| e.ok())
+            .filter(|e| e.path().is_dir())
+            .map(|e| (e.file_name().to_string_lossy().to_string(), e.path()))
+            .filter(|(name, _)| rules.is_empty() || rules.contains(name))
+            .collect(),
+        Err(_) => Vec::new(),
+    };
+    rule_dirs.sort();
+
+    if rule_dirs.is_empty() {
+        fatal_error_and_exit(
+            &format!("No rule tests found in {}", tests_dir.display()),
+            &[&format!(
+                "Add fixtures to {}/{}/<rule-name>/ with '// expect: <rule-name>' comments.",
+                config_dir_name(),
+                rule_tests_dir()
+            )],
+        );
+    }
+
+    let mut summary = TestSummary::default();
+
+    for (rule, dir) in &rule_dirs {
+        println!();
+        print_section_title(&format!("{}:", rule));
+
+        let scanner = match build_rule_scanner(&config, rule, &root, &config_dir) {
+            Ok(scanner) => scanner,
+            Err(e) => {
+                println!("  {} {}", icon_error().red(), e.to_string().red());
+                summary.failed += 1;
+                continue;
+            }
+        };
+
+        for fixture in collect_fixtures(dir) {
+            run_fixture(&scanner, rule, &root, &fixture, update, &mut summary)?;
+        }
+    }
+
+    println!();
+    print_section_title("Results:");
+    let mut parts = vec![
+        format!("{} passed", summary.passed).green().to_string(),
+        format!("{} failed", summary.failed).red().to_string(),
+    ];
+    if update {
+        parts.push(format!("{} snapshots updated", summary.updated));
+    }
+    println!("  {}", parts.join(", "));
+    println!();
+
+    if summary.failed > 0 {
+        std::process::exit(1);
+    }
+
+    Ok(())
+}
+
+fn build_rule_scanner(
+    config: &TscannerConfig,
+    rule: &str,
+    root: &Path,
+    config_dir: &Path,
+) -> Result<Scanner> {
+    if config.ai_rules.contains_key(rule) {
+        anyhow::bail!("AI rules cannot be tested with fixtures");
+    }
+
+    let mut test_config = config.clone();
+    test_config.ai_rules.clear();
+    test_config.files.include = vec!["**/*".to_string()];
+    test_config.files.exclude.clear();
+    test_config.rules.builtin.retain(|name, _| name == rule);
+    test_config.rules.regex.retain(|name, _| name == rule);
+    test_config.rules.script.retain(|name, _| name == rule);
+
+    for rule_config in test_config.rules.builtin.values_mut() {
+        rule_config.include.clear();
+        rule_config.exclude.clear();
+    }
+    for rule_config in test_config.rules.regex.values_mut() {
+        rule_config.include.clear();
+        rule_config.exclude.clear();
+    }
+    for rule_config in test_config.rules.script.values_mut() {
+        rule_config.include = vec!["**/*".to_string()];
+        rule_config.exclude.clear();
+    }
+
+    if test_config.rules.builtin.is_empty()
+        && test_config.rules.regex.is_empty()
+        && test_config.rules.script.is_empty()
+    {
+        anyhow::bail!("Rule '{}' is not defined in the configuration", rule);
+    }
+
+    Scanner::with_caches_and_config_dir(
+        test_config,
+        Arc::new(FileCache::new()),
+        Arc::new(AiCache::new()),
+        Arc::new(ScriptCache::new()),
+        root.to_path_buf(),
+        config_dir.to_path_buf(),
+    )
+    .map_err(|e| anyhow::anyhow!("{}", e))
+}
+
+fn collect_fixtures(dir: &Path) -> Vec<PathBuf> {
+    WalkDir::new(dir)
+        .sort_by_file_name()
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file())
+        .map(|e| e.into_path())
+        .filter(|p| p.extension().and_then(|e| e.to_str()) != Some(SNAPSHOT_EXTENSION))
+        .collect()
+}
+
+fn run_fixture(
+    scanner: &Scanner,
+    rule: &str,
+    root: &Path,
+    fixture: &Path,
+    update: bool,
+    summary: &mut TestSummary,
+) -> Result<()> {
+    let content = std::fs::read_to_string(fixture)
+        .context(format!("Failed to read fixture: {}", fixture.display()))?;
+
+    let mut issues: Vec<Issue> = scanner
+        .scan_content(fixture, &content)
+        .map(|r| r.issues)
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|i| i.rule == rule)
+        .collect();
+    issues.sort_by_key(|i| (i.line, i.column));
+
+    let mut failures = compare(parse_expectations(&content, rule), &issues);
+
+    let file_name = fixture
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let ext = fixture
+        .extension()
+        .map(|e| e.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let rendered = format_snapshot(&content, &issues, &file_name, &ext);
+    let snapshot_path = PathBuf::from(format!("{}.{}", fixture.display(), SNAPSHOT_EXTENSION));
+    let existing = std::fs::read_to_string(&snapshot_path).ok();
+
+    let mut snapshot_written = false;
+    if update {
+        if existing.as_deref() != Some(rendered.as_str()) {
+            std::fs::write(&snapshot_path, &rendered).context(format!(
+                "Failed to write snapshot: {}",
+                snapshot_path.display()
+            ))?;
+            summary.updated += 1;
+            snapshot_written = true;
+        }
+    } else if existing.is_some_and(|s| s != rendered) {
+        failures.push(Failure::SnapshotMismatch);
+    }
+
+    let relative = pathdiff::diff_paths(fixture, root).unwrap_or_else(|| fixture.to_path_buf());
+    let label = relative.display().to_string();
+
+    if failures.is_empty() {
+        summary.passed += 1;
+        let suffix = if snapshot_written {
+            " (snapshot updated)".dimmed().to_string()
+        } else {
+            String::new()
+        };
+        println!("  {} {}{}", icon_success().green(), label, suffix);
+        return Ok(());
+    }
+
+    summary.failed += 1;
+    println!("  {} {}", icon_error().red(), label.bold());
+    for failure in &failures {
+        println!("    {}", format_failure(failure));
+    }
+
+    Ok(())
+}
+
+fn format_location(line: usize, column: Option<usize>) -> String {
+    match column {
+        Some(column) => format!("{}:{}", line, column),
+        None => line.to_string(),
+    }
+}
+
+fn format_failure(failure: &Failure) -> String {
+    match failure {
+        Failure::Missing(expected) => format!(
+            "{} expected issue at line {} was not reported",
+            "missing".red(),
+            format_location(expected.line, expected.column)
+        ),
+        Failure::Unexpected(issue) => format!(
+            "{} {}:{} {}",
+            "unexpected".red(),
+            issue.line,
+            issue.column,
+            issue.message
+        ),
+        Failure::Misplaced(expected, issue) => format!(
+            "{} expected line {}, found {}:{}",
+            "mis-positioned".yellow(),
+            format_location(expected.line, expected.column),
+            issue.line,
+            issue.column
+        ),
+        Failure::SnapshotMismatch => format!(
+            "{} output changed (run with --update to accept)",
+            "snapshot".yellow()
+        ),
+    }
+}
@@ -0,0 +1,198 @@
+use regex::Regex;
+use tscanner_types::Issue;
+
+pub(super) struct Expectation {
+    pub line: usize,
+    pub column: Option<usize>,
+}
+
+pub(super) enum Failure {
+    Missing(Expectation),
+    Unexpected(Issue),
+    Misplaced(Expectation, Issue),
+    SnapshotMismatch,
+}
+
+pub(super) fn parse_expectations(source: &str, rule: &str) -> Vec<Expectation> {
+    let re = Regex::new(r"(?://|#|/\*)\s*expect:\s*(.*?)\s*(?:\*/\s*)?$").unwrap();
+    let mut expectations = Vec::new();
+
+    for (index, line) in source.lines().enumerate() {
+        let Some(captures) = re.captures(line) else {
+            continue;
+        };
+        for entry in captures[1].split(',').map(str::trim) {
+            let (name, column) = match entry.split_once(':') {
+                Some((name, column)) => (name.trim(), column.trim().parse().ok()),
+                None => (entry, None),
+            };
+            if name == rule {
+                expectations.push(Expectation {
+                    line: index + 1,
+                    column,
+                });
+            }
+        }
+    }
+
+    expectations
+}
+
+fn matches(expectation: &Expectation, issue: &Issue) -> bool {
+    expectation.line == issue.line && expectation.column.is_none_or(|c| c == issue.column)
+}
+
+pub(super) fn compare(expectations: Vec<Expectation>, issues: &[Issue]) -> Vec<Failure> {
+    let mut remaining: Vec<Issue> = issues.to_vec();
+    let mut unmatched = Vec::new();
+
+    for expectation in expectations {
+        match remaining.iter().position(|i| matches(&expectation, i)) {
+            Some(index) => {
+                remaining.remove(index);
+            }
+            None => unmatched.push(expectation),
+        }
+    }
+
+    let mut failures = Vec::new();
+    for expectation in unmatched {
+        let nearby = remaining
+            .iter()
+            .position(|i| i.line.abs_diff(expectation.line) <= 1);
+        match nearby {
+            Some(index) => failures.push(Failure::Misplaced(expectation, remaining.remove(index))),
+            None => failures.push(Failure::Missing(expectation)),
+        }
+    }
+    failures.extend(remaining.into_iter().map(Failure::Unexpected));
+
+    failures
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+
+    fn issue(line: usize, column: usize) -> Issue {
+        Issue::new(
+            "no-console",
+            PathBuf::from("fixture.ts"),
+            line,
+            column,
+            column + 5,
+            "Unexpected console".to_string(),
+        )
+    }
+
+    fn expect(line: usize, column: Option<usize>) -> Expectation {
+        Expectation { line, column }
+    }
+
+    fn positions(expectations: &[Expectation]) -> Vec<(usize, Option<usize>)> {
+        expectations.iter().map(|e| (e.line, e.column)).collect()
+    }
+
+    #[test]
+    fn parses_comment_styles_and_columns() {
+        let source = "\
+console.log(a); // expect: no-console
+console.log(b); /* expect: no-console:3 */
+print(c)  # expect: no-console
+const d = 1;
+";
+
+        let expectations = parse_expectations(source, "no-console");
+
+        assert_eq!(
+            positions(&expectations),
+            vec![(1, None), (2, Some(3)), (3, None)]
+        );
+    }
+
+    #[test]
+    fn parses_multiple_issues_on_one_line() {
+        let source =
+            "console.log(a); console.log(b); // expect: no-console:1, no-var, no-console:17\n";
+
+        let expectations = parse_expectations(source, "no-console");
+
+        assert_eq!(positions(&expectations), vec![(1, Some(1)), (1, Some(17))]);
+        assert_eq!(
+            positions(&parse_expectations(source, "no-var")),
+            vec![(1, None)]
+        );
+    }
+
+    #[test]
+    fn skips_malformed_annotations_and_columns() {
+        let source = "\
+a(); // expect no-console
+b(); // expect:
+c(); // expects: no-console
+d(); // expect: no-consoles
+e(); // expect: no-console:abc
+";
+
+        let expectations = parse_expectations(source, "no-console");
+
+        assert_eq!(positions(&expectations), vec![(5, None)]);
+    }
+
+    #[test]
+    fn matching_issues_produce_no_failures() {
+        let failures = compare(
+            vec![expect(1, None), expect(3, Some(5))],
+            &[issue(1, 9), issue(3, 5)],
+        );
+
+        assert!(failures.is_empty());
+    }
+
+    #[test]
+    fn reports_missing_expectation() {
+        let failures = compare(vec![expect(4, None)], &[]);
+
+        assert!(matches!(failures.as_slice(), [Failure::Missing(e)] if e.line == 4));
+    }
+
+    #[test]
+    fn reports_unexpected_issue() {
+        let failures = compare(vec![expect(1, None)], &[issue(1, 1), issue(6, 2)]);
+
+        assert!(matches!(
+            failures.as_slice(),
+            [Failure::Unexpected(i)] if i.line == 6 && i.column == 2
+        ));
+    }
+
+    #[test]
+    fn reports_misplaced_issue_on_adjacent_line_or_wrong_column() {
+        let failures = compare(
+            vec![expect(2, None), expect(5, Some(1))],
+            &[issue(3, 1), issue(5, 7)],
+        );
+
+        assert_eq!(failures.len(), 2);
+        assert!(matches!(
+            &failures[0],
+            Failure::Misplaced(e, i) if e.line == 2 && i.line == 3
+        ));
+        assert!(matches!(
+            &failures[1],
+            Failure::Misplaced(e, i) if e.column == Some(1) && i.column == 7
+        ));
+    }
+
+    #[test]
+    fn distant_issue_is_missing_plus_unexpected() {
+        let failures = compare(vec![expect(2, None)], &[issue(9, 1)]);
+
+        assert!(matches!(
+            failures.as_slice(),
+            [Failure::Missing(e), Failure::Unexpected(i)] if e.line == 2 && i.line == 9
+        ));
+    }
+}
@@ -0,0 +1,5 @@
+mod command;
+mod fixture;
+mod snapshot;
+
+pub use command::cmd_test;
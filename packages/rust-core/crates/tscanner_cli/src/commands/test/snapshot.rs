@@ -0,0 +1,59 @@
+use tscanner_types::Issue;
+
+pub(super) fn format_snapshot(
+    source: &str,
+    issues: &[Issue],
+    file_name: &str,
+    ext: &str,
+) -> String {
+    let mut output = String::new();
+
+    output.push_str(&format!("# Input\n```{}\n", ext));
+    output.push_str(source);
+    if !source.ends_with('\n') {
+        output.push('\n');
+    }
+    output.push_str("```\n\n");
+
+    output.push_str("# Diagnostics\n");
+
+    if issues.is_empty() {
+        output.push_str("No issues found.\n");
+        return output;
+    }
+
+    let lines: Vec<&str> = source.lines().collect();
+    for issue in issues {
+        output.push_str(&format!(
+            "```\n{}:{}:{} {} ━━━━━━━━━━━━━━━━━━━━\n\n",
+            file_name, issue.line, issue.column, issue.rule
+        ));
+        output.push_str(&format!("  ! {}\n\n", issue.message));
+
+        if issue.line > 0 && issue.line <= lines.len() {
+            let line_idx = issue.line - 1;
+
+            if line_idx > 0 {
+                output.push_str(&format!(
+                    "    {} │ {}\n",
+                    issue.line - 1,
+                    lines[line_idx - 1]
+                ));
+            }
+
+            output.push_str(&format!("  > {} │ {}\n", issue.line, lines[line_idx]));
+
+            if line_idx + 1 < lines.len() {
+                output.push_str(&format!(
+                    "    {} │ {}\n",
+                    issue.line + 1,
+                    lines[line_idx + 1]
+                ));
+            }
+        }
+
+        output.push_str("\n```\n\n");
+    }
+
+    output
+}
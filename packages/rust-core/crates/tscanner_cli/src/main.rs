@@ -7,8 +7,11 @@ mod commands;
 mod config_loader;
 mod shared;
 
-use commands::{cmd_check, cmd_init, cmd_registry, validate};
-use tscanner_cli::{Cli, Commands};
+use commands::{
+    cmd_cache, cmd_check, cmd_explain, cmd_init, cmd_migrate, cmd_registry, cmd_rules, cmd_stats,
+    cmd_test, validate,
+};
+use tscanner_cli::{CheckArgs, Cli, Commands};
 use tscanner_service::init_logger;
 
 fn main() -> Result<()> {
@@ -21,24 +24,37 @@ fn main() -> Result<()> {
     let cli = Cli::parse();
 
     match cli.command {
-        Some(Commands::Check {
-            paths,
-            branch,
-            staged,
-            uncommitted,
-            include_ai,
-            only_ai,
-            glob,
-            rule,
-            severity,
-            kind,
-            group_by,
-            format,
-            json_output,
-            no_cache,
-            continue_on_error,
-            config_path,
-        }) => {
+        Some(Commands::Check(args)) => {
+            let CheckArgs {
+                paths,
+                branch,
+                no_merge_base,
+                staged,
+                uncommitted,
+                range,
+                diff,
+                watch,
+                include_ai,
+                only_ai,
+                glob,
+                rule,
+                severity,
+                kind,
+                author,
+                since,
+                group_by,
+                format,
+                json_output,
+                report,
+                link_template,
+                code_frame,
+                blame,
+                record,
+                no_cache,
+                cache_dir,
+                continue_on_error,
+                config_path,
+            } = *args;
             let paths = if paths.is_empty() {
                 vec![PathBuf::from(".")]
             } else {
@@ -47,24 +63,60 @@ fn main() -> Result<()> {
             cmd_check(
                 &paths,
                 no_cache,
+                cache_dir,
                 group_by,
                 Some(format),
                 json_output,
+                report,
+                link_template,
+                code_frame,
+                blame,
                 branch,
+                no_merge_base,
                 staged,
                 uncommitted,
+                range,
+                diff,
                 glob,
                 rule,
                 severity,
                 kind,
+                author,
+                since,
                 continue_on_error,
                 include_ai,
                 only_ai,
                 config_path,
+                watch,
+                record,
             )
         }
         Some(Commands::Init { full }) => cmd_init(&PathBuf::from("."), full),
         Some(Commands::Validate { config_path }) => validate(config_path),
+        Some(Commands::Rules {
+            category,
+            enabled,
+            config_path,
+        }) => cmd_rules(category, enabled, config_path),
+        Some(Commands::Explain { name, config_path }) => cmd_explain(&name, config_path),
+        Some(Commands::Migrate {
+            from,
+            input,
+            force,
+            dry_run,
+            config_path,
+        }) => cmd_migrate(from, input, force, dry_run, config_path),
+        Some(Commands::Stats {
+            limit,
+            csv,
+            config_path,
+        }) => cmd_stats(limit, csv, config_path),
+        Some(Commands::Test {
+            rules,
+            update,
+            config_path,
+        }) => cmd_test(rules, update, config_path),
+        Some(Commands::Cache { command }) => cmd_cache(command),
         Some(Commands::Lsp) => {
             tscanner_service::log_info("LSP server starting");
             let result = tscanner_lsp::run_lsp_server().map_err(|e| anyhow::anyhow!("{}", e));
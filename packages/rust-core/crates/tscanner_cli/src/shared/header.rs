@@ -7,9 +7,25 @@ use super::section::{print_section_header, print_setting, print_setting_value};
 #[derive(Clone)]
 pub enum ScanMode {
     Codebase,
-    Staged { file_count: usize },
-    Uncommitted { file_count: usize },
-    Branch { name: String, file_count: usize },
+    Staged {
+        file_count: usize,
+    },
+    Uncommitted {
+        file_count: usize,
+    },
+    Branch {
+        name: String,
+        merge_base: Option<String>,
+        file_count: usize,
+    },
+    Range {
+        spec: String,
+        file_count: usize,
+    },
+    Diff {
+        source: String,
+        file_count: usize,
+    },
 }
 
 pub struct ScanConfig {
@@ -37,14 +53,23 @@ pub fn render_header(config: &ScanConfig) {
             ScanMode::Staged { .. } => "staged",
             ScanMode::Uncommitted { .. } => "uncommitted",
             ScanMode::Branch { .. } => "branch",
+            ScanMode::Range { .. } => "range",
+            ScanMode::Diff { .. } => "diff",
         };
         let format_str = match config.format {
             OutputFormat::Text => "text",
             OutputFormat::Json => "json",
+            OutputFormat::Sarif => "sarif",
+            OutputFormat::Junit => "junit",
+            OutputFormat::Checkstyle => "checkstyle",
+            OutputFormat::Gitlab => "gitlab",
+            OutputFormat::Markdown => "markdown",
+            OutputFormat::Github => "github",
         };
         let group_str = match config.group_by {
             GroupMode::Rule => "rule",
             GroupMode::File => "file",
+            GroupMode::Author => "author",
         };
         let ai_mode_str = match config.ai_mode {
             AiExecutionMode::Ignore => "ignore",
@@ -65,8 +90,23 @@ pub fn render_header(config: &ScanConfig) {
             ScanMode::Uncommitted { file_count } => {
                 print_setting_value("Uncommitted files", file_count);
             }
-            ScanMode::Branch { name, file_count } => {
+            ScanMode::Branch {
+                name,
+                merge_base,
+                file_count,
+            } => {
                 print_setting("Target branch", name);
+                if let Some(sha) = merge_base {
+                    print_setting("Merge base", sha.get(..7).unwrap_or(sha));
+                }
+                print_setting_value("Changed files", file_count);
+            }
+            ScanMode::Range { spec, file_count } => {
+                print_setting("Range", spec);
+                print_setting_value("Changed files", file_count);
+            }
+            ScanMode::Diff { source, file_count } => {
+                print_setting("Diff", source);
                 print_setting_value("Changed files", file_count);
             }
             ScanMode::Codebase => {}
@@ -76,12 +116,7 @@ pub fn render_header(config: &ScanConfig) {
         print_setting("Group by", group_str);
         print_setting("AI mode", ai_mode_str);
         if let Some(ref provider) = config.ai_provider {
-            let provider_str = match provider {
-                AiProvider::Claude => "claude",
-                AiProvider::Gemini => "gemini",
-                AiProvider::Custom => "custom",
-            };
-            print_setting("AI provider", provider_str);
+            print_setting("AI provider", provider.as_str());
         }
         print_setting("Cache", cache_str);
         print_setting_value("Continue on error", config.continue_on_error);
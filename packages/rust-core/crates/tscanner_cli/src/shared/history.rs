@@ -0,0 +1,41 @@
+use anyhow::{Context, Result};
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use tscanner_cli_output::HistoryRecord;
+use tscanner_constants::history_file_name;
+
+pub fn history_path(config_dir: &Path) -> PathBuf {
+    config_dir.join(history_file_name())
+}
+
+pub fn append_history(path: &Path, record: &HistoryRecord) -> Result<()> {
+    let line = serde_json::to_string(record)?;
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .context(format!("Failed to open history file: {}", path.display()))?;
+    writeln!(file, "{}", line)
+        .context(format!("Failed to write history file: {}", path.display()))?;
+    Ok(())
+}
+
+pub fn read_history(path: &Path) -> Result<Vec<HistoryRecord>> {
+    let content = std::fs::read_to_string(path)
+        .context(format!("Failed to read history file: {}", path.display()))?;
+
+    content
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| {
+            serde_json::from_str(line).context(format!(
+                "Invalid history record at {}:{}",
+                path.display(),
+                index + 1
+            ))
+        })
+        .collect()
+}
@@ -1,9 +1,12 @@
 pub mod header;
+pub mod history;
 pub mod section;
 
 pub use header::*;
+pub use history::*;
 pub use section::*;
 pub use tscanner_cli_output::{
-    format_duration, rule_type_icon, severity_icon, FormattedOutput, OutputFileGroup,
-    OutputRuleGroup, OutputSummary, RulesBreakdown, SummaryStats,
+    format_duration, rule_type_icon, severity_icon, FormattedOutput, HistoryRecord,
+    OutputAuthorGroup, OutputFileGroup, OutputRuleGroup, OutputSummary, RulesBreakdown,
+    SummaryStats,
 };
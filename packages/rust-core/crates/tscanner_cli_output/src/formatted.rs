@@ -1,13 +1,53 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::path::Path;
 
 use serde::Serialize;
-use tscanner_types::{IssueRuleType, ScanResult, Severity};
+use tscanner_types::{Issue, IssueRuleType, ScanResult, Severity};
 
 use crate::types::{
-    OutputFileGroup, OutputIssue, OutputRuleGroup, OutputRuleIssue, OutputSummary, RulesBreakdown,
+    OutputAuthorGroup, OutputFileGroup, OutputIssue, OutputRelatedLocation, OutputRuleGroup,
+    OutputRuleIssue, OutputSummary, RulesBreakdown,
 };
 
+const UNKNOWN_AUTHOR: &str = "Unknown";
+
+fn build_related(root: &Path, issue: &Issue) -> Vec<OutputRelatedLocation> {
+    issue
+        .related
+        .iter()
+        .map(|related| OutputRelatedLocation {
+            file: pathdiff::diff_paths(&related.file, root)
+                .unwrap_or_else(|| related.file.clone())
+                .display()
+                .to_string(),
+            line: related.range.start_line,
+            column: related.range.start_col,
+            end_line: related.range.end_line,
+            end_column: related.range.end_col,
+            message: related.message.clone(),
+        })
+        .collect()
+}
+
+fn build_output_issue(root: &Path, issue: &Issue) -> OutputIssue {
+    OutputIssue {
+        rule: issue.rule.clone(),
+        severity: issue.severity.as_str().to_string(),
+        line: issue.line,
+        column: issue.column,
+        end_line: issue.last_line(),
+        end_column: issue.end_column,
+        message: issue.message.clone(),
+        line_text: issue.line_text.clone(),
+        rule_type: issue.rule_type,
+        fix_title: issue.fix.as_ref().map(|fix| fix.title.clone()),
+        note: issue.note.clone(),
+        docs_url: issue.docs_url.clone(),
+        related: build_related(root, issue),
+        blame: issue.blame.clone(),
+    }
+}
+
 pub struct SummaryStats {
     pub total_issues: usize,
     pub error_count: usize,
@@ -67,6 +107,10 @@ pub enum FormattedOutput {
         rules: Vec<OutputRuleGroup>,
         summary: OutputSummary,
     },
+    ByAuthor {
+        authors: Vec<OutputAuthorGroup>,
+        summary: OutputSummary,
+    },
 }
 
 impl FormattedOutput {
@@ -86,15 +130,7 @@ impl FormattedOutput {
                     issues: file_result
                         .issues
                         .iter()
-                        .map(|issue| OutputIssue {
-                            rule: issue.rule.clone(),
-                            severity: issue.severity.as_str().to_string(),
-                            line: issue.line,
-                            column: issue.column,
-                            message: issue.message.clone(),
-                            line_text: issue.line_text.clone(),
-                            rule_type: issue.rule_type,
-                        })
+                        .map(|issue| build_output_issue(root, issue))
                         .collect(),
                 }
             })
@@ -124,8 +160,15 @@ impl FormattedOutput {
                     file: relative_path.display().to_string(),
                     line: issue.line,
                     column: issue.column,
+                    end_line: issue.last_line(),
+                    end_column: issue.end_column,
                     severity: issue.severity.as_str().to_string(),
                     line_text: issue.line_text.clone(),
+                    fix_title: issue.fix.as_ref().map(|fix| fix.title.clone()),
+                    note: issue.note.clone(),
+                    docs_url: issue.docs_url.clone(),
+                    related: build_related(root, issue),
+                    blame: issue.blame.clone(),
                 });
             }
         }
@@ -146,6 +189,51 @@ impl FormattedOutput {
         FormattedOutput::ByRule { rules, summary }
     }
 
+    pub fn build_by_author(root: &Path, result: &ScanResult, stats: &SummaryStats) -> Self {
+        let summary = Self::build_summary(result, stats);
+
+        let mut authors_map: BTreeMap<(String, String), BTreeMap<String, Vec<OutputIssue>>> =
+            BTreeMap::new();
+
+        for file_result in &result.files {
+            let relative_path = pathdiff::diff_paths(&file_result.file, root)
+                .unwrap_or_else(|| file_result.file.clone());
+
+            for issue in &file_result.issues {
+                let key = match issue.blame {
+                    Some(ref blame) => (blame.author.clone(), blame.email.clone()),
+                    None => (UNKNOWN_AUTHOR.to_string(), String::new()),
+                };
+                authors_map
+                    .entry(key)
+                    .or_default()
+                    .entry(relative_path.display().to_string())
+                    .or_default()
+                    .push(build_output_issue(root, issue));
+            }
+        }
+
+        let mut authors: Vec<OutputAuthorGroup> = authors_map
+            .into_iter()
+            .map(|((author, email), files)| {
+                let files: Vec<OutputFileGroup> = files
+                    .into_iter()
+                    .map(|(file, issues)| OutputFileGroup { file, issues })
+                    .collect();
+                OutputAuthorGroup {
+                    author,
+                    email,
+                    count: files.iter().map(|f| f.issues.len()).sum(),
+                    files,
+                }
+            })
+            .collect();
+
+        authors.sort_by_key(|a| std::cmp::Reverse(a.count));
+
+        FormattedOutput::ByAuthor { authors, summary }
+    }
+
     fn build_summary(result: &ScanResult, stats: &SummaryStats) -> OutputSummary {
         let files_with_issues = result.files.iter().filter(|f| !f.issues.is_empty()).count();
         let triggered_breakdown = Self::compute_triggered_breakdown(result);
@@ -198,6 +286,7 @@ impl FormattedOutput {
         match self {
             FormattedOutput::ByFile { summary, .. } => summary,
             FormattedOutput::ByRule { summary, .. } => summary,
+            FormattedOutput::ByAuthor { summary, .. } => summary,
         }
     }
 
@@ -208,14 +297,21 @@ impl FormattedOutput {
     pub fn files(&self) -> Option<&Vec<OutputFileGroup>> {
         match self {
             FormattedOutput::ByFile { files, .. } => Some(files),
-            FormattedOutput::ByRule { .. } => None,
+            FormattedOutput::ByRule { .. } | FormattedOutput::ByAuthor { .. } => None,
         }
     }
 
     pub fn rules(&self) -> Option<&Vec<OutputRuleGroup>> {
         match self {
-            FormattedOutput::ByFile { .. } => None,
             FormattedOutput::ByRule { rules, .. } => Some(rules),
+            FormattedOutput::ByFile { .. } | FormattedOutput::ByAuthor { .. } => None,
+        }
+    }
+
+    pub fn authors(&self) -> Option<&Vec<OutputAuthorGroup>> {
+        match self {
+            FormattedOutput::ByAuthor { authors, .. } => Some(authors),
+            FormattedOutput::ByFile { .. } | FormattedOutput::ByRule { .. } => None,
         }
     }
 }
@@ -0,0 +1,72 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+use tscanner_types::ScanResult;
+
+use crate::types::OutputSummary;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HistoryRecord {
+    pub timestamp: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub commit: Option<String>,
+    pub total_files: usize,
+    pub files_with_issues: usize,
+    pub total_issues: usize,
+    pub errors: usize,
+    pub warnings: usize,
+    pub infos: usize,
+    pub hints: usize,
+    pub duration_ms: u128,
+    #[serde(default)]
+    pub rules: BTreeMap<String, usize>,
+    #[serde(default)]
+    pub directories: BTreeMap<String, usize>,
+}
+
+impl HistoryRecord {
+    pub fn from_summary(
+        root: &Path,
+        result: &ScanResult,
+        summary: &OutputSummary,
+        timestamp: String,
+        commit: Option<String>,
+    ) -> Self {
+        let mut rules: BTreeMap<String, usize> = BTreeMap::new();
+        let mut directories: BTreeMap<String, usize> = BTreeMap::new();
+
+        for file_result in &result.files {
+            if file_result.issues.is_empty() {
+                continue;
+            }
+            for issue in &file_result.issues {
+                *rules.entry(issue.rule.clone()).or_default() += 1;
+            }
+
+            let relative = pathdiff::diff_paths(&file_result.file, root)
+                .unwrap_or_else(|| file_result.file.clone());
+            let directory = relative
+                .parent()
+                .map(|p| p.display().to_string())
+                .filter(|p| !p.is_empty())
+                .unwrap_or_else(|| ".".to_string());
+            *directories.entry(directory).or_default() += file_result.issues.len();
+        }
+
+        Self {
+            timestamp,
+            commit,
+            total_files: summary.total_files,
+            files_with_issues: summary.files_with_issues,
+            total_issues: summary.total_issues,
+            errors: summary.errors,
+            warnings: summary.warnings,
+            infos: summary.infos,
+            hints: summary.hints,
+            duration_ms: summary.duration_ms,
+            rules,
+            directories,
+        }
+    }
+}
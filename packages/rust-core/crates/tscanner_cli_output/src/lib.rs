@@ -1,11 +1,18 @@
 mod display;
 mod formatted;
+mod history;
 mod plain_text;
+mod reporters;
 mod types;
 
 pub use display::{format_duration, rule_type_icon, severity_icon};
 pub use formatted::{FormattedOutput, SummaryStats};
+pub use history::HistoryRecord;
+pub use reporters::{
+    get_reporter, CheckstyleReporter, GithubReporter, GitlabReporter, JsonReporter, JunitReporter,
+    MarkdownReporter, ReportContext, ReportFormat, Reporter, SarifLog, SarifReporter,
+};
 pub use types::{
-    GroupMode, IssuePart, OutputFileGroup, OutputIssue, OutputRuleGroup, OutputRuleIssue,
-    OutputSummary, RulesBreakdown,
+    GroupMode, IssuePart, OutputAuthorGroup, OutputFileGroup, OutputIssue, OutputRelatedLocation,
+    OutputRuleGroup, OutputRuleIssue, OutputSummary, RuleDescriptor, RulesBreakdown,
 };
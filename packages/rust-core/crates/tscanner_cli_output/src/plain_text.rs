@@ -4,7 +4,7 @@ use tscanner_types::IssueRuleType;
 
 use crate::display::{format_duration, rule_type_icon, severity_icon};
 use crate::formatted::FormattedOutput;
-use crate::types::{OutputFileGroup, OutputRuleGroup, OutputSummary};
+use crate::types::{OutputAuthorGroup, OutputFileGroup, OutputRuleGroup, OutputSummary};
 
 fn format_issue_line(
     severity_icon: &str,
@@ -30,6 +30,9 @@ impl FormattedOutput {
             FormattedOutput::ByRule { rules, summary } => {
                 render_by_rule(rules, summary, include_summary)
             }
+            FormattedOutput::ByAuthor { authors, summary } => {
+                render_by_author(authors, summary, include_summary)
+            }
         }
     }
 }
@@ -214,6 +217,56 @@ fn render_by_rule(
     lines.join("\n")
 }
 
+fn render_by_author(
+    authors: &[OutputAuthorGroup],
+    summary: &OutputSummary,
+    include_summary: bool,
+) -> String {
+    let mut lines: Vec<String> = Vec::new();
+
+    lines.push(String::new());
+    lines.push("Results:".to_string());
+
+    if !authors.is_empty() {
+        lines.push(String::new());
+        lines.push("Issues grouped by author:".to_string());
+    }
+
+    for author in authors {
+        lines.push(String::new());
+        lines.push(format!(
+            "{} ({} issues, {} files)",
+            author.display_name(),
+            author.count,
+            author.files.len()
+        ));
+
+        for file in &author.files {
+            lines.push(String::new());
+            lines.push(format!("  {} ({} issues)", file.file, file.issues.len()));
+
+            for issue in &file.issues {
+                let icon = severity_icon(&issue.severity);
+                let location = format!("{}:{} {}", issue.line, issue.column, issue.rule);
+                lines.push(format_issue_line(
+                    icon,
+                    &location,
+                    issue.line_text.as_deref(),
+                    "    ",
+                ));
+            }
+        }
+    }
+
+    lines.push(String::new());
+
+    if include_summary {
+        render_summary_lines(&mut lines, summary);
+    }
+
+    lines.join("\n")
+}
+
 fn render_summary_lines(lines: &mut Vec<String>, summary: &OutputSummary) {
     lines.push("Scope:".to_string());
     lines.push(String::new());
@@ -0,0 +1,82 @@
+use tscanner_constants::app_name;
+use tscanner_types::Severity;
+
+use super::xml::{escape, XML_DECLARATION};
+use super::{relative_path, ReportContext, Reporter};
+
+const CHECKSTYLE_VERSION: &str = "4.3";
+
+pub struct CheckstyleReporter;
+
+fn checkstyle_severity(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+        Severity::Info | Severity::Hint => "info",
+    }
+}
+
+impl Reporter for CheckstyleReporter {
+    fn report(&self, ctx: &ReportContext) -> Option<String> {
+        let mut out = String::new();
+        out.push_str(XML_DECLARATION);
+        out.push('\n');
+        out.push_str(&format!(
+            "<checkstyle version=\"{}\">\n",
+            CHECKSTYLE_VERSION
+        ));
+
+        for file_result in &ctx.result.files {
+            if file_result.issues.is_empty() {
+                continue;
+            }
+            out.push_str(&format!(
+                "  <file name=\"{}\">\n",
+                escape(&relative_path(ctx.root, &file_result.file))
+            ));
+            for issue in &file_result.issues {
+                out.push_str(&format!(
+                    "    <error line=\"{}\" column=\"{}\" severity=\"{}\" message=\"{}\" source=\"{}.{}\"/>\n",
+                    issue.line,
+                    issue.column,
+                    checkstyle_severity(issue.severity),
+                    escape(&issue.message),
+                    app_name(),
+                    escape(&issue.rule)
+                ));
+            }
+            out.push_str("  </file>\n");
+        }
+
+        out.push_str("</checkstyle>\n");
+        Some(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tscanner_types::Severity;
+
+    use super::super::test_support::{issue, render, scan_result};
+    use super::CheckstyleReporter;
+
+    #[test]
+    fn groups_errors_by_file_with_mapped_severities() {
+        let result = scan_result(vec![
+            issue("no-console", "src/a.ts", 2, "Avoid 'console'"),
+            issue("no-var", "src/a.ts", 5, "Use let").with_severity(Severity::Warning),
+            issue("prefer-const", "src/b.ts", 1, "Use const").with_severity(Severity::Hint),
+        ]);
+
+        let xml = render(&CheckstyleReporter, &result, &[], None);
+
+        assert!(xml.contains("<checkstyle version=\"4.3\">"));
+        assert_eq!(xml.matches("<file name=").count(), 2);
+        assert!(xml.contains(
+            "<error line=\"2\" column=\"3\" severity=\"error\" message=\"Avoid &apos;console&apos;\" source=\"tscanner.no-console\"/>"
+        ));
+        assert!(xml.contains("severity=\"warning\" message=\"Use let\""));
+        assert!(xml.contains("severity=\"info\" message=\"Use const\""));
+        assert!(xml.trim_end().ends_with("</checkstyle>"));
+    }
+}
@@ -0,0 +1,131 @@
+use tscanner_constants::app_name;
+use tscanner_types::Severity;
+
+use super::{relative_path, ReportContext, Reporter};
+
+pub struct GithubReporter;
+
+fn github_command(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+        Severity::Info | Severity::Hint => "notice",
+    }
+}
+
+fn escape_data(value: &str) -> String {
+    value
+        .replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+fn escape_property(value: &str) -> String {
+    escape_data(value).replace(':', "%3A").replace(',', "%2C")
+}
+
+impl Reporter for GithubReporter {
+    fn report(&self, ctx: &ReportContext) -> Option<String> {
+        let mut lines = Vec::new();
+
+        for file_result in &ctx.result.files {
+            let path = escape_property(&relative_path(ctx.root, &file_result.file));
+            for issue in &file_result.issues {
+                let end = if issue.last_line() > issue.line {
+                    format!("endLine={}", issue.last_line())
+                } else {
+                    format!("endColumn={}", issue.end_column.max(issue.column))
+                };
+                let message = match &issue.note {
+                    Some(note) => format!("{}\n{}", issue.message, note),
+                    None => issue.message.clone(),
+                };
+                lines.push(format!(
+                    "::{} file={},line={},col={},{},title={}::{}",
+                    github_command(issue.severity),
+                    path,
+                    issue.line,
+                    issue.column,
+                    end,
+                    escape_property(&format!("{} ({})", app_name(), issue.rule)),
+                    escape_data(&message)
+                ));
+            }
+        }
+
+        for error in &ctx.result.errors {
+            lines.push(format!(
+                "::error title={}::{}",
+                app_name(),
+                escape_data(error)
+            ));
+        }
+
+        for warning in &ctx.result.warnings {
+            lines.push(format!(
+                "::warning title={}::{}",
+                app_name(),
+                escape_data(warning)
+            ));
+        }
+
+        Some(lines.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tscanner_types::Severity;
+
+    use super::super::test_support::{issue, render, scan_result};
+    use super::{escape_data, escape_property, GithubReporter};
+
+    #[test]
+    fn escapes_data_and_properties() {
+        assert_eq!(escape_data("50% done\r\nnext"), "50%25 done%0D%0Anext");
+        assert_eq!(escape_data("a:b,c"), "a:b,c");
+        assert_eq!(escape_property("src/a,b.ts:1%\n"), "src/a%2Cb.ts%3A1%25%0A");
+    }
+
+    #[test]
+    fn single_line_issue_emits_end_column() {
+        let result = scan_result(vec![
+            issue("no-console", "src/a.ts", 2, "Avoid console").with_severity(Severity::Hint)
+        ]);
+
+        let output = render(&GithubReporter, &result, &[], None);
+
+        assert_eq!(
+            output,
+            "::notice file=src/a.ts,line=2,col=3,endColumn=8,title=tscanner (no-console)::Avoid console"
+        );
+    }
+
+    #[test]
+    fn multi_line_issue_emits_end_line_and_note() {
+        let mut multi_line = issue("max-params", "src/a.ts", 2, "Too many params");
+        multi_line.end_line = 6;
+        multi_line.note = Some("Limit is 3".to_string());
+
+        let output = render(&GithubReporter, &scan_result(vec![multi_line]), &[], None);
+
+        assert_eq!(
+            output,
+            "::error file=src/a.ts,line=2,col=3,endLine=6,title=tscanner (max-params)::Too many params%0ALimit is 3"
+        );
+    }
+
+    #[test]
+    fn scan_errors_and_warnings_become_commands() {
+        let mut result = scan_result(Vec::new());
+        result.errors.push("rule failed: 100%".to_string());
+        result.warnings.push("slow\nrule".to_string());
+
+        let output = render(&GithubReporter, &result, &[], None);
+
+        assert_eq!(
+            output,
+            "::error title=tscanner::rule failed: 100%25\n::warning title=tscanner::slow%0Arule"
+        );
+    }
+}
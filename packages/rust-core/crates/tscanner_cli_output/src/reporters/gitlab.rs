@@ -0,0 +1,173 @@
+use std::collections::HashMap;
+
+use serde::Serialize;
+use tscanner_types::{Issue, Severity};
+
+use super::{relative_path, ReportContext, Reporter};
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+const FNV_PRIME: u64 = 0x100000001b3;
+
+#[derive(Serialize)]
+struct CodeQualityIssue {
+    description: String,
+    check_name: String,
+    fingerprint: String,
+    severity: &'static str,
+    location: CodeQualityLocation,
+}
+
+#[derive(Serialize)]
+struct CodeQualityLocation {
+    path: String,
+    lines: CodeQualityLines,
+}
+
+#[derive(Serialize)]
+struct CodeQualityLines {
+    begin: usize,
+    end: usize,
+}
+
+pub struct GitlabReporter;
+
+fn gitlab_severity(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Error => "major",
+        Severity::Warning => "minor",
+        Severity::Info | Severity::Hint => "info",
+    }
+}
+
+fn fnv1a(parts: &[&str]) -> u64 {
+    let mut hash = FNV_OFFSET_BASIS;
+    for part in parts {
+        for byte in part.bytes().chain(std::iter::once(0)) {
+            hash ^= byte as u64;
+            hash = hash.wrapping_mul(FNV_PRIME);
+        }
+    }
+    hash
+}
+
+fn fingerprint_anchor(issue: &Issue) -> String {
+    match issue.line_text.as_deref().map(str::trim) {
+        Some(text) if !text.is_empty() => text.split_whitespace().collect::<Vec<_>>().join(" "),
+        _ => issue.message.clone(),
+    }
+}
+
+impl Reporter for GitlabReporter {
+    fn report(&self, ctx: &ReportContext) -> Option<String> {
+        let mut occurrences: HashMap<(String, String, String), usize> = HashMap::new();
+        let mut issues = Vec::new();
+
+        for file_result in &ctx.result.files {
+            let path = relative_path(ctx.root, &file_result.file);
+            for issue in &file_result.issues {
+                let anchor = fingerprint_anchor(issue);
+                let occurrence = occurrences
+                    .entry((issue.rule.clone(), path.clone(), anchor.clone()))
+                    .or_insert(0);
+                *occurrence += 1;
+
+                let fingerprint = fnv1a(&[&issue.rule, &path, &anchor, &occurrence.to_string()]);
+
+                issues.push(CodeQualityIssue {
+                    description: issue.message.clone(),
+                    check_name: issue.rule.clone(),
+                    fingerprint: format!("{:016x}", fingerprint),
+                    severity: gitlab_severity(issue.severity),
+                    location: CodeQualityLocation {
+                        path: path.clone(),
+                        lines: CodeQualityLines {
+                            begin: issue.line,
+                            end: issue.last_line(),
+                        },
+                    },
+                });
+            }
+        }
+
+        serde_json::to_string_pretty(&issues).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::Value;
+    use tscanner_types::Issue;
+
+    use super::super::test_support::{issue, render, scan_result};
+    use super::GitlabReporter;
+
+    fn fingerprints(issues: Vec<Issue>) -> Vec<String> {
+        let report: Value =
+            serde_json::from_str(&render(&GitlabReporter, &scan_result(issues), &[], None))
+                .unwrap();
+        report
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|entry| entry["fingerprint"].as_str().unwrap().to_string())
+            .collect()
+    }
+
+    fn console_issue(line: usize) -> Issue {
+        issue("no-console", "src/a.ts", line, "Avoid console")
+            .with_line_text("    console.log(value);".to_string())
+    }
+
+    #[test]
+    fn reports_code_quality_entries() {
+        let report: Value = serde_json::from_str(&render(
+            &GitlabReporter,
+            &scan_result(vec![console_issue(2)]),
+            &[],
+            None,
+        ))
+        .unwrap();
+        let entry = &report[0];
+
+        assert_eq!(entry["check_name"], "no-console");
+        assert_eq!(entry["description"], "Avoid console");
+        assert_eq!(entry["severity"], "major");
+        assert_eq!(entry["location"]["path"], "src/a.ts");
+        assert_eq!(entry["location"]["lines"]["begin"], 2);
+        assert_eq!(entry["fingerprint"].as_str().unwrap().len(), 16);
+    }
+
+    #[test]
+    fn fingerprint_is_stable_when_lines_shift() {
+        let before = fingerprints(vec![console_issue(2)]);
+        let mut shifted = console_issue(40);
+        shifted.line_text = Some("console.log(value);".to_string());
+        let after = fingerprints(vec![shifted]);
+
+        assert_eq!(before, after);
+    }
+
+    #[test]
+    fn identical_issues_in_one_file_get_distinct_fingerprints() {
+        let prints = fingerprints(vec![console_issue(2), console_issue(7)]);
+
+        assert_eq!(prints.len(), 2);
+        assert_ne!(prints[0], prints[1]);
+        assert_eq!(
+            prints,
+            fingerprints(vec![console_issue(3), console_issue(9)])
+        );
+    }
+
+    #[test]
+    fn fingerprint_depends_on_rule_and_file() {
+        let base = fingerprints(vec![console_issue(2)]);
+        let other_rule = fingerprints(vec![issue("no-debug", "src/a.ts", 2, "Avoid console")
+            .with_line_text("console.log(value);".to_string())]);
+        let other_file = fingerprints(vec![issue("no-console", "src/b.ts", 2, "Avoid console")
+            .with_line_text("console.log(value);".to_string())]);
+
+        assert_ne!(base, other_rule);
+        assert_ne!(base, other_file);
+    }
+}
@@ -0,0 +1,9 @@
+use super::{ReportContext, Reporter};
+
+pub struct JsonReporter;
+
+impl Reporter for JsonReporter {
+    fn report(&self, ctx: &ReportContext) -> Option<String> {
+        ctx.output.to_json()
+    }
+}
@@ -0,0 +1,115 @@
+use tscanner_constants::app_name;
+
+use super::xml::{escape, XML_DECLARATION};
+use super::{relative_path, ReportContext, Reporter};
+
+pub struct JunitReporter;
+
+impl Reporter for JunitReporter {
+    fn report(&self, ctx: &ReportContext) -> Option<String> {
+        let total_issues: usize = ctx.result.files.iter().map(|f| f.issues.len()).sum();
+        let seconds = ctx.result.duration_ms as f64 / 1000.0;
+
+        let mut out = String::new();
+        out.push_str(XML_DECLARATION);
+        out.push('\n');
+        out.push_str(&format!(
+            "<testsuites name=\"{}\" tests=\"{}\" failures=\"{}\" errors=\"0\" time=\"{:.3}\">\n",
+            app_name(),
+            total_issues.max(1),
+            total_issues,
+            seconds
+        ));
+
+        if total_issues == 0 {
+            out.push_str(&format!(
+                "  <testsuite name=\"{0}\" tests=\"1\" failures=\"0\" errors=\"0\">\n    <testcase name=\"{0}\" classname=\"{0}\"/>\n  </testsuite>\n",
+                app_name()
+            ));
+        }
+
+        for file_result in &ctx.result.files {
+            if file_result.issues.is_empty() {
+                continue;
+            }
+            let path = escape(&relative_path(ctx.root, &file_result.file));
+            out.push_str(&format!(
+                "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" errors=\"0\">\n",
+                path,
+                file_result.issues.len(),
+                file_result.issues.len()
+            ));
+            for issue in &file_result.issues {
+                let message = escape(&issue.message);
+                out.push_str(&format!(
+                    "    <testcase name=\"{} ({}:{})\" classname=\"{}\" file=\"{}\" line=\"{}\">\n",
+                    escape(&issue.rule),
+                    issue.line,
+                    issue.column,
+                    path,
+                    path,
+                    issue.line
+                ));
+                out.push_str(&format!(
+                    "      <failure message=\"{}\" type=\"{}\">{}:{}:{} [{}] {}</failure>\n",
+                    message,
+                    issue.severity.as_str(),
+                    path,
+                    issue.line,
+                    issue.column,
+                    escape(&issue.rule),
+                    message
+                ));
+                out.push_str("    </testcase>\n");
+            }
+            out.push_str("  </testsuite>\n");
+        }
+
+        out.push_str("</testsuites>\n");
+        Some(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_support::{issue, render, scan_result};
+    use super::JunitReporter;
+
+    #[test]
+    fn reports_one_failing_testcase_per_issue() {
+        let result = scan_result(vec![
+            issue("no-console", "src/a.ts", 2, "Avoid <console> & \"logs\""),
+            issue("no-var", "src/a.ts", 5, "Use let"),
+            issue("no-var", "src/b.ts", 1, "Use let"),
+        ]);
+
+        let xml = render(&JunitReporter, &result, &[], None);
+
+        assert!(xml.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>"));
+        assert!(xml.contains(
+            "<testsuites name=\"tscanner\" tests=\"3\" failures=\"3\" errors=\"0\" time=\"1.500\">"
+        ));
+        assert!(
+            xml.contains("<testsuite name=\"src/a.ts\" tests=\"2\" failures=\"2\" errors=\"0\">")
+        );
+        assert!(
+            xml.contains("<testsuite name=\"src/b.ts\" tests=\"1\" failures=\"1\" errors=\"0\">")
+        );
+        assert!(xml.contains(
+            "<testcase name=\"no-console (2:3)\" classname=\"src/a.ts\" file=\"src/a.ts\" line=\"2\">"
+        ));
+        assert!(
+            xml.contains("message=\"Avoid &lt;console&gt; &amp; &quot;logs&quot;\" type=\"error\"")
+        );
+        assert_eq!(xml.matches("<failure ").count(), 3);
+    }
+
+    #[test]
+    fn clean_scan_reports_a_single_passing_testcase() {
+        let xml = render(&JunitReporter, &scan_result(vec![]), &[], None);
+
+        assert!(xml.contains("tests=\"1\" failures=\"0\""));
+        assert!(xml.contains("<testcase name=\"tscanner\" classname=\"tscanner\"/>"));
+        assert!(!xml.contains("<failure"));
+    }
+}
@@ -0,0 +1,344 @@
+use std::collections::BTreeMap;
+
+use tscanner_constants::{app_display_name, icon_error, icon_success, icon_warning};
+
+use super::xml::escape;
+use super::{ReportContext, Reporter};
+use crate::display::{format_duration, rule_type_icon, severity_icon};
+use crate::formatted::FormattedOutput;
+use crate::types::{OutputAuthorGroup, OutputFileGroup, OutputRuleGroup, OutputSummary};
+
+pub struct MarkdownReporter;
+
+struct IssueRef<'a> {
+    rule: Option<&'a str>,
+    line: usize,
+    column: usize,
+    severity: &'a str,
+    line_text: Option<&'a str>,
+}
+
+fn pluralize(count: usize, word: &str) -> String {
+    if count == 1 {
+        format!("{} {}", count, word)
+    } else {
+        format!("{} {}s", count, word)
+    }
+}
+
+fn encode_path(file: &str) -> String {
+    file.split('/')
+        .map(|segment| {
+            let mut encoded = String::with_capacity(segment.len());
+            for byte in segment.bytes() {
+                match byte {
+                    b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                        encoded.push(byte as char)
+                    }
+                    _ => encoded.push_str(&format!("%{:02X}", byte)),
+                }
+            }
+            encoded
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+fn apply_link_template(template: &str, file: &str, line: usize, column: usize) -> String {
+    template
+        .replace("{file}", &encode_path(file))
+        .replace("{line}", &line.to_string())
+        .replace("{column}", &column.to_string())
+}
+
+fn render_header(summary: &OutputSummary) -> String {
+    let (icon, title) = if summary.total_issues == 0 {
+        (icon_success(), "No Issues Found")
+    } else if summary.errors > 0 {
+        (icon_error(), "Errors Found")
+    } else {
+        (icon_warning(), "Warnings Found")
+    };
+    format!("## {} {} - {}", icon, app_display_name(), title)
+}
+
+fn render_summary_table(summary: &OutputSummary) -> Vec<String> {
+    let enabled_breakdown =
+        summary.format_rules_breakdown(&summary.enabled_rules_breakdown_parts());
+    let triggered_breakdown = summary.format_rules_breakdown(&summary.rules_breakdown_parts());
+
+    vec![
+        "| | |".to_string(),
+        "|---|---|".to_string(),
+        format!(
+            "| Rules | {}{} |",
+            summary.total_enabled_rules, enabled_breakdown
+        ),
+        format!(
+            "| Files | {} ({} cached, {} scanned) |",
+            summary.total_files, summary.cached_files, summary.scanned_files
+        ),
+        format!("| Issues | {} |", summary.format_issues_plain()),
+        format!(
+            "| Triggered rules | {}{} |",
+            summary.triggered_rules, triggered_breakdown
+        ),
+        format!("| Files with issues | {} |", summary.files_with_issues),
+        format!("| Duration | {} |", format_duration(summary.duration_ms)),
+    ]
+}
+
+fn render_issue_items(
+    lines: &mut Vec<String>,
+    file: &str,
+    issues: &[IssueRef],
+    link_template: Option<&str>,
+) {
+    for issue in issues {
+        let location = format!("{}:{}", issue.line, issue.column);
+        let location = match link_template {
+            Some(template) => format!(
+                "<a href=\"{}\">{}</a>",
+                escape(&apply_link_template(
+                    template,
+                    file,
+                    issue.line,
+                    issue.column
+                )),
+                location
+            ),
+            None => location,
+        };
+        let location = match issue.rule {
+            Some(rule) => format!("{} <strong>{}</strong>", location, escape(rule)),
+            None => location,
+        };
+        let text = issue.line_text.map(str::trim).unwrap_or_default();
+        if text.is_empty() {
+            lines.push(format!("- {} {}", severity_icon(issue.severity), location));
+        } else {
+            lines.push(format!(
+                "- {} {} - <code>{}</code>",
+                severity_icon(issue.severity),
+                location,
+                escape(text)
+            ));
+        }
+    }
+    lines.push(String::new());
+}
+
+fn render_by_file(lines: &mut Vec<String>, files: &[OutputFileGroup], link_template: Option<&str>) {
+    for file in files {
+        let mut issues_by_rule: BTreeMap<&str, Vec<_>> = BTreeMap::new();
+        for issue in &file.issues {
+            issues_by_rule.entry(&issue.rule).or_default().push(issue);
+        }
+
+        lines.push("<details>".to_string());
+        lines.push(format!(
+            "<summary><strong>{}</strong> - {} - {}</summary>",
+            escape(&file.file),
+            pluralize(file.issues.len(), "issue"),
+            pluralize(issues_by_rule.len(), "rule")
+        ));
+        lines.push(String::new());
+
+        for (rule, issues) in issues_by_rule {
+            let icon = issues
+                .first()
+                .map(|i| rule_type_icon(i.rule_type))
+                .unwrap_or_default();
+            lines.push(format!(
+                "{} <strong>{}</strong> - {}",
+                icon,
+                escape(rule),
+                pluralize(issues.len(), "issue")
+            ));
+            lines.push(String::new());
+            let refs: Vec<IssueRef> = issues
+                .iter()
+                .map(|i| IssueRef {
+                    rule: None,
+                    line: i.line,
+                    column: i.column,
+                    severity: &i.severity,
+                    line_text: i.line_text.as_deref(),
+                })
+                .collect();
+            render_issue_items(lines, &file.file, &refs, link_template);
+        }
+
+        lines.push("</details>".to_string());
+        lines.push(String::new());
+    }
+}
+
+fn render_by_rule(lines: &mut Vec<String>, rules: &[OutputRuleGroup], link_template: Option<&str>) {
+    for rule in rules {
+        let mut issues_by_file: BTreeMap<&str, Vec<_>> = BTreeMap::new();
+        for issue in &rule.issues {
+            issues_by_file.entry(&issue.file).or_default().push(issue);
+        }
+
+        lines.push("<details>".to_string());
+        lines.push(format!(
+            "<summary>{} <strong>{}</strong> - {} - {}</summary>",
+            rule_type_icon(rule.rule_type),
+            escape(&rule.rule),
+            pluralize(rule.count, "issue"),
+            pluralize(issues_by_file.len(), "file")
+        ));
+        lines.push(String::new());
+        lines.push(format!("_{}_", escape(&rule.message)));
+        lines.push(String::new());
+
+        for (file, issues) in issues_by_file {
+            lines.push(format!(
+                "<strong>{}</strong> - {}",
+                escape(file),
+                pluralize(issues.len(), "issue")
+            ));
+            lines.push(String::new());
+            let refs: Vec<IssueRef> = issues
+                .iter()
+                .map(|i| IssueRef {
+                    rule: None,
+                    line: i.line,
+                    column: i.column,
+                    severity: &i.severity,
+                    line_text: i.line_text.as_deref(),
+                })
+                .collect();
+            render_issue_items(lines, file, &refs, link_template);
+        }
+
+        lines.push("</details>".to_string());
+        lines.push(String::new());
+    }
+}
+
+fn render_by_author(
+    lines: &mut Vec<String>,
+    authors: &[OutputAuthorGroup],
+    link_template: Option<&str>,
+) {
+    for author in authors {
+        lines.push("<details>".to_string());
+        lines.push(format!(
+            "<summary><strong>{}</strong> - {} - {}</summary>",
+            escape(&author.display_name()),
+            pluralize(author.count, "issue"),
+            pluralize(author.files.len(), "file")
+        ));
+        lines.push(String::new());
+
+        for file in &author.files {
+            lines.push(format!(
+                "<strong>{}</strong> - {}",
+                escape(&file.file),
+                pluralize(file.issues.len(), "issue")
+            ));
+            lines.push(String::new());
+            let refs: Vec<IssueRef> = file
+                .issues
+                .iter()
+                .map(|i| IssueRef {
+                    rule: Some(&i.rule),
+                    line: i.line,
+                    column: i.column,
+                    severity: &i.severity,
+                    line_text: i.line_text.as_deref(),
+                })
+                .collect();
+            render_issue_items(lines, &file.file, &refs, link_template);
+        }
+
+        lines.push("</details>".to_string());
+        lines.push(String::new());
+    }
+}
+
+fn render_scan_messages(lines: &mut Vec<String>, summary: &OutputSummary) {
+    let messages: Vec<(&str, &String)> = summary
+        .scan_errors
+        .iter()
+        .map(|m| (icon_error(), m))
+        .chain(summary.scan_warnings.iter().map(|m| (icon_warning(), m)))
+        .chain(summary.scan_notes.iter().map(|m| ("", m)))
+        .collect();
+
+    if messages.is_empty() {
+        return;
+    }
+
+    for (icon, message) in messages {
+        let prefix = if icon.is_empty() {
+            String::new()
+        } else {
+            format!("{} ", icon)
+        };
+        lines.push(format!("> {}{}", prefix, escape(message)));
+        lines.push(">".to_string());
+    }
+    lines.pop();
+    lines.push(String::new());
+}
+
+impl Reporter for MarkdownReporter {
+    fn report(&self, ctx: &ReportContext) -> Option<String> {
+        let summary = ctx.output.summary();
+        let mut lines = vec![render_header(summary), String::new()];
+
+        lines.extend(render_summary_table(summary));
+        lines.push(String::new());
+
+        render_scan_messages(&mut lines, summary);
+
+        match ctx.output {
+            FormattedOutput::ByFile { files, .. } => {
+                render_by_file(&mut lines, files, ctx.link_template)
+            }
+            FormattedOutput::ByRule { rules, .. } => {
+                render_by_rule(&mut lines, rules, ctx.link_template)
+            }
+            FormattedOutput::ByAuthor { authors, .. } => {
+                render_by_author(&mut lines, authors, ctx.link_template)
+            }
+        }
+
+        Some(lines.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_support::{issue, render, scan_result};
+    use super::{apply_link_template, MarkdownReporter};
+
+    const TEMPLATE: &str = "https://github.com/org/repo/blob/main/{file}#L{line}";
+
+    #[test]
+    fn link_template_percent_encodes_path_segments() {
+        assert_eq!(
+            apply_link_template(TEMPLATE, "src/my file#1?.ts", 3, 1),
+            "https://github.com/org/repo/blob/main/src/my%20file%231%3F.ts#L3"
+        );
+        assert_eq!(
+            apply_link_template(TEMPLATE, "src/caf\u{e9}/a-b_c.d~.ts", 7, 2),
+            "https://github.com/org/repo/blob/main/src/caf%C3%A9/a-b_c.d~.ts#L7"
+        );
+    }
+
+    #[test]
+    fn renders_encoded_links_in_report() {
+        let result = scan_result(vec![issue("no-var", "docs & notes/a b.ts", 4, "Use let")]);
+
+        let markdown = render(&MarkdownReporter, &result, &[], Some(TEMPLATE));
+
+        assert!(markdown.contains(
+            "<a href=\"https://github.com/org/repo/blob/main/docs%20%26%20notes/a%20b.ts#L4\">4:3</a>"
+        ));
+        assert!(markdown.contains("<strong>docs &amp; notes/a b.ts</strong>"));
+    }
+}
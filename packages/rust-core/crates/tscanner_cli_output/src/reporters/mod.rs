@@ -0,0 +1,147 @@
+mod checkstyle;
+mod github;
+mod gitlab;
+mod json;
+mod junit;
+mod markdown;
+mod sarif;
+mod xml;
+
+use std::path::Path;
+
+use tscanner_types::ScanResult;
+
+use crate::formatted::FormattedOutput;
+use crate::types::RuleDescriptor;
+
+pub use checkstyle::CheckstyleReporter;
+pub use github::GithubReporter;
+pub use gitlab::GitlabReporter;
+pub use json::JsonReporter;
+pub use junit::JunitReporter;
+pub use markdown::MarkdownReporter;
+pub use sarif::{SarifLog, SarifReporter};
+
+pub struct ReportContext<'a> {
+    pub root: &'a Path,
+    pub result: &'a ScanResult,
+    pub output: &'a FormattedOutput,
+    pub rules: &'a [RuleDescriptor],
+    pub link_template: Option<&'a str>,
+}
+
+pub trait Reporter {
+    fn report(&self, ctx: &ReportContext) -> Option<String>;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportFormat {
+    Json,
+    Sarif,
+    Junit,
+    Checkstyle,
+    Gitlab,
+    Markdown,
+    Github,
+}
+
+impl ReportFormat {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReportFormat::Json => "json",
+            ReportFormat::Sarif => "sarif",
+            ReportFormat::Junit => "junit",
+            ReportFormat::Checkstyle => "checkstyle",
+            ReportFormat::Gitlab => "gitlab",
+            ReportFormat::Markdown => "markdown",
+            ReportFormat::Github => "github",
+        }
+    }
+}
+
+pub fn get_reporter(format: ReportFormat) -> Box<dyn Reporter> {
+    match format {
+        ReportFormat::Json => Box::new(JsonReporter),
+        ReportFormat::Sarif => Box::new(SarifReporter),
+        ReportFormat::Junit => Box::new(JunitReporter),
+        ReportFormat::Checkstyle => Box::new(CheckstyleReporter),
+        ReportFormat::Gitlab => Box::new(GitlabReporter),
+        ReportFormat::Markdown => Box::new(MarkdownReporter),
+        ReportFormat::Github => Box::new(GithubReporter),
+    }
+}
+
+pub(crate) fn relative_path(root: &Path, file: &Path) -> String {
+    let relative = pathdiff::diff_paths(file, root).unwrap_or_else(|| file.to_path_buf());
+    relative.display().to_string().replace('\\', "/")
+}
+
+#[cfg(test)]
+pub(crate) mod test_support {
+    use std::path::{Path, PathBuf};
+
+    use tscanner_types::{FileResult, Issue, ScanResult};
+
+    use super::{ReportContext, Reporter};
+    use crate::formatted::{FormattedOutput, SummaryStats};
+    use crate::types::{RuleDescriptor, RulesBreakdown};
+
+    pub const ROOT: &str = "/repo";
+
+    pub fn issue(rule: &str, file: &str, line: usize, message: &str) -> Issue {
+        Issue::new(
+            rule,
+            Path::new(ROOT).join(file),
+            line,
+            3,
+            8,
+            message.to_string(),
+        )
+    }
+
+    pub fn scan_result(issues: Vec<Issue>) -> ScanResult {
+        let mut files: Vec<FileResult> = Vec::new();
+        for issue in issues {
+            match files.iter_mut().find(|f| f.file == issue.file) {
+                Some(file) => file.issues.push(issue),
+                None => files.push(FileResult {
+                    file: issue.file.clone(),
+                    issues: vec![issue],
+                }),
+            }
+        }
+        let total_issues = files.iter().map(|f| f.issues.len()).sum();
+        ScanResult {
+            total_files: files.len(),
+            scanned_files: files.len(),
+            files,
+            total_issues,
+            duration_ms: 1500,
+            regular_rules_duration_ms: 0,
+            ai_rules_duration_ms: 0,
+            cached_files: 0,
+            notes: Vec::new(),
+            warnings: Vec::new(),
+            errors: Vec::new(),
+        }
+    }
+
+    pub fn render(
+        reporter: &dyn Reporter,
+        result: &ScanResult,
+        rules: &[RuleDescriptor],
+        link_template: Option<&str>,
+    ) -> String {
+        let root = PathBuf::from(ROOT);
+        let stats = SummaryStats::from_result(result, rules.len(), RulesBreakdown::default());
+        let output = FormattedOutput::build_by_file(&root, result, &stats);
+        let ctx = ReportContext {
+            root: &root,
+            result,
+            output: &output,
+            rules,
+            link_template,
+        };
+        reporter.report(&ctx).expect("reporter produced no output")
+    }
+}
@@ -0,0 +1,400 @@
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+
+use serde::Serialize;
+use tscanner_constants::{app_name, repo_url};
+use tscanner_types::{Issue, IssueFix, ScanResult, Severity, TextRange};
+
+use super::{relative_path, ReportContext, Reporter};
+use crate::types::RuleDescriptor;
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+const SARIF_VERSION: &str = "2.1.0";
+const SRCROOT: &str = "%SRCROOT%";
+
+#[derive(Serialize)]
+pub struct SarifLog {
+    #[serde(rename = "$schema")]
+    schema: &'static str,
+    version: &'static str,
+    runs: Vec<SarifRun>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifRun {
+    tool: SarifTool,
+    original_uri_base_ids: BTreeMap<&'static str, SarifArtifactLocation>,
+    results: Vec<SarifResult>,
+}
+
+#[derive(Serialize)]
+struct SarifTool {
+    driver: SarifDriver,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifDriver {
+    name: &'static str,
+    version: &'static str,
+    information_uri: &'static str,
+    rules: Vec<SarifReportingDescriptor>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifReportingDescriptor {
+    id: String,
+    short_description: SarifMessage,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    help_uri: Option<String>,
+    default_configuration: SarifConfiguration,
+}
+
+#[derive(Serialize)]
+struct SarifConfiguration {
+    level: &'static str,
+}
+
+#[derive(Serialize)]
+struct SarifMessage {
+    text: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifResult {
+    rule_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rule_index: Option<usize>,
+    level: &'static str,
+    message: SarifMessage,
+    locations: Vec<SarifLocation>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    related_locations: Vec<SarifLocation>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    fixes: Vec<SarifFix>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifLocation {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<usize>,
+    physical_location: SarifPhysicalLocation,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    message: Option<SarifMessage>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifPhysicalLocation {
+    artifact_location: SarifArtifactLocation,
+    region: SarifRegion,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifArtifactLocation {
+    uri: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    uri_base_id: Option<&'static str>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifRegion {
+    start_line: usize,
+    start_column: usize,
+    end_line: usize,
+    end_column: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    snippet: Option<SarifMessage>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifFix {
+    description: SarifMessage,
+    artifact_changes: Vec<SarifArtifactChange>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifArtifactChange {
+    artifact_location: SarifArtifactLocation,
+    replacements: Vec<SarifReplacement>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifReplacement {
+    deleted_region: SarifRegion,
+    inserted_content: SarifMessage,
+}
+
+fn sarif_level(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+        Severity::Info | Severity::Hint => "note",
+    }
+}
+
+fn root_uri(root: &Path) -> String {
+    let root_str = root.display().to_string().replace('\\', "/");
+    let prefix = if root_str.starts_with('/') {
+        "file://"
+    } else {
+        "file:///"
+    };
+    if root_str.ends_with('/') {
+        format!("{}{}", prefix, root_str)
+    } else {
+        format!("{}{}/", prefix, root_str)
+    }
+}
+
+fn region_from_range(range: &TextRange) -> SarifRegion {
+    SarifRegion {
+        start_line: range.start_line,
+        start_column: range.start_col,
+        end_line: range.end_line,
+        end_column: range.end_col,
+        snippet: None,
+    }
+}
+
+fn build_fix(uri: &str, fix: &IssueFix) -> SarifFix {
+    SarifFix {
+        description: SarifMessage {
+            text: fix.title.clone(),
+        },
+        artifact_changes: vec![SarifArtifactChange {
+            artifact_location: SarifArtifactLocation {
+                uri: uri.to_string(),
+                uri_base_id: Some(SRCROOT),
+            },
+            replacements: fix
+                .edits
+                .iter()
+                .map(|edit| SarifReplacement {
+                    deleted_region: region_from_range(&edit.range),
+                    inserted_content: SarifMessage {
+                        text: edit.new_text.clone(),
+                    },
+                })
+                .collect(),
+        }],
+    }
+}
+
+fn build_result(root: &Path, issue: &Issue, rule_index: Option<usize>) -> SarifResult {
+    let uri = relative_path(root, &issue.file);
+    let fixes = issue
+        .fix
+        .as_ref()
+        .map(|fix| vec![build_fix(&uri, fix)])
+        .unwrap_or_default();
+    let related_locations = issue
+        .related
+        .iter()
+        .enumerate()
+        .map(|(idx, related)| SarifLocation {
+            id: Some(idx),
+            physical_location: SarifPhysicalLocation {
+                artifact_location: SarifArtifactLocation {
+                    uri: relative_path(root, &related.file),
+                    uri_base_id: Some(SRCROOT),
+                },
+                region: region_from_range(&related.range),
+            },
+            message: Some(SarifMessage {
+                text: related.message.clone(),
+            }),
+        })
+        .collect();
+    let message = match &issue.note {
+        Some(note) => format!("{}\n{}", issue.message, note),
+        None => issue.message.clone(),
+    };
+
+    SarifResult {
+        rule_id: issue.rule.clone(),
+        rule_index,
+        level: sarif_level(issue.severity),
+        message: SarifMessage { text: message },
+        locations: vec![SarifLocation {
+            id: None,
+            physical_location: SarifPhysicalLocation {
+                artifact_location: SarifArtifactLocation {
+                    uri,
+                    uri_base_id: Some(SRCROOT),
+                },
+                region: SarifRegion {
+                    start_line: issue.line.max(1),
+                    start_column: issue.column.max(1),
+                    end_line: issue.last_line().max(1),
+                    end_column: if issue.last_line() > issue.line {
+                        issue.end_column.max(1)
+                    } else {
+                        issue.end_column.max(issue.column.max(1))
+                    },
+                    snippet: issue
+                        .line_text
+                        .as_ref()
+                        .map(|text| SarifMessage { text: text.clone() }),
+                },
+            },
+            message: None,
+        }],
+        related_locations,
+        fixes,
+    }
+}
+
+impl SarifLog {
+    pub fn build(root: &Path, result: &ScanResult, rules: &[RuleDescriptor]) -> Self {
+        let mut descriptors: Vec<SarifReportingDescriptor> = rules
+            .iter()
+            .map(|rule| SarifReportingDescriptor {
+                id: rule.name.clone(),
+                short_description: SarifMessage {
+                    text: rule.description.clone(),
+                },
+                help_uri: rule.help_uri.clone(),
+                default_configuration: SarifConfiguration {
+                    level: sarif_level(rule.default_severity),
+                },
+            })
+            .collect();
+
+        let mut rule_indexes: HashMap<String, usize> = descriptors
+            .iter()
+            .enumerate()
+            .map(|(idx, d)| (d.id.clone(), idx))
+            .collect();
+
+        let mut results = Vec::new();
+        for file_result in &result.files {
+            for issue in &file_result.issues {
+                let rule_index = *rule_indexes.entry(issue.rule.clone()).or_insert_with(|| {
+                    descriptors.push(SarifReportingDescriptor {
+                        id: issue.rule.clone(),
+                        short_description: SarifMessage {
+                            text: issue.message.clone(),
+                        },
+                        help_uri: None,
+                        default_configuration: SarifConfiguration {
+                            level: sarif_level(issue.severity),
+                        },
+                    });
+                    descriptors.len() - 1
+                });
+                results.push(build_result(root, issue, Some(rule_index)));
+            }
+        }
+
+        let mut original_uri_base_ids = BTreeMap::new();
+        original_uri_base_ids.insert(
+            SRCROOT,
+            SarifArtifactLocation {
+                uri: root_uri(root),
+                uri_base_id: None,
+            },
+        );
+
+        Self {
+            schema: SARIF_SCHEMA,
+            version: SARIF_VERSION,
+            runs: vec![SarifRun {
+                tool: SarifTool {
+                    driver: SarifDriver {
+                        name: app_name(),
+                        version: env!("CARGO_PKG_VERSION"),
+                        information_uri: repo_url(),
+                        rules: descriptors,
+                    },
+                },
+                original_uri_base_ids,
+                results,
+            }],
+        }
+    }
+
+    pub fn to_json(&self) -> Option<String> {
+        serde_json::to_string_pretty(self).ok()
+    }
+}
+
+pub struct SarifReporter;
+
+impl Reporter for SarifReporter {
+    fn report(&self, ctx: &ReportContext) -> Option<String> {
+        SarifLog::build(ctx.root, ctx.result, ctx.rules).to_json()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::Value;
+    use tscanner_types::{IssueFix, Severity, TextEdit, TextRange};
+
+    use super::super::test_support::{issue, render, scan_result};
+    use super::SarifReporter;
+    use crate::types::RuleDescriptor;
+
+    fn descriptor(name: &str) -> RuleDescriptor {
+        RuleDescriptor {
+            name: name.to_string(),
+            description: format!("{} description", name),
+            help_uri: None,
+            default_severity: Severity::Warning,
+        }
+    }
+
+    #[test]
+    fn serializes_rule_index_region_and_fixes() {
+        let mut fixable = issue("no-var", "src/a.ts", 4, "Use let or const");
+        fixable.end_column = 12;
+        fixable.fix = Some(IssueFix {
+            title: "Replace var with let".to_string(),
+            edits: vec![TextEdit::new(TextRange::single_line(4, 3, 6), "let")],
+        });
+        let result = scan_result(vec![
+            fixable,
+            issue("undeclared", "src/b.ts", 1, "Not in descriptors"),
+        ]);
+        let rules = [descriptor("no-console"), descriptor("no-var")];
+
+        let log: Value =
+            serde_json::from_str(&render(&SarifReporter, &result, &rules, None)).unwrap();
+        let run = &log["runs"][0];
+        let results = run["results"].as_array().unwrap();
+
+        assert_eq!(log["version"], "2.1.0");
+        assert_eq!(results[0]["ruleId"], "no-var");
+        assert_eq!(results[0]["ruleIndex"], 1);
+        assert_eq!(run["tool"]["driver"]["rules"][1]["id"], "no-var");
+        assert_eq!(results[1]["ruleIndex"], 2);
+        assert_eq!(run["tool"]["driver"]["rules"][2]["id"], "undeclared");
+
+        let location = &results[0]["locations"][0]["physicalLocation"];
+        assert_eq!(location["artifactLocation"]["uri"], "src/a.ts");
+        assert_eq!(location["artifactLocation"]["uriBaseId"], "%SRCROOT%");
+        assert_eq!(location["region"]["startLine"], 4);
+        assert_eq!(location["region"]["startColumn"], 3);
+        assert_eq!(location["region"]["endColumn"], 12);
+
+        let fix = &results[0]["fixes"][0];
+        assert_eq!(fix["description"]["text"], "Replace var with let");
+        let change = &fix["artifactChanges"][0];
+        assert_eq!(change["artifactLocation"]["uri"], "src/a.ts");
+        let replacement = &change["replacements"][0];
+        assert_eq!(replacement["deletedRegion"]["startColumn"], 3);
+        assert_eq!(replacement["deletedRegion"]["endColumn"], 6);
+        assert_eq!(replacement["insertedContent"]["text"], "let");
+        assert!(results[1].get("fixes").is_none());
+    }
+}
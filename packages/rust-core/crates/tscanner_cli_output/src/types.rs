@@ -2,12 +2,13 @@ use serde::Serialize;
 use tscanner_constants::{
     icon_ai, icon_builtin, icon_error, icon_hint, icon_info, icon_regex, icon_script, icon_warning,
 };
-use tscanner_types::IssueRuleType;
+use tscanner_types::{IssueBlame, IssueRuleType, Severity};
 
 #[derive(Debug, Clone)]
 pub enum GroupMode {
     File,
     Rule,
+    Author,
 }
 
 #[derive(Clone, Default, Serialize)]
@@ -18,16 +19,46 @@ pub struct RulesBreakdown {
     pub ai: usize,
 }
 
+#[derive(Debug, Clone)]
+pub struct RuleDescriptor {
+    pub name: String,
+    pub description: String,
+    pub help_uri: Option<String>,
+    pub default_severity: Severity,
+}
+
 #[derive(Clone, Serialize)]
 pub struct OutputIssue {
     pub rule: String,
     pub severity: String,
     pub line: usize,
     pub column: usize,
+    pub end_line: usize,
+    pub end_column: usize,
     pub message: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub line_text: Option<String>,
     pub rule_type: IssueRuleType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fix_title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub docs_url: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub related: Vec<OutputRelatedLocation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blame: Option<IssueBlame>,
+}
+
+#[derive(Clone, Serialize)]
+pub struct OutputRelatedLocation {
+    pub file: String,
+    pub line: usize,
+    pub column: usize,
+    pub end_line: usize,
+    pub end_column: usize,
+    pub message: String,
 }
 
 #[derive(Clone, Serialize)]
@@ -50,9 +81,29 @@ pub struct OutputRuleIssue {
     pub file: String,
     pub line: usize,
     pub column: usize,
+    pub end_line: usize,
+    pub end_column: usize,
     pub severity: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub line_text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fix_title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub docs_url: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub related: Vec<OutputRelatedLocation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blame: Option<IssueBlame>,
+}
+
+#[derive(Clone, Serialize)]
+pub struct OutputAuthorGroup {
+    pub author: String,
+    pub email: String,
+    pub count: usize,
+    pub files: Vec<OutputFileGroup>,
 }
 
 #[derive(Clone, Serialize)]
@@ -79,6 +130,16 @@ pub struct OutputSummary {
     pub scan_errors: Vec<String>,
 }
 
+impl OutputAuthorGroup {
+    pub fn display_name(&self) -> String {
+        if self.email.is_empty() {
+            self.author.clone()
+        } else {
+            format!("{} <{}>", self.author, self.email)
+        }
+    }
+}
+
 pub struct IssuePart {
     pub count: usize,
     pub label: &'static str,
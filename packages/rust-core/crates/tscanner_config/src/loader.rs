@@ -1,7 +1,7 @@
 use std::path::Path;
 
 use crate::ai_rules_validator::validate_ai_rules;
-use crate::types::{AiProvider, CompiledRuleConfig, TscannerConfig};
+use crate::types::{AiMode, AiProvider, CompiledRuleConfig, TscannerConfig};
 use crate::validation::{validate_json_fields, ValidationResult};
 use tscanner_constants::{config_dir_name, config_error_prefix};
 
@@ -81,6 +81,36 @@ impl TscannerConfigExt for TscannerConfig {
             {
                 result.add_error("ai.command is required when ai.provider is 'custom'".to_string());
             }
+
+            if ai_config.max_concurrency == Some(0) {
+                result.add_error("ai.maxConcurrency must be at least 1".to_string());
+            }
+
+            if ai_config.requests_per_minute == Some(0) {
+                result.add_error(
+                    "ai.requestsPerMinute must be at least 1 (omit it for no limit)".to_string(),
+                );
+            }
+
+            if let Some(provider) = ai_config.provider.filter(|p| p.is_http()) {
+                if ai_config.model.as_ref().is_none_or(|m| m.trim().is_empty()) {
+                    result.add_error(format!(
+                        "ai.model is required when ai.provider is '{}'",
+                        provider.as_str()
+                    ));
+                }
+
+                for (name, rule) in &self.ai_rules {
+                    if rule.mode != AiMode::Content {
+                        result.add_warning(format!(
+                            "AI rule '{}' uses mode '{}' but the '{}' provider cannot read files; use mode 'content'",
+                            name,
+                            rule.mode.as_str(),
+                            provider.as_str()
+                        ));
+                    }
+                }
+            }
         }
 
         for (name, regex_config) in &self.rules.regex {
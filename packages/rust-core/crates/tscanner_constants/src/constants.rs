@@ -15,7 +15,6 @@ struct Constants {
 #[serde(rename_all = "camelCase")]
 struct SharedConfig {
     package_name: String,
-    #[allow(dead_code)]
     package_display_name: String,
     #[allow(dead_code)]
     package_description: String,
@@ -38,7 +37,6 @@ struct SharedConfig {
 #[derive(Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 struct UrlsConfig {
-    #[allow(dead_code)]
     repo: String,
     #[allow(dead_code)]
     repo_blob: String,
@@ -72,6 +70,7 @@ struct LspMethodsConfig {
 struct CoreRustConfig {
     defaults: Defaults,
     cache: CacheConfig,
+    history: HistoryConfig,
     ai: AiConstantsConfig,
 }
 
@@ -100,6 +99,7 @@ struct CodeEditorDefaults {
 struct DirectoryDefaults {
     script_rules: String,
     ai_rules: String,
+    rule_tests: String,
 }
 
 #[derive(Deserialize, Clone)]
@@ -128,14 +128,43 @@ struct ExtensionsConfig {
 #[serde(rename_all = "camelCase")]
 struct CacheConfig {
     dir_name: String,
+    max_size_mb: u64,
+    max_age_days: u64,
+}
+
+#[derive(Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+struct HistoryConfig {
+    file_name: String,
 }
 
 #[derive(Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 struct AiConstantsConfig {
     temp_dir: String,
+    chars_per_token: usize,
+    limits: AiLimitsConfig,
+    repair: AiRepairConfig,
     placeholders: AiPlaceholdersConfig,
     providers: AiProvidersConfig,
+    default_max_tokens: u64,
+}
+
+#[derive(Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+struct AiLimitsConfig {
+    max_concurrency: usize,
+    max_retries: usize,
+    backoff_base_ms: u64,
+    backoff_max_ms: u64,
+}
+
+#[derive(Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+struct AiRepairConfig {
+    max_attempts: usize,
+    max_errors: usize,
+    max_response_chars: usize,
 }
 
 #[derive(Deserialize, Clone)]
@@ -151,6 +180,8 @@ struct AiPlaceholdersConfig {
 struct AiProvidersConfig {
     claude: AiProviderConfig,
     gemini: AiProviderConfig,
+    openai: AiHttpProviderConfig,
+    anthropic: AiHttpProviderConfig,
 }
 
 #[derive(Deserialize, Clone)]
@@ -160,6 +191,15 @@ struct AiProviderConfig {
     args: Vec<String>,
 }
 
+#[derive(Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+struct AiHttpProviderConfig {
+    base_url: String,
+    api_key_env: String,
+    #[serde(default)]
+    api_version: String,
+}
+
 lazy_static::lazy_static! {
     static ref CONSTANTS: Constants = serde_json::from_str(CONSTANTS_JSON)
         .expect("Failed to parse constants.json");
@@ -169,6 +209,10 @@ pub fn app_name() -> &'static str {
     &CONSTANTS.shared.package_name
 }
 
+pub fn app_display_name() -> &'static str {
+    &CONSTANTS.shared.package_display_name
+}
+
 pub fn config_dir_name() -> &'static str {
     &CONSTANTS.shared.config_dir_name
 }
@@ -221,6 +265,10 @@ pub fn ai_rules_dir() -> &'static str {
     &CONSTANTS.core_rust.defaults.directories.ai_rules
 }
 
+pub fn rule_tests_dir() -> &'static str {
+    &CONSTANTS.core_rust.defaults.directories.rule_tests
+}
+
 pub fn default_highlight_errors() -> bool {
     CONSTANTS.core_rust.defaults.code_editor.highlight_errors
 }
@@ -318,10 +366,54 @@ pub fn cache_dir_name() -> &'static str {
     &CONSTANTS.core_rust.cache.dir_name
 }
 
+pub fn cache_max_size_mb() -> u64 {
+    CONSTANTS.core_rust.cache.max_size_mb
+}
+
+pub fn cache_max_age_days() -> u64 {
+    CONSTANTS.core_rust.cache.max_age_days
+}
+
+pub fn history_file_name() -> &'static str {
+    &CONSTANTS.core_rust.history.file_name
+}
+
 pub fn ai_temp_dir() -> &'static str {
     &CONSTANTS.core_rust.ai.temp_dir
 }
 
+pub fn ai_chars_per_token() -> usize {
+    CONSTANTS.core_rust.ai.chars_per_token
+}
+
+pub fn ai_default_max_concurrency() -> usize {
+    CONSTANTS.core_rust.ai.limits.max_concurrency
+}
+
+pub fn ai_default_max_retries() -> usize {
+    CONSTANTS.core_rust.ai.limits.max_retries
+}
+
+pub fn ai_backoff_base_ms() -> u64 {
+    CONSTANTS.core_rust.ai.limits.backoff_base_ms
+}
+
+pub fn ai_backoff_max_ms() -> u64 {
+    CONSTANTS.core_rust.ai.limits.backoff_max_ms
+}
+
+pub fn ai_repair_max_attempts() -> usize {
+    CONSTANTS.core_rust.ai.repair.max_attempts
+}
+
+pub fn ai_repair_max_errors() -> usize {
+    CONSTANTS.core_rust.ai.repair.max_errors
+}
+
+pub fn ai_repair_max_response_chars() -> usize {
+    CONSTANTS.core_rust.ai.repair.max_response_chars
+}
+
 pub fn ai_placeholder_files() -> &'static str {
     &CONSTANTS.core_rust.ai.placeholders.files
 }
@@ -350,10 +442,47 @@ pub fn gemini_args() -> &'static [String] {
     &CONSTANTS.core_rust.ai.providers.gemini.args
 }
 
+pub fn openai_base_url() -> &'static str {
+    &CONSTANTS.core_rust.ai.providers.openai.base_url
+}
+
+pub fn openai_api_key_env() -> &'static str {
+    &CONSTANTS.core_rust.ai.providers.openai.api_key_env
+}
+
+pub fn anthropic_base_url() -> &'static str {
+    &CONSTANTS.core_rust.ai.providers.anthropic.base_url
+}
+
+pub fn anthropic_api_key_env() -> &'static str {
+    &CONSTANTS.core_rust.ai.providers.anthropic.api_key_env
+}
+
+pub fn anthropic_api_version() -> &'static str {
+    &CONSTANTS.core_rust.ai.providers.anthropic.api_version
+}
+
+pub fn ai_default_max_tokens() -> u64 {
+    CONSTANTS.core_rust.ai.default_max_tokens
+}
+
+pub fn repo_url() -> &'static str {
+    &CONSTANTS.shared.urls.repo
+}
+
 pub fn rules_base_url() -> &'static str {
     &CONSTANTS.shared.urls.rules_base
 }
 
+pub fn rule_docs_url(category: &str, rule: &str) -> String {
+    format!(
+        "{}/{}/{}.rs",
+        rules_base_url(),
+        category,
+        rule.replace('-', "_")
+    )
+}
+
 pub fn registry_base_url_template() -> &'static str {
     &CONSTANTS.shared.urls.registry_base
 }
@@ -0,0 +1,180 @@
+use anyhow::Result;
+use std::collections::{BTreeSet, HashMap};
+use std::path::Path;
+
+use crate::git::{run_git, run_git_with_stdin};
+
+#[derive(Debug, Clone, Default)]
+pub struct BlameInfo {
+    pub commit: String,
+    pub author: String,
+    pub email: String,
+    pub timestamp: i64,
+}
+
+pub fn get_blame(
+    root: &Path,
+    path: &Path,
+    lines: &BTreeSet<usize>,
+    revision: Option<&str>,
+) -> Result<HashMap<usize, BlameInfo>> {
+    if lines.is_empty() {
+        return Ok(HashMap::new());
+    }
+
+    let relative = path.strip_prefix(root).unwrap_or(path);
+    let relative = relative.to_string_lossy();
+    let ranges = line_ranges(lines);
+
+    let mut args: Vec<&str> = vec!["blame", "--incremental", "-w"];
+    args.extend(ranges.iter().map(String::as_str));
+    if let Some(revision) = revision {
+        args.push(revision);
+    }
+    args.push("--");
+    args.push(&relative);
+
+    let stdout = run_git(root, &args, "blame")?;
+    Ok(parse_incremental_blame(&stdout))
+}
+
+pub fn get_blame_for_contents(
+    root: &Path,
+    path: &Path,
+    lines: &BTreeSet<usize>,
+    contents: &str,
+) -> Result<HashMap<usize, BlameInfo>> {
+    if lines.is_empty() {
+        return Ok(HashMap::new());
+    }
+
+    let relative = path.strip_prefix(root).unwrap_or(path);
+    let relative = relative.to_string_lossy();
+    let ranges = line_ranges(lines);
+
+    let mut args: Vec<&str> = vec!["blame", "--incremental", "-w", "--contents", "-"];
+    args.extend(ranges.iter().map(String::as_str));
+    args.push("--");
+    args.push(&relative);
+
+    let stdout = run_git_with_stdin(root, &args, "blame", contents)?;
+    Ok(parse_incremental_blame(&stdout))
+}
+
+fn line_ranges(lines: &BTreeSet<usize>) -> Vec<String> {
+    lines.iter().map(|l| format!("-L{},{}", l, l)).collect()
+}
+
+fn parse_incremental_blame(output: &str) -> HashMap<usize, BlameInfo> {
+    let mut commits: HashMap<String, BlameInfo> = HashMap::new();
+    let mut result = HashMap::new();
+    let mut current: Option<(String, usize, usize)> = None;
+
+    for line in output.lines() {
+        let Some((sha, start, count)) = current.clone() else {
+            let mut parts = line.split_whitespace();
+            if let (Some(sha), Some(_), Some(start), Some(count)) =
+                (parts.next(), parts.next(), parts.next(), parts.next())
+            {
+                if let (Ok(start), Ok(count)) = (start.parse(), count.parse()) {
+                    commits.entry(sha.to_string()).or_insert_with(|| BlameInfo {
+                        commit: sha.to_string(),
+                        ..Default::default()
+                    });
+                    current = Some((sha.to_string(), start, count));
+                }
+            }
+            continue;
+        };
+
+        let info = commits.entry(sha.clone()).or_default();
+        if let Some(author) = line.strip_prefix("author ") {
+            info.author = author.to_string();
+        } else if let Some(email) = line.strip_prefix("author-mail ") {
+            info.email = email
+                .trim_start_matches('<')
+                .trim_end_matches('>')
+                .to_string();
+        } else if let Some(time) = line.strip_prefix("author-time ") {
+            info.timestamp = time.parse().unwrap_or_default();
+        } else if line.starts_with("filename ") {
+            for line_number in start..start + count {
+                result.insert(line_number, info.clone());
+            }
+            current = None;
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIRST: &str = "1111111111111111111111111111111111111111";
+    const SECOND: &str = "2222222222222222222222222222222222222222";
+
+    #[test]
+    fn parses_incremental_porcelain() {
+        let output = format!(
+            "\
+{FIRST} 1 1 2
+author Ada Lovelace
+author-mail <ada@example.com>
+author-time 1700000000
+author-tz +0000
+committer Ada Lovelace
+committer-mail <ada@example.com>
+committer-time 1700000000
+committer-tz +0000
+summary Initial commit
+boundary
+filename src/a.ts
+{SECOND} 3 5 1
+author Grace Hopper
+author-mail <grace@example.com>
+author-time 1710000000
+author-tz -0500
+committer Grace Hopper
+committer-mail <grace@example.com>
+committer-time 1710000000
+committer-tz -0500
+summary Add logging
+previous {FIRST} src/a.ts
+filename src/a.ts
+{FIRST} 9 8 1
+filename src/a.ts
+"
+        );
+
+        let blame = parse_incremental_blame(&output);
+
+        let mut lines: Vec<usize> = blame.keys().copied().collect();
+        lines.sort_unstable();
+        assert_eq!(lines, vec![1, 2, 5, 8]);
+
+        assert_eq!(blame[&1].commit, FIRST);
+        assert_eq!(blame[&2].author, "Ada Lovelace");
+        assert_eq!(blame[&5].commit, SECOND);
+        assert_eq!(blame[&5].author, "Grace Hopper");
+        assert_eq!(blame[&5].email, "grace@example.com");
+        assert_eq!(blame[&5].timestamp, 1710000000);
+        assert_eq!(blame[&8].email, "ada@example.com");
+        assert_eq!(blame[&8].timestamp, 1700000000);
+    }
+
+    #[test]
+    fn ignores_malformed_headers() {
+        let output = "not a header\nauthor Nobody\nfilename src/a.ts\n";
+
+        assert!(parse_incremental_blame(output).is_empty());
+    }
+
+    #[test]
+    fn line_ranges_use_single_line_spans() {
+        let lines = BTreeSet::from([3, 7]);
+
+        assert_eq!(line_ranges(&lines), vec!["-L3,3", "-L7,7"]);
+    }
+}
@@ -0,0 +1,251 @@
+use std::collections::{HashMap, HashSet};
+
+struct Hunk {
+    old_remaining: usize,
+    new_remaining: usize,
+}
+
+fn parse_range(range: &str) -> Option<(usize, usize)> {
+    let mut parts = range.split(',');
+    let start = parts.next()?.parse().ok()?;
+    let count = match parts.next() {
+        Some(count) => count.parse().ok()?,
+        None => 1,
+    };
+    Some((start, count))
+}
+
+fn parse_hunk_header(line: &str) -> Option<(usize, Hunk)> {
+    let mut parts = line.split_whitespace().skip(1);
+    let (_, old_count) = parse_range(parts.next()?.strip_prefix('-')?)?;
+    let (new_start, new_count) = parse_range(parts.next()?.strip_prefix('+')?)?;
+    Some((
+        new_start,
+        Hunk {
+            old_remaining: old_count,
+            new_remaining: new_count,
+        },
+    ))
+}
+
+fn unquote_path(raw: &str) -> String {
+    let Some(quoted) = raw.strip_prefix('"').and_then(|p| p.strip_suffix('"')) else {
+        return raw.to_string();
+    };
+
+    let mut bytes = Vec::with_capacity(quoted.len());
+    let mut chars = quoted.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            let mut buf = [0; 4];
+            bytes.extend_from_slice(c.encode_utf8(&mut buf).as_bytes());
+            continue;
+        }
+        match chars.next() {
+            Some('a') => bytes.push(0x07),
+            Some('b') => bytes.push(0x08),
+            Some('t') => bytes.push(b'\t'),
+            Some('n') => bytes.push(b'\n'),
+            Some('v') => bytes.push(0x0b),
+            Some('f') => bytes.push(0x0c),
+            Some('r') => bytes.push(b'\r'),
+            Some(d @ '0'..='7') => {
+                let mut value = d.to_digit(8).unwrap_or(0);
+                for _ in 0..2 {
+                    match chars.peek().and_then(|c| c.to_digit(8)) {
+                        Some(digit) => {
+                            value = value * 8 + digit;
+                            chars.next();
+                        }
+                        None => break,
+                    }
+                }
+                bytes.push(value as u8);
+            }
+            Some(other) => {
+                let mut buf = [0; 4];
+                bytes.extend_from_slice(other.encode_utf8(&mut buf).as_bytes());
+            }
+            None => bytes.push(b'\\'),
+        }
+    }
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+fn parse_file_path(raw: &str) -> Option<String> {
+    let path = raw.split('\t').next().unwrap_or(raw).trim_end();
+    let path = unquote_path(path);
+    if path == "/dev/null" {
+        return None;
+    }
+    Some(path.strip_prefix("b/").map(str::to_string).unwrap_or(path))
+}
+
+pub fn parse_modified_lines(diff_output: &str) -> HashMap<String, HashSet<usize>> {
+    let mut file_lines: HashMap<String, HashSet<usize>> = HashMap::new();
+    let mut current_file: Option<String> = None;
+    let mut current_line: usize = 0;
+    let mut hunk: Option<Hunk> = None;
+
+    for line in diff_output.lines() {
+        if let Some(ref mut h) = hunk {
+            match line.as_bytes().first() {
+                Some(b'+') => {
+                    if let Some(ref file) = current_file {
+                        file_lines
+                            .entry(file.clone())
+                            .or_default()
+                            .insert(current_line);
+                    }
+                    current_line += 1;
+                    h.new_remaining = h.new_remaining.saturating_sub(1);
+                }
+                Some(b'-') => h.old_remaining = h.old_remaining.saturating_sub(1),
+                Some(b'\\') => {}
+                _ => {
+                    current_line += 1;
+                    h.old_remaining = h.old_remaining.saturating_sub(1);
+                    h.new_remaining = h.new_remaining.saturating_sub(1);
+                }
+            }
+            if h.old_remaining == 0 && h.new_remaining == 0 {
+                hunk = None;
+            }
+            continue;
+        }
+
+        if line.starts_with("diff ") {
+            current_file = None;
+        } else if let Some(path) = line.strip_prefix("rename to ") {
+            let path = unquote_path(path);
+            file_lines.entry(path.clone()).or_default();
+            current_file = Some(path);
+        } else if let Some(path) = line.strip_prefix("+++ ") {
+            current_file = parse_file_path(path);
+            if let Some(ref file) = current_file {
+                file_lines.entry(file.clone()).or_default();
+            }
+        } else if line.starts_with("@@") {
+            if let Some((start, h)) = parse_hunk_header(line) {
+                current_line = start;
+                hunk = (h.old_remaining > 0 || h.new_remaining > 0).then_some(h);
+            }
+        }
+    }
+
+    file_lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lines(diff: &HashMap<String, HashSet<usize>>, file: &str) -> Vec<usize> {
+        let mut lines: Vec<usize> = diff[file].iter().copied().collect();
+        lines.sort_unstable();
+        lines
+    }
+
+    #[test]
+    fn rename_without_hunks_tracks_new_path() {
+        let diff = "\
+diff --git a/src/old.ts b/src/new.ts
+similarity index 100%
+rename from src/old.ts
+rename to src/new.ts
+";
+        let result = parse_modified_lines(diff);
+        assert_eq!(result.len(), 1);
+        assert!(result["src/new.ts"].is_empty());
+    }
+
+    #[test]
+    fn deleted_file_is_ignored() {
+        let diff = "\
+diff --git a/src/gone.ts b/src/gone.ts
+deleted file mode 100644
+--- a/src/gone.ts
++++ /dev/null
+@@ -1,2 +0,0 @@
+-const a = 1;
+-const b = 2;
+diff --git a/src/kept.ts b/src/kept.ts
+--- a/src/kept.ts
++++ b/src/kept.ts
+@@ -1 +1 @@
+-const a = 1;
++const a = 2;
+";
+        let result = parse_modified_lines(diff);
+        assert_eq!(result.len(), 1);
+        assert_eq!(lines(&result, "src/kept.ts"), vec![1]);
+    }
+
+    #[test]
+    fn no_newline_marker_inside_hunk_is_skipped() {
+        let diff = "\
+--- a/src/a.ts
++++ b/src/a.ts
+@@ -1,2 +1,3 @@
+ const a = 1;
+-const b = 2;
+\\ No newline at end of file
++const b = 3;
++const c = 4;
+\\ No newline at end of file
+";
+        let result = parse_modified_lines(diff);
+        assert_eq!(lines(&result, "src/a.ts"), vec![2, 3]);
+    }
+
+    #[test]
+    fn p0_patch_without_prefix_keeps_path() {
+        let diff = "\
+--- src/a.ts\t2024-01-01 00:00:00
++++ src/a.ts\t2024-01-02 00:00:00
+@@ -1 +1,2 @@
+ const a = 1;
++const b = 2;
+";
+        let result = parse_modified_lines(diff);
+        assert_eq!(lines(&result, "src/a.ts"), vec![2]);
+    }
+
+    #[test]
+    fn multiple_hunks_use_their_own_start_lines() {
+        let diff = "\
+--- a/src/a.ts
++++ b/src/a.ts
+@@ -1,3 +1,4 @@
+ line1
++added2
+ line2
+ line3
+@@ -20,2 +21,3 @@ function tail() {
+ line20
+-line21
++changed22
++added23
+";
+        let result = parse_modified_lines(diff);
+        assert_eq!(lines(&result, "src/a.ts"), vec![2, 22, 23]);
+    }
+
+    #[test]
+    fn quoted_paths_decode_c_style_escapes() {
+        let diff = "\
+diff --git \"a/src/caf\\303\\251.ts\" \"b/src/caf\\303\\251.ts\"
+--- \"a/src/caf\\303\\251.ts\"
++++ \"b/src/caf\\303\\251.ts\"
+@@ -0,0 +1 @@
++const a = 1;
+diff --git a/src/old.ts \"b/src/tab\\there.ts\"
+rename from src/old.ts
+rename to \"src/tab\\there.ts\"
+";
+        let result = parse_modified_lines(diff);
+        assert_eq!(lines(&result, "src/café.ts"), vec![1]);
+        assert!(result["src/tab\there.ts"].is_empty());
+        assert_eq!(unquote_path(r#""a \"b\" \\ c""#), r#"a "b" \ c"#);
+    }
+}
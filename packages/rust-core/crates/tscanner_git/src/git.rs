@@ -1,48 +1,14 @@
 use anyhow::{Context, Result};
 use std::collections::{HashMap, HashSet};
+use std::io::Write;
 use std::path::{Path, PathBuf};
-use std::process::Command;
-
-fn parse_modified_lines(diff_output: &str) -> HashMap<String, HashSet<usize>> {
-    let mut file_lines: HashMap<String, HashSet<usize>> = HashMap::new();
-    let mut current_file: Option<String> = None;
-    let mut current_line: usize = 0;
-
-    for line in diff_output.lines() {
-        if line.starts_with("diff --git") {
-            current_file = None;
-            current_line = 0;
-        } else if line.starts_with("+++") {
-            if let Some(file_path) = line.strip_prefix("+++ b/") {
-                current_file = Some(file_path.to_string());
-            }
-        } else if line.starts_with("@@") {
-            if let Some(hunk_info) = line.split("@@").nth(1) {
-                if let Some(new_info) = hunk_info.split_whitespace().nth(1) {
-                    if let Some(line_num) = new_info.trim_start_matches('+').split(',').next() {
-                        current_line = line_num.parse::<usize>().unwrap_or(0);
-                    }
-                }
-            }
-        } else if let Some(ref file) = current_file {
-            if line.starts_with('+') && !line.starts_with("+++") {
-                file_lines
-                    .entry(file.clone())
-                    .or_default()
-                    .insert(current_line);
-                current_line += 1;
-            } else if !line.starts_with('-') {
-                current_line += 1;
-            }
-        }
-    }
+use std::process::{Command, Stdio};
 
-    file_lines
-}
+use crate::diff::parse_modified_lines;
 
 pub fn get_changed_files(root: &Path, branch: &str) -> Result<HashSet<PathBuf>> {
     let output = Command::new("git")
-        .args(["diff", "-w", "--name-only", branch])
+        .args(["diff", "-w", "-M", "--name-only", branch])
         .current_dir(root)
         .output()
         .context("Failed to execute git diff")?;
@@ -65,6 +31,7 @@ pub fn get_staged_files(root: &Path) -> Result<HashSet<PathBuf>> {
         .args([
             "diff",
             "-w",
+            "-M",
             "--cached",
             "--name-only",
             "--diff-filter=ACMR",
@@ -115,34 +82,371 @@ fn get_modified_lines_internal(
 }
 
 pub fn get_modified_lines(root: &Path, branch: &str) -> Result<HashMap<PathBuf, HashSet<usize>>> {
-    get_modified_lines_internal(root, &["diff", "-w", branch], "diff")
+    get_modified_lines_internal(root, &["diff", "-w", "-M", branch], "diff")
 }
 
 pub fn get_staged_modified_lines(root: &Path) -> Result<HashMap<PathBuf, HashSet<usize>>> {
-    get_modified_lines_internal(root, &["diff", "-w", "--cached"], "diff --cached")
+    get_modified_lines_internal(root, &["diff", "-w", "-M", "--cached"], "diff --cached")
 }
 
 pub fn get_uncommitted_files(root: &Path) -> Result<HashSet<PathBuf>> {
+    let index = UntrackedIndex::create(root)?;
+    let stdout = run_git_with_index(
+        root,
+        &[
+            "diff",
+            "-w",
+            "-M",
+            "--name-only",
+            "--diff-filter=ACMR",
+            "HEAD",
+        ],
+        "diff HEAD",
+        index.as_ref().map(|i| i.path.as_path()),
+    )?;
+
+    let files = stdout
+        .lines()
+        .filter(|l| !l.is_empty())
+        .map(|l| root.join(l))
+        .collect();
+
+    Ok(files)
+}
+
+pub fn get_uncommitted_modified_lines(root: &Path) -> Result<HashMap<PathBuf, HashSet<usize>>> {
+    let index = UntrackedIndex::create(root)?;
+    let stdout = run_git_with_index(
+        root,
+        &["diff", "-w", "-M", "HEAD"],
+        "diff HEAD",
+        index.as_ref().map(|i| i.path.as_path()),
+    )?;
+
+    Ok(parse_modified_lines(&stdout)
+        .into_iter()
+        .map(|(file, lines)| (root.join(file), lines))
+        .collect())
+}
+
+struct UntrackedIndex {
+    path: PathBuf,
+}
+
+impl UntrackedIndex {
+    fn create(root: &Path) -> Result<Option<Self>> {
+        let untracked = run_git(
+            root,
+            &["ls-files", "--others", "--exclude-standard", "-z"],
+            "ls-files",
+        )?;
+        if untracked.is_empty() {
+            return Ok(None);
+        }
+
+        let git_index =
+            root.join(run_git(root, &["rev-parse", "--git-path", "index"], "rev-parse")?.trim());
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_nanos())
+            .unwrap_or(0);
+        let index = Self {
+            path: std::env::temp_dir().join(format!(
+                "tscanner-index-{}-{}",
+                std::process::id(),
+                nanos
+            )),
+        };
+        if git_index.exists() {
+            std::fs::copy(&git_index, &index.path).context("Failed to copy git index")?;
+        }
+
+        let mut child = Command::new("git")
+            .args([
+                "add",
+                "--intent-to-add",
+                "--pathspec-from-file=-",
+                "--pathspec-file-nul",
+            ])
+            .current_dir(root)
+            .env("GIT_INDEX_FILE", &index.path)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::piped())
+            .spawn()
+            .context("Failed to execute git add --intent-to-add")?;
+        if let Some(mut stdin) = child.stdin.take() {
+            stdin.write_all(untracked.as_bytes())?;
+        }
+        let output = child.wait_with_output()?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            anyhow::bail!("git add --intent-to-add failed: {}", stderr.trim());
+        }
+
+        Ok(Some(index))
+    }
+}
+
+impl Drop for UntrackedIndex {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+pub fn get_head_commit(root: &Path) -> Result<String> {
     let output = Command::new("git")
-        .args(["diff", "-w", "--name-only", "--diff-filter=ACMR", "HEAD"])
+        .args(["rev-parse", "HEAD"])
         .current_dir(root)
         .output()
-        .context("Failed to execute git diff HEAD")?;
+        .context("Failed to execute git rev-parse")?;
 
     if !output.status.success() {
         let stderr = String::from_utf8_lossy(&output.stderr);
-        anyhow::bail!("git diff HEAD failed: {}", stderr);
+        anyhow::bail!("git rev-parse failed: {}", stderr);
     }
 
-    let files = String::from_utf8_lossy(&output.stdout)
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+pub fn get_repository_id(root: &Path) -> Result<String> {
+    let root_commit = run_git(root, &["rev-list", "--max-parents=0", "HEAD"], "rev-list")?
+        .lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty())
+        .min()
+        .map(str::to_string)
+        .ok_or_else(|| anyhow::anyhow!("repository has no commits"))?;
+    let prefix = run_git(root, &["rev-parse", "--show-prefix"], "rev-parse")?;
+    Ok(format!("{}:{}", root_commit, prefix.trim()))
+}
+
+#[derive(Debug, Clone)]
+pub struct RevisionRange {
+    pub base: String,
+    pub head: String,
+}
+
+pub(crate) fn run_git(root: &Path, args: &[&str], context: &str) -> Result<String> {
+    run_git_with_index(root, args, context, None)
+}
+
+fn run_git_with_index(
+    root: &Path,
+    args: &[&str],
+    context: &str,
+    index_file: Option<&Path>,
+) -> Result<String> {
+    let mut command = Command::new("git");
+    command.args(args).current_dir(root);
+    if let Some(index_file) = index_file {
+        command.env("GIT_INDEX_FILE", index_file);
+    }
+    let output = command
+        .output()
+        .context(format!("Failed to execute git {}", context))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        anyhow::bail!("git {} failed: {}", context, stderr.trim());
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+pub(crate) fn run_git_with_stdin(
+    root: &Path,
+    args: &[&str],
+    context: &str,
+    input: &str,
+) -> Result<String> {
+    let mut child = Command::new("git")
+        .args(args)
+        .current_dir(root)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .context(format!("Failed to execute git {}", context))?;
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(input.as_bytes())?;
+    }
+    let output = child.wait_with_output()?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        anyhow::bail!("git {} failed: {}", context, stderr.trim());
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+pub fn get_merge_base(root: &Path, left: &str, right: &str) -> Result<String> {
+    let stdout = run_git(root, &["merge-base", left, right], "merge-base")?;
+    Ok(stdout.trim().to_string())
+}
+
+pub fn resolve_range(root: &Path, spec: &str) -> Result<RevisionRange> {
+    let (left, right, symmetric) = if let Some((left, right)) = spec.split_once("...") {
+        (left, right, true)
+    } else if let Some((left, right)) = spec.split_once("..") {
+        (left, right, false)
+    } else {
+        anyhow::bail!("Invalid range '{}' (expected A..B or A...B)", spec);
+    };
+
+    let left = if left.is_empty() { "HEAD" } else { left };
+    let right = if right.is_empty() { "HEAD" } else { right };
+
+    let head = run_git(
+        root,
+        &["rev-parse", "--verify", &format!("{}^{{commit}}", right)],
+        "rev-parse",
+    )?
+    .trim()
+    .to_string();
+    let base = if symmetric {
+        get_merge_base(root, left, &head)?
+    } else {
+        run_git(
+            root,
+            &["rev-parse", "--verify", &format!("{}^{{commit}}", left)],
+            "rev-parse",
+        )?
+        .trim()
+        .to_string()
+    };
+
+    Ok(RevisionRange { base, head })
+}
+
+pub fn get_range_changed_files(root: &Path, range: &RevisionRange) -> Result<HashSet<PathBuf>> {
+    let stdout = run_git(
+        root,
+        &[
+            "diff",
+            "-w",
+            "-M",
+            "--name-only",
+            "--diff-filter=ACMR",
+            &range.base,
+            &range.head,
+        ],
+        "diff",
+    )?;
+
+    Ok(stdout
         .lines()
         .filter(|l| !l.is_empty())
         .map(|l| root.join(l))
-        .collect();
+        .collect())
+}
 
-    Ok(files)
+pub fn get_range_modified_lines(
+    root: &Path,
+    range: &RevisionRange,
+) -> Result<HashMap<PathBuf, HashSet<usize>>> {
+    get_modified_lines_internal(
+        root,
+        &["diff", "-w", "-M", &range.base, &range.head],
+        "diff",
+    )
 }
 
-pub fn get_uncommitted_modified_lines(root: &Path) -> Result<HashMap<PathBuf, HashSet<usize>>> {
-    get_modified_lines_internal(root, &["diff", "-w", "HEAD"], "diff HEAD")
+pub fn get_file_at_revision(root: &Path, revision: &str, path: &Path) -> Result<String> {
+    let relative = path.strip_prefix(root).unwrap_or(path);
+    let object = format!(
+        "{}:{}",
+        revision,
+        relative.to_string_lossy().replace('\\', "/")
+    );
+    run_git(root, &["show", &object], "show")
+}
+
+pub fn get_staged_file_content(root: &Path, path: &Path) -> Result<String> {
+    get_file_at_revision(root, "", path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn git(root: &Path, args: &[&str]) -> String {
+        run_git(root, args, "test").unwrap().trim().to_string()
+    }
+
+    fn commit(root: &Path, file: &str, content: &str) -> String {
+        std::fs::write(root.join(file), content).unwrap();
+        git(root, &["add", file]);
+        git(root, &["commit", "-q", "-m", file]);
+        git(root, &["rev-parse", "HEAD"])
+    }
+
+    fn diverged_repo(name: &str) -> (PathBuf, [String; 3]) {
+        let root =
+            std::env::temp_dir().join(format!("tscanner-git-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+        std::fs::create_dir_all(&root).unwrap();
+        git(&root, &["init", "-q"]);
+        git(&root, &["symbolic-ref", "HEAD", "refs/heads/main"]);
+        git(&root, &["config", "user.name", "Test"]);
+        git(&root, &["config", "user.email", "test@example.com"]);
+        git(&root, &["config", "commit.gpgsign", "false"]);
+
+        let base = commit(&root, "base.ts", "base\n");
+        git(&root, &["checkout", "-q", "-b", "feature"]);
+        let feature = commit(&root, "feature.ts", "feature\n");
+        git(&root, &["checkout", "-q", "main"]);
+        let main = commit(&root, "main.ts", "main\n");
+
+        (root, [base, main, feature])
+    }
+
+    #[test]
+    fn two_dot_range_diffs_against_left_tip() {
+        let (root, [_, main, feature]) = diverged_repo("two-dot");
+
+        let range = resolve_range(&root, "main..feature").unwrap();
+
+        assert_eq!(range.base, main);
+        assert_eq!(range.head, feature);
+        let _ = std::fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn three_dot_range_diffs_against_merge_base() {
+        let (root, [base, _, feature]) = diverged_repo("three-dot");
+
+        let range = resolve_range(&root, "main...feature").unwrap();
+        let changed = get_range_changed_files(&root, &range).unwrap();
+
+        assert_eq!(range.base, base);
+        assert_eq!(range.head, feature);
+        assert_eq!(changed, HashSet::from([root.join("feature.ts")]));
+        let _ = std::fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn empty_side_defaults_to_head() {
+        let (root, [base, main, _]) = diverged_repo("empty-side");
+
+        let range = resolve_range(&root, "feature...").unwrap();
+
+        assert_eq!(range.base, base);
+        assert_eq!(range.head, main);
+        let _ = std::fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn invalid_ranges_are_rejected() {
+        let (root, _) = diverged_repo("invalid");
+
+        let missing_separator = resolve_range(&root, "main").unwrap_err();
+        assert!(missing_separator
+            .to_string()
+            .contains("expected A..B or A...B"));
+        assert!(resolve_range(&root, "main..missing-branch").is_err());
+        assert!(resolve_range(&root, "missing-branch...main").is_err());
+        let _ = std::fs::remove_dir_all(&root);
+    }
 }
@@ -1,6 +1,12 @@
+mod blame;
+mod diff;
 mod git;
 
+pub use blame::{get_blame, get_blame_for_contents, BlameInfo};
+pub use diff::parse_modified_lines;
 pub use git::{
-    get_changed_files, get_modified_lines, get_staged_files, get_staged_modified_lines,
-    get_uncommitted_files, get_uncommitted_modified_lines,
+    get_changed_files, get_file_at_revision, get_head_commit, get_merge_base, get_modified_lines,
+    get_range_changed_files, get_range_modified_lines, get_repository_id, get_staged_file_content,
+    get_staged_files, get_staged_modified_lines, get_uncommitted_files,
+    get_uncommitted_modified_lines, resolve_range, RevisionRange,
 };
@@ -1,21 +1,62 @@
-use lsp_types::{CodeDescription, Diagnostic, DiagnosticSeverity, Position, Range, Url};
-use tscanner_constants::{app_name, rules_base_url};
-use tscanner_types::{Issue, Severity};
+use lsp_types::{
+    CodeDescription, Diagnostic, DiagnosticRelatedInformation, DiagnosticSeverity, Location,
+    Position, Range, Url,
+};
+use tscanner_constants::{app_name, rule_docs_url};
+use tscanner_types::{Issue, Severity, TextRange};
 
-fn get_rule_url(rule: &str, category: Option<&str>) -> Option<Url> {
-    let category_folder = category?;
-    let rule_file = rule.replace('-', "_");
-    let url_str = format!("{}/{}/{}.rs", rules_base_url(), category_folder, rule_file);
+fn get_rule_url(issue: &Issue) -> Option<Url> {
+    let url_str = match &issue.docs_url {
+        Some(url) => url.clone(),
+        None => rule_docs_url(issue.category.as_deref()?, &issue.rule),
+    };
     Url::parse(&url_str).ok()
 }
 
+fn to_lsp_range(range: &TextRange) -> Range {
+    Range {
+        start: Position {
+            line: range.start_line.saturating_sub(1) as u32,
+            character: range.start_col.saturating_sub(1) as u32,
+        },
+        end: Position {
+            line: range.end_line.saturating_sub(1) as u32,
+            character: range.end_col.saturating_sub(1) as u32,
+        },
+    }
+}
+
+fn related_information(issue: &Issue) -> Option<Vec<DiagnosticRelatedInformation>> {
+    if issue.related.is_empty() {
+        return None;
+    }
+    let related = issue
+        .related
+        .iter()
+        .filter_map(|related| {
+            Some(DiagnosticRelatedInformation {
+                location: Location {
+                    uri: Url::from_file_path(&related.file).ok()?,
+                    range: to_lsp_range(&related.range),
+                },
+                message: related.message.clone(),
+            })
+        })
+        .collect();
+    Some(related)
+}
+
 pub fn issue_to_diagnostic(issue: &Issue) -> Diagnostic {
     let line = (issue.line.saturating_sub(1)) as u32;
     let column = (issue.column.saturating_sub(1)) as u32;
+    let end_line = (issue.last_line().saturating_sub(1)) as u32;
     let end_column = (issue.end_column.saturating_sub(1)) as u32;
 
-    let code_description =
-        get_rule_url(&issue.rule, issue.category.as_deref()).map(|href| CodeDescription { href });
+    let code_description = get_rule_url(issue).map(|href| CodeDescription { href });
+    let message = match &issue.note {
+        Some(note) => format!("{}\n{}", issue.message, note),
+        None => issue.message.clone(),
+    };
 
     Diagnostic {
         range: Range {
@@ -24,7 +65,7 @@ pub fn issue_to_diagnostic(issue: &Issue) -> Diagnostic {
                 character: column,
             },
             end: Position {
-                line,
+                line: end_line,
                 character: end_column,
             },
         },
@@ -37,7 +78,8 @@ pub fn issue_to_diagnostic(issue: &Issue) -> Diagnostic {
         code: Some(lsp_types::NumberOrString::String(issue.rule.clone())),
         code_description,
         source: Some(app_name().to_string()),
-        message: issue.message.clone(),
+        message,
+        related_information: related_information(issue),
         ..Default::default()
     }
 }
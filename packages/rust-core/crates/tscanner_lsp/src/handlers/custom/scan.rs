@@ -28,7 +28,6 @@ pub fn handle_scan(
     };
 
     let no_cache = params.no_cache.unwrap_or(false);
-    let config_hash = config.compute_hash();
     let (cache, ai_cache, script_cache) = if no_cache {
         (
             Arc::new(FileCache::new()),
@@ -37,9 +36,18 @@ pub fn handle_scan(
         )
     } else {
         (
-            Arc::new(FileCache::with_config_hash(config_hash)),
-            Arc::new(AiCache::with_config_hash(config_hash)),
-            Arc::new(ScriptCache::with_config_hash(config_hash)),
+            Arc::new(
+                FileCache::for_project(&params.root, None, config.file_rule_fingerprints())
+                    .with_limits(config.cache_limits()),
+            ),
+            Arc::new(
+                AiCache::for_project(&params.root, None, config.ai_rule_fingerprints())
+                    .with_limits(config.cache_limits()),
+            ),
+            Arc::new(
+                ScriptCache::for_project(&params.root, None, config.script_rule_fingerprints())
+                    .with_limits(config.cache_limits()),
+            ),
         )
     };
 
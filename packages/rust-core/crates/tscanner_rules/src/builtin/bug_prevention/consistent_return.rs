@@ -1,5 +1,7 @@
 use crate::context::RuleContext;
-use crate::metadata::{RuleCategory, RuleMetadata, RuleMetadataRegistration, RuleType};
+use crate::metadata::{
+    spec_fixture, RuleCategory, RuleExample, RuleMetadata, RuleMetadataRegistration, RuleType,
+};
 use crate::signals::{RuleDiagnostic, TextRange};
 use crate::traits::{Rule, RuleRegistration};
 use crate::utils::get_span_positions;
@@ -24,6 +26,10 @@ inventory::submit!(RuleMetadataRegistration {
         typescript_only: false,
         equivalent_eslint_rule: Some("https://eslint.org/docs/latest/rules/consistent-return"),
         equivalent_biome_rule: None,
+        example: Some(RuleExample {
+            invalid: "",
+            valid: spec_fixture!("consistent-return", "valid.ts"),
+        }),
         ..RuleMetadata::defaults()
     }
 });
@@ -1,5 +1,7 @@
 use crate::context::RuleContext;
-use crate::metadata::{RuleCategory, RuleMetadata, RuleMetadataRegistration, RuleType};
+use crate::metadata::{
+    spec_fixture, RuleCategory, RuleExample, RuleMetadata, RuleMetadataRegistration, RuleType,
+};
 use crate::signals::{RuleDiagnostic, TextRange};
 use crate::traits::{Rule, RuleRegistration};
 use crate::utils::get_span_positions;
@@ -26,6 +28,10 @@ inventory::submit!(RuleMetadataRegistration {
         typescript_only: true,
         equivalent_eslint_rule: Some("https://typescript-eslint.io/rules/no-floating-promises"),
         equivalent_biome_rule: Some("https://biomejs.dev/linter/rules/no-floating-promises"),
+        example: Some(RuleExample {
+            invalid: spec_fixture!("no-floating-promises", "invalid.ts"),
+            valid: spec_fixture!("no-floating-promises", "valid.ts"),
+        }),
         ..RuleMetadata::defaults()
     }
 });
@@ -1,6 +1,7 @@
 use crate::context::RuleContext;
 use crate::metadata::{
-    RuleCategory, RuleMetadata, RuleMetadataRegistration, RuleOption, RuleOptionSchema, RuleType,
+    spec_fixture, RuleCategory, RuleExample, RuleMetadata, RuleMetadataRegistration, RuleOption,
+    RuleOptionSchema, RuleType,
 };
 use crate::signals::{RuleDiagnostic, TextRange};
 use crate::traits::{Rule, RuleRegistration};
@@ -77,6 +78,10 @@ inventory::submit!(RuleMetadataRegistration {
                 minimum: Some(1),
             },
         }],
+        example: Some(RuleExample {
+            invalid: spec_fixture!("max-function-length", "invalid.ts"),
+            valid: spec_fixture!("max-function-length", "valid.ts"),
+        }),
         ..RuleMetadata::defaults()
     }
 });
@@ -1,6 +1,7 @@
 use crate::context::RuleContext;
 use crate::metadata::{
-    RuleCategory, RuleMetadata, RuleMetadataRegistration, RuleOption, RuleOptionSchema, RuleType,
+    spec_fixture, RuleCategory, RuleExample, RuleMetadata, RuleMetadataRegistration, RuleOption,
+    RuleOptionSchema, RuleType,
 };
 use crate::signals::{RuleDiagnostic, TextRange};
 use crate::traits::{Rule, RuleRegistration};
@@ -76,6 +77,10 @@ inventory::submit!(RuleMetadataRegistration {
                 minimum: Some(1),
             },
         }],
+        example: Some(RuleExample {
+            invalid: spec_fixture!("max-params", "invalid.ts"),
+            valid: spec_fixture!("max-params", "valid.ts"),
+        }),
         ..RuleMetadata::defaults()
     }
 });
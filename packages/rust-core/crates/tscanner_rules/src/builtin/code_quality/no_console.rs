@@ -1,6 +1,7 @@
 use crate::context::RuleContext;
 use crate::metadata::{
-    RuleCategory, RuleMetadata, RuleMetadataRegistration, RuleOption, RuleOptionSchema, RuleType,
+    spec_fixture, RuleCategory, RuleExample, RuleMetadata, RuleMetadataRegistration, RuleOption,
+    RuleOptionSchema, RuleType,
 };
 use crate::signals::{RuleDiagnostic, TextRange};
 use crate::traits::{Rule, RuleRegistration};
@@ -99,6 +100,10 @@ inventory::submit!(RuleMetadataRegistration {
                 default: DEFAULT_METHODS,
             },
         }],
+        example: Some(RuleExample {
+            invalid: spec_fixture!("no-console", "invalid.ts"),
+            valid: "",
+        }),
         ..RuleMetadata::defaults()
     }
 });
@@ -1,5 +1,7 @@
 use crate::context::RuleContext;
-use crate::metadata::{RuleCategory, RuleMetadata, RuleMetadataRegistration, RuleType};
+use crate::metadata::{
+    spec_fixture, RuleCategory, RuleExample, RuleMetadata, RuleMetadataRegistration, RuleType,
+};
 use crate::signals::{RuleDiagnostic, TextRange};
 use crate::traits::{Rule, RuleRegistration};
 use crate::utils::get_span_positions;
@@ -32,6 +34,10 @@ inventory::submit!(RuleMetadataRegistration {
         typescript_only: false,
         equivalent_eslint_rule: Some("https://eslint.org/docs/latest/rules/no-nested-ternary"),
         equivalent_biome_rule: Some("https://biomejs.dev/linter/rules/no-nested-ternary"),
+        example: Some(RuleExample {
+            invalid: "",
+            valid: spec_fixture!("no-nested-ternary", "valid.ts"),
+        }),
         ..RuleMetadata::defaults()
     }
 });
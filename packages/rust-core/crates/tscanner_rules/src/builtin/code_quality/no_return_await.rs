@@ -1,5 +1,7 @@
 use crate::context::RuleContext;
-use crate::metadata::{RuleCategory, RuleMetadata, RuleMetadataRegistration, RuleType};
+use crate::metadata::{
+    spec_fixture, RuleCategory, RuleExample, RuleMetadata, RuleMetadataRegistration, RuleType,
+};
 use crate::signals::{RuleDiagnostic, TextRange};
 use crate::traits::{Rule, RuleRegistration};
 use crate::utils::get_span_positions;
@@ -31,6 +33,10 @@ inventory::submit!(RuleMetadataRegistration {
         typescript_only: false,
         equivalent_eslint_rule: Some("https://typescript-eslint.io/rules/return-await"),
         equivalent_biome_rule: None,
+        example: Some(RuleExample {
+            invalid: spec_fixture!("no-return-await", "invalid.ts"),
+            valid: spec_fixture!("no-return-await", "valid.ts"),
+        }),
         ..RuleMetadata::defaults()
     }
 });
@@ -1,6 +1,7 @@
 use crate::context::RuleContext;
 use crate::metadata::{
-    RuleCategory, RuleMetadata, RuleMetadataRegistration, RuleOption, RuleOptionSchema, RuleType,
+    spec_fixture, RuleCategory, RuleExample, RuleMetadata, RuleMetadataRegistration, RuleOption,
+    RuleOptionSchema, RuleType,
 };
 use crate::signals::{RuleDiagnostic, TextRange};
 use crate::traits::{Rule, RuleRegistration};
@@ -77,6 +78,10 @@ inventory::submit!(RuleMetadataRegistration {
                 default: DEFAULT_KEYWORDS,
             },
         }],
+        example: Some(RuleExample {
+            invalid: spec_fixture!("no-todo-comments", "invalid.ts"),
+            valid: spec_fixture!("no-todo-comments", "valid.ts"),
+        }),
         ..RuleMetadata::defaults()
     }
 });
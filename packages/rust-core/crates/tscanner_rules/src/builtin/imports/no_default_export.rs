@@ -1,5 +1,7 @@
 use crate::context::RuleContext;
-use crate::metadata::{RuleCategory, RuleMetadata, RuleMetadataRegistration, RuleType};
+use crate::metadata::{
+    spec_fixture, RuleCategory, RuleExample, RuleMetadata, RuleMetadataRegistration, RuleType,
+};
 use crate::signals::{RuleDiagnostic, TextRange};
 use crate::traits::{Rule, RuleRegistration};
 use crate::utils::get_span_positions;
@@ -30,6 +32,10 @@ inventory::submit!(RuleMetadataRegistration {
         typescript_only: false,
         equivalent_eslint_rule: None,
         equivalent_biome_rule: Some("https://biomejs.dev/linter/rules/no-default-export"),
+        example: Some(RuleExample {
+            invalid: spec_fixture!("no-default-export", "invalid.ts"),
+            valid: spec_fixture!("no-default-export", "valid.ts"),
+        }),
         ..RuleMetadata::defaults()
     }
 });
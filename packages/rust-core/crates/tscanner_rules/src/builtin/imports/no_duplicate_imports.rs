@@ -1,5 +1,7 @@
 use crate::context::RuleContext;
-use crate::metadata::{RuleCategory, RuleMetadata, RuleMetadataRegistration, RuleType};
+use crate::metadata::{
+    spec_fixture, RuleCategory, RuleExample, RuleMetadata, RuleMetadataRegistration, RuleType,
+};
 use crate::signals::{RuleDiagnostic, TextRange};
 use crate::traits::{Rule, RuleRegistration};
 use crate::utils::get_span_positions;
@@ -12,6 +14,7 @@ pub struct DuplicateImportState {
     pub line: usize,
     pub start_col: usize,
     pub end_col: usize,
+    pub first_import: TextRange,
 }
 
 pub struct NoDuplicateImportsRule;
@@ -31,6 +34,10 @@ inventory::submit!(RuleMetadataRegistration {
         typescript_only: false,
         equivalent_eslint_rule: Some("https://eslint.org/docs/latest/rules/no-duplicate-imports"),
         equivalent_biome_rule: Some("https://biomejs.dev/linter/rules/no-duplicate-json-keys"),
+        example: Some(RuleExample {
+            invalid: spec_fixture!("no-duplicate-imports", "invalid.ts"),
+            valid: spec_fixture!("no-duplicate-imports", "valid.ts"),
+        }),
         ..RuleMetadata::defaults()
     }
 });
@@ -57,13 +64,14 @@ impl Rule for NoDuplicateImportsRule {
             TextRange::single_line(state.line, state.start_col, state.end_col),
             "Module is already imported. Merge imports.".to_string(),
         )
+        .with_related(state.first_import.clone(), "First import here")
     }
 }
 
 struct DuplicateImportsVisitor<'a> {
     states: Vec<DuplicateImportState>,
     source: &'a str,
-    seen_imports: HashMap<String, usize>,
+    seen_imports: HashMap<String, TextRange>,
 }
 
 impl<'a> Visit for DuplicateImportsVisitor<'a> {
@@ -76,18 +84,21 @@ impl<'a> Visit for DuplicateImportsVisitor<'a> {
             let src_slice = &self.source[import_start..import_end];
             let module_name = src_slice.trim_matches('"').trim_matches('\'').to_string();
 
-            if let Some(&_first_line) = self.seen_imports.get(&module_name) {
-                let (line, column, end_column) =
-                    get_span_positions(self.source, import_start, import_end);
+            let (line, column, end_column) =
+                get_span_positions(self.source, import_start, import_end);
 
+            if let Some(first_import) = self.seen_imports.get(&module_name) {
                 self.states.push(DuplicateImportState {
                     line,
                     start_col: column,
                     end_col: end_column,
+                    first_import: first_import.clone(),
                 });
             } else {
-                let (line, _, _) = get_span_positions(self.source, import_start, import_end);
-                self.seen_imports.insert(module_name, line);
+                self.seen_imports.insert(
+                    module_name,
+                    TextRange::single_line(line, column, end_column),
+                );
             }
         }
 
@@ -1,5 +1,7 @@
 use crate::context::RuleContext;
-use crate::metadata::{RuleCategory, RuleMetadata, RuleMetadataRegistration, RuleType};
+use crate::metadata::{
+    spec_fixture, RuleCategory, RuleExample, RuleMetadata, RuleMetadataRegistration, RuleType,
+};
 use crate::signals::{RuleDiagnostic, TextRange};
 use crate::traits::{Rule, RuleRegistration};
 use crate::utils::get_span_positions;
@@ -33,6 +35,10 @@ inventory::submit!(RuleMetadataRegistration {
             "https://typescript-eslint.io/rules/consistent-type-definitions"
         ),
         equivalent_biome_rule: None,
+        example: Some(RuleExample {
+            invalid: spec_fixture!("prefer-interface-over-type", "invalid.ts"),
+            valid: spec_fixture!("prefer-interface-over-type", "valid.ts"),
+        }),
         ..RuleMetadata::defaults()
     }
 });
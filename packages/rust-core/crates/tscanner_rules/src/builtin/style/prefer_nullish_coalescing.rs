@@ -1,5 +1,7 @@
 use crate::context::RuleContext;
-use crate::metadata::{RuleCategory, RuleMetadata, RuleMetadataRegistration, RuleType};
+use crate::metadata::{
+    spec_fixture, RuleCategory, RuleExample, RuleMetadata, RuleMetadataRegistration, RuleType,
+};
 use crate::signals::{RuleDiagnostic, TextRange};
 use crate::traits::{Rule, RuleRegistration};
 use crate::utils::get_span_positions;
@@ -30,6 +32,10 @@ inventory::submit!(RuleMetadataRegistration {
         typescript_only: false,
         equivalent_eslint_rule: Some("https://typescript-eslint.io/rules/prefer-nullish-coalescing"),
         equivalent_biome_rule: None,
+        example: Some(RuleExample {
+            invalid: spec_fixture!("prefer-nullish-coalescing", "invalid.ts"),
+            valid: spec_fixture!("prefer-nullish-coalescing", "valid.ts"),
+        }),
         ..RuleMetadata::defaults()
     }
 });
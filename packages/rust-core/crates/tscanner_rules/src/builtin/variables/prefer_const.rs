@@ -1,5 +1,7 @@
 use crate::context::RuleContext;
-use crate::metadata::{RuleCategory, RuleMetadata, RuleMetadataRegistration, RuleType};
+use crate::metadata::{
+    spec_fixture, RuleCategory, RuleExample, RuleMetadata, RuleMetadataRegistration, RuleType,
+};
 use crate::signals::{RuleAction, RuleDiagnostic, TextEdit, TextRange};
 use crate::traits::{Rule, RuleRegistration};
 use crate::utils::get_span_positions;
@@ -26,6 +28,10 @@ inventory::submit!(RuleMetadataRegistration {
         typescript_only: false,
         equivalent_eslint_rule: Some("https://eslint.org/docs/latest/rules/prefer-const"),
         equivalent_biome_rule: Some("https://biomejs.dev/linter/rules/use-const"),
+        example: Some(RuleExample {
+            invalid: spec_fixture!("prefer-const", "invalid.ts"),
+            valid: spec_fixture!("prefer-const", "valid.ts"),
+        }),
         ..RuleMetadata::defaults()
     }
 });
@@ -10,10 +10,10 @@ pub mod utils;
 pub use context::RuleContext;
 pub use executors::{RegexExecutor, RegexRule};
 pub use metadata::{
-    get_all_rule_metadata, RuleCategory, RuleMetadata, RuleMetadataRegistration, RuleOption,
-    RuleOptionSchema, RuleType,
+    get_all_rule_metadata, RuleCategory, RuleExample, RuleMetadata, RuleMetadataRegistration,
+    RuleOption, RuleOptionSchema, RuleType,
 };
 pub use registry::RuleRegistry;
-pub use signals::{ActionKind, RuleAction, RuleDiagnostic, RuleSignal};
+pub use signals::{ActionKind, RelatedInformation, RuleAction, RuleDiagnostic, RuleSignal};
 pub use traits::{DynRule, Rule, RuleRegistration};
 pub use tscanner_types::{FileSource, Language, LanguageVariant, TextEdit, TextRange};
@@ -1,4 +1,20 @@
-pub use tscanner_types::{RuleCategory, RuleMetadata, RuleOption, RuleOptionSchema, RuleType};
+pub use tscanner_types::{
+    RuleCategory, RuleExample, RuleMetadata, RuleOption, RuleOptionSchema, RuleType,
+};
+
+macro_rules! spec_fixture {
+    ($rule:literal, $file:literal) => {
+        include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/specs/",
+            $rule,
+            "/",
+            $file
+        ))
+    };
+}
+
+pub(crate) use spec_fixture;
 
 pub struct RuleMetadataRegistration {
     pub metadata: RuleMetadata,
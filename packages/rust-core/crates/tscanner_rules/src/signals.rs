@@ -1,5 +1,7 @@
 use std::path::Path;
-pub use tscanner_types::{Issue, IssueRuleType, Severity, TextEdit, TextRange};
+pub use tscanner_types::{
+    Issue, IssueFix, IssueRuleType, RelatedLocation, Severity, TextEdit, TextRange,
+};
 
 #[derive(Debug, Clone)]
 pub struct RuleDiagnostic {
@@ -7,6 +9,13 @@ pub struct RuleDiagnostic {
     pub message: String,
     pub severity: Severity,
     pub note: Option<String>,
+    pub related: Vec<RelatedInformation>,
+}
+
+#[derive(Debug, Clone)]
+pub struct RelatedInformation {
+    pub range: TextRange,
+    pub message: String,
 }
 
 impl RuleDiagnostic {
@@ -16,6 +25,7 @@ impl RuleDiagnostic {
             message: message.into(),
             severity: Severity::Warning,
             note: None,
+            related: Vec::new(),
         }
     }
 
@@ -28,6 +38,14 @@ impl RuleDiagnostic {
         self.note = Some(note.into());
         self
     }
+
+    pub fn with_related(mut self, range: TextRange, message: impl Into<String>) -> Self {
+        self.related.push(RelatedInformation {
+            range,
+            message: message.into(),
+        });
+        self
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -87,12 +105,30 @@ impl RuleSignal {
             file: path.to_path_buf(),
             line: self.diagnostic.range.start_line,
             column: self.diagnostic.range.start_col,
+            end_line: self.diagnostic.range.end_line,
             end_column: self.diagnostic.range.end_col,
             message: self.diagnostic.message.clone(),
             severity: self.diagnostic.severity,
             line_text: None,
             category: None,
             rule_type: IssueRuleType::Builtin,
+            fix: self.action.as_ref().map(|action| IssueFix {
+                title: action.title.clone(),
+                edits: action.edits.clone(),
+            }),
+            note: self.diagnostic.note.clone(),
+            docs_url: None,
+            related: self
+                .diagnostic
+                .related
+                .iter()
+                .map(|related| RelatedLocation {
+                    file: path.to_path_buf(),
+                    range: related.range.clone(),
+                    message: related.message.clone(),
+                })
+                .collect(),
+            blame: None,
         }
     }
 }
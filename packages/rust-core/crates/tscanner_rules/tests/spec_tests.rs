@@ -1,23 +1,11 @@
 use std::fs;
 use std::path::Path;
-use tscanner_rules::{FileSource, RuleContext, RuleRegistration};
+use tscanner_rules::{get_all_rule_metadata, FileSource, RuleContext, RuleRegistration};
 use tscanner_scanner::parse_file;
 use tscanner_types::Issue;
 
-fn run_rule_test(input_path: &str) {
-    let input_file = Path::new(input_path);
-    let file_name = input_file.file_name().unwrap().to_str().unwrap();
-
-    let rule_name = input_file
-        .parent()
-        .and_then(|p| p.file_name())
-        .and_then(|n| n.to_str())
-        .expect("test file must be in a rule directory (specs/{rule-name}/)");
-
-    let source = fs::read_to_string(input_file)
-        .unwrap_or_else(|err| panic!("failed to read {input_file:?}: {err:?}"));
-
-    let program = parse_file(input_file, &source)
+fn collect_issues(rule_name: &str, input_file: &Path, source: &str) -> Vec<Issue> {
+    let program = parse_file(input_file, source)
         .unwrap_or_else(|err| panic!("failed to parse {input_file:?}: {err:?}"));
 
     let file_source = FileSource::from_path(input_file);
@@ -28,13 +16,30 @@ fn run_rule_test(input_path: &str) {
         .map(|r| (r.factory)(None))
         .unwrap_or_else(|| panic!("rule '{rule_name}' not found"));
 
-    let ctx = RuleContext::new(&program, input_file, &source, file_source);
+    let ctx = RuleContext::new(&program, input_file, source, file_source);
     let signals = rule.signals(&ctx);
     let mut issues: Vec<Issue> = signals
         .iter()
         .map(|s| s.to_issue(rule_name, input_file))
         .collect();
     issues.sort_by(|a, b| a.line.cmp(&b.line).then(a.column.cmp(&b.column)));
+    issues
+}
+
+fn run_rule_test(input_path: &str) {
+    let input_file = Path::new(input_path);
+    let file_name = input_file.file_name().unwrap().to_str().unwrap();
+
+    let rule_name = input_file
+        .parent()
+        .and_then(|p| p.file_name())
+        .and_then(|n| n.to_str())
+        .expect("test file must be in a rule directory (specs/{rule-name}/)");
+
+    let source = fs::read_to_string(input_file)
+        .unwrap_or_else(|err| panic!("failed to read {input_file:?}: {err:?}"));
+
+    let issues = collect_issues(rule_name, input_file, &source);
 
     let snapshot = format_snapshot(&source, &issues, input_file);
 
@@ -73,6 +78,17 @@ fn format_snapshot(source: &str, issues: &[Issue], path: &Path) -> String {
             ));
             output.push_str(&format!("  ! {}\n\n", issue.message));
 
+            if let Some(note) = &issue.note {
+                output.push_str(&format!("  i {}\n\n", note));
+            }
+
+            for related in &issue.related {
+                output.push_str(&format!(
+                    "  i {} ({}:{})\n\n",
+                    related.message, related.range.start_line, related.range.start_col
+                ));
+            }
+
             let lines: Vec<&str> = source.lines().collect();
             if issue.line > 0 && issue.line <= lines.len() {
                 let line_idx = issue.line - 1;
@@ -107,6 +123,33 @@ fn format_snapshot(source: &str, issues: &[Issue], path: &Path) -> String {
     output
 }
 
+#[test]
+fn rule_examples_match_rule_behavior() {
+    let path = Path::new("example.ts");
+    for metadata in get_all_rule_metadata() {
+        let name = metadata.name;
+        let example = metadata
+            .example
+            .unwrap_or_else(|| panic!("rule '{name}' has no example"));
+
+        if !example.valid.is_empty() {
+            let valid_issues = collect_issues(name, path, example.valid);
+            assert!(
+                valid_issues.is_empty(),
+                "valid example of '{name}' reports issues: {valid_issues:?}"
+            );
+        }
+
+        if !example.invalid.is_empty() {
+            let invalid_issues = collect_issues(name, path, example.invalid);
+            assert!(
+                !invalid_issues.is_empty(),
+                "invalid example of '{name}' reports no issues"
+            );
+        }
+    }
+}
+
 macro_rules! generate_rule_tests {
     ($rule_name:ident) => {
         mod $rule_name {
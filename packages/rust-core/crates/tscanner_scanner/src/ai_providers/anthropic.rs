@@ -0,0 +1,66 @@
+use serde_json::{json, Value};
+use tscanner_constants::{anthropic_api_key_env, anthropic_api_version, anthropic_base_url};
+
+use super::http::{issues_schema, AiHttpProvider, HttpRequest, HttpSettings};
+
+const REPORT_TOOL: &str = "report_issues";
+
+pub struct AnthropicProvider;
+
+impl AiHttpProvider for AnthropicProvider {
+    fn default_base_url(&self) -> &'static str {
+        anthropic_base_url()
+    }
+
+    fn default_api_key_env(&self) -> &'static str {
+        anthropic_api_key_env()
+    }
+
+    fn requires_api_key(&self) -> bool {
+        true
+    }
+
+    fn build_request(&self, settings: &HttpSettings, prompt: &str) -> HttpRequest {
+        let mut body = json!({
+            "model": settings.model,
+            "max_tokens": settings.max_tokens,
+            "messages": [{ "role": "user", "content": prompt }],
+            "tools": [{
+                "name": REPORT_TOOL,
+                "description": "Report the issues found in the analyzed files",
+                "input_schema": issues_schema(),
+            }],
+            "tool_choice": { "type": "tool", "name": REPORT_TOOL },
+        });
+        if let Some(temperature) = settings.temperature {
+            body["temperature"] = json!(temperature);
+        }
+
+        let mut headers = vec![("anthropic-version", anthropic_api_version().to_string())];
+        if let Some(ref key) = settings.api_key {
+            headers.push(("x-api-key", key.clone()));
+        }
+
+        HttpRequest {
+            url: format!("{}/messages", settings.base_url),
+            headers,
+            body,
+        }
+    }
+
+    fn extract_response(&self, body: &Value) -> Option<String> {
+        let blocks = body.get("content")?.as_array()?;
+
+        if let Some(input) = blocks.iter().find_map(|block| {
+            (block.get("type")?.as_str()? == "tool_use").then(|| block.get("input"))?
+        }) {
+            return Some(input.to_string());
+        }
+
+        let text: Vec<&str> = blocks
+            .iter()
+            .filter_map(|block| block.get("text")?.as_str())
+            .collect();
+        (!text.is_empty()).then(|| text.join("\n"))
+    }
+}
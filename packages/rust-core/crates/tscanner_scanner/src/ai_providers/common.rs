@@ -9,13 +9,72 @@ pub trait AiProviderImpl {
     fn parse_error(&self, error_output: &str) -> String {
         truncate_error(error_output)
     }
+    fn is_transient_error(&self, error_output: &str) -> bool {
+        is_transient_output(error_output)
+    }
+}
+
+const TRANSIENT_ERROR_PATTERNS: &[&str] = &[
+    "rate limit",
+    "rate_limit",
+    "too many requests",
+    "overloaded",
+    "service unavailable",
+    "temporarily unavailable",
+    "econnreset",
+    "etimedout",
+];
+
+const TRANSIENT_STATUS_CODES: &[&str] = &["429", "503"];
+
+const STATUS_PREFIXES: &[&str] = &[
+    "status ",
+    "status: ",
+    "status code ",
+    "http ",
+    "http/1.1 ",
+    "http/2 ",
+    "code ",
+    "code: ",
+    "\"code\": ",
+    "error ",
+    "error: ",
+];
+
+fn has_transient_status(lower: &str) -> bool {
+    TRANSIENT_STATUS_CODES.iter().any(|code| {
+        STATUS_PREFIXES
+            .iter()
+            .any(|prefix| contains_token(lower, &format!("{}{}", prefix, code)))
+            || contains_token(lower, &format!("({})", code))
+            || contains_token(lower, &format!("[{}]", code))
+    })
+}
+
+fn contains_token(haystack: &str, token: &str) -> bool {
+    haystack.match_indices(token).any(|(start, _)| {
+        let end = start + token.len();
+        let before = haystack[..start].chars().next_back();
+        let after = haystack[end..].chars().next();
+        !before.is_some_and(|c| c.is_ascii_alphanumeric())
+            && !after.is_some_and(|c| c.is_ascii_digit())
+    })
 }
 
+pub fn is_transient_output(error_output: &str) -> bool {
+    let lower = error_output.to_lowercase();
+    TRANSIENT_ERROR_PATTERNS
+        .iter()
+        .any(|pattern| lower.contains(pattern))
+        || has_transient_status(&lower)
+}
+
+const MAX_ERROR_CHARS: usize = 200;
+
 pub fn truncate_error(error_output: &str) -> String {
-    if error_output.len() > 200 {
-        format!("{}...", &error_output[..200])
-    } else {
-        error_output.to_string()
+    match error_output.char_indices().nth(MAX_ERROR_CHARS) {
+        Some((end, _)) => format!("{}...", &error_output[..end]),
+        None => error_output.to_string(),
     }
 }
 
@@ -24,7 +83,20 @@ pub fn parse_provider_error(provider: Option<&AiProvider>, error_output: &str) -
         Some(AiProvider::Claude) => ClaudeProvider.parse_error(error_output),
         Some(AiProvider::Gemini) => GeminiProvider.parse_error(error_output),
         Some(AiProvider::Custom) => CustomProvider.parse_error(error_output),
-        None => truncate_error(error_output),
+        Some(AiProvider::Openai) | Some(AiProvider::Anthropic) | None => {
+            truncate_error(error_output)
+        }
+    }
+}
+
+pub fn is_transient_provider_error(provider: Option<&AiProvider>, error_output: &str) -> bool {
+    match provider {
+        Some(AiProvider::Claude) => ClaudeProvider.is_transient_error(error_output),
+        Some(AiProvider::Gemini) => GeminiProvider.is_transient_error(error_output),
+        Some(AiProvider::Custom) => CustomProvider.is_transient_error(error_output),
+        Some(AiProvider::Openai) | Some(AiProvider::Anthropic) | None => {
+            is_transient_output(error_output)
+        }
     }
 }
 
@@ -36,6 +108,10 @@ pub fn resolve_provider_command(
         Some(AiProvider::Claude) => ClaudeProvider.get_command(custom_command),
         Some(AiProvider::Gemini) => GeminiProvider.get_command(custom_command),
         Some(AiProvider::Custom) => CustomProvider.get_command(custom_command),
+        Some(provider @ (AiProvider::Openai | AiProvider::Anthropic)) => Err(format!(
+            "'{}' is an HTTP provider and does not run a command",
+            provider.as_str()
+        )),
         None => Err("AI provider not configured. Add 'ai.provider' to your config.".to_string()),
     }
 }
@@ -66,3 +142,59 @@ pub(crate) fn get_home_dir() -> Option<PathBuf> {
         .ok()
         .map(PathBuf::from)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn transient_output_matches_status_tokens() {
+        for output in [
+            "HTTP 429 Too Many Requests",
+            "request failed with status 503",
+            "API Error: status code 429",
+            "HTTP/1.1 503 Service Unavailable",
+            "{\"error\": {\"code\": 429}}",
+            "upstream returned (503)",
+            "Error: rate limit exceeded",
+            "model is overloaded, try again",
+            "read ECONNRESET",
+        ] {
+            assert!(is_transient_output(output), "{}", output);
+        }
+    }
+
+    #[test]
+    fn transient_output_ignores_incidental_numbers() {
+        for output in [
+            "syntax error at line 4290",
+            "unknown session id 15031",
+            "invalid token 429abc",
+            "status 4291",
+            "expected 503 items but got 12",
+            "Error: invalid API key",
+        ] {
+            assert!(!is_transient_output(output), "{}", output);
+        }
+    }
+
+    #[test]
+    fn truncate_error_keeps_short_output() {
+        assert_eq!(truncate_error("boom"), "boom");
+        let exact = "é".repeat(MAX_ERROR_CHARS);
+        assert_eq!(truncate_error(&exact), exact);
+    }
+
+    #[test]
+    fn truncate_error_cuts_on_char_boundary() {
+        let body = format!("{}{}", "a".repeat(199), "日本語のエラー");
+        let truncated = truncate_error(&body);
+        assert_eq!(truncated, format!("{}日...", "a".repeat(199)));
+
+        let emoji = "🔥".repeat(300);
+        assert_eq!(
+            truncate_error(&emoji).chars().count(),
+            MAX_ERROR_CHARS + "...".len()
+        );
+    }
+}
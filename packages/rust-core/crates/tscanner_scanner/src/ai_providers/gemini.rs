@@ -1,4 +1,6 @@
-use super::common::{get_home_dir, resolve_command_path, truncate_error, AiProviderImpl};
+use super::common::{
+    get_home_dir, is_transient_output, resolve_command_path, truncate_error, AiProviderImpl,
+};
 use std::path::PathBuf;
 use tscanner_constants::{gemini_args, gemini_command};
 
@@ -58,4 +60,9 @@ impl AiProviderImpl for GeminiProvider {
 
         truncate_error(error_output)
     }
+
+    fn is_transient_error(&self, error_output: &str) -> bool {
+        is_transient_output(error_output)
+            || error_output.to_lowercase().contains("resource_exhausted")
+    }
 }
@@ -0,0 +1,200 @@
+use serde_json::{json, Value};
+use std::time::Duration;
+use tscanner_config::{AiConfig, AiProvider};
+use tscanner_constants::ai_default_max_tokens;
+
+use super::common::truncate_error;
+use super::{AnthropicProvider, OpenAiProvider};
+
+#[derive(Debug)]
+pub struct ApiFailure {
+    pub status: Option<u16>,
+    pub message: String,
+    pub retryable: bool,
+    pub retry_after: Option<Duration>,
+}
+
+impl ApiFailure {
+    pub fn new(status: Option<u16>, message: impl Into<String>) -> Self {
+        Self {
+            status,
+            message: message.into(),
+            retryable: status.is_some_and(is_retryable_status),
+            retry_after: None,
+        }
+    }
+}
+
+fn is_retryable_status(status: u16) -> bool {
+    matches!(status, 408 | 429 | 500..=599)
+}
+
+pub struct HttpRequest {
+    pub url: String,
+    pub headers: Vec<(&'static str, String)>,
+    pub body: Value,
+}
+
+pub struct HttpSettings<'a> {
+    pub base_url: &'a str,
+    pub model: &'a str,
+    pub api_key: Option<String>,
+    pub temperature: Option<f64>,
+    pub max_tokens: u64,
+}
+
+pub trait AiHttpProvider {
+    fn default_base_url(&self) -> &'static str;
+    fn default_api_key_env(&self) -> &'static str;
+    fn requires_api_key(&self) -> bool;
+    fn build_request(&self, settings: &HttpSettings, prompt: &str) -> HttpRequest;
+    fn extract_response(&self, body: &Value) -> Option<String>;
+}
+
+fn http_provider(provider: AiProvider) -> Option<&'static dyn AiHttpProvider> {
+    match provider {
+        AiProvider::Openai => Some(&OpenAiProvider),
+        AiProvider::Anthropic => Some(&AnthropicProvider),
+        AiProvider::Claude | AiProvider::Gemini | AiProvider::Custom => None,
+    }
+}
+
+pub fn call_http_provider(
+    provider: AiProvider,
+    ai_config: &AiConfig,
+    prompt: &str,
+    timeout_ms: u64,
+) -> Result<String, ApiFailure> {
+    let Some(http) = http_provider(provider) else {
+        return Err(ApiFailure::new(
+            None,
+            format!("'{}' is not an HTTP provider", provider.as_str()),
+        ));
+    };
+
+    let Some(model) = ai_config.model.as_deref().filter(|m| !m.trim().is_empty()) else {
+        return Err(ApiFailure::new(
+            None,
+            format!(
+                "ai.model is required when ai.provider is '{}'",
+                provider.as_str()
+            ),
+        ));
+    };
+
+    let api_key_env = ai_config
+        .api_key_env
+        .as_deref()
+        .unwrap_or(http.default_api_key_env());
+    let api_key = std::env::var(api_key_env).ok().filter(|k| !k.is_empty());
+    if api_key.is_none() && http.requires_api_key() {
+        return Err(ApiFailure::new(
+            None,
+            format!(
+                "Environment variable {} is not set. Export your API key or set 'ai.apiKeyEnv'.",
+                api_key_env
+            ),
+        ));
+    }
+
+    let settings = HttpSettings {
+        base_url: ai_config
+            .base_url
+            .as_deref()
+            .unwrap_or(http.default_base_url())
+            .trim_end_matches('/'),
+        model,
+        api_key,
+        temperature: ai_config.temperature,
+        max_tokens: ai_config.max_tokens.unwrap_or(ai_default_max_tokens()),
+    };
+    let request = http.build_request(&settings, prompt);
+
+    let mut agent = ureq::AgentBuilder::new();
+    if timeout_ms > 0 {
+        agent = agent.timeout(Duration::from_millis(timeout_ms));
+    }
+
+    let mut call = agent.build().post(&request.url);
+    for (name, value) in &request.headers {
+        call = call.set(name, value);
+    }
+
+    let response = match call.send_json(&request.body) {
+        Ok(response) => response,
+        Err(ureq::Error::Status(status, response)) => {
+            let retry_after = response
+                .header("retry-after")
+                .and_then(|v| v.trim().parse::<u64>().ok())
+                .map(Duration::from_secs);
+            let body = response.into_string().unwrap_or_default();
+            let mut failure =
+                ApiFailure::new(Some(status), describe_status(status, &body, api_key_env));
+            failure.retry_after = retry_after;
+            return Err(failure);
+        }
+        Err(ureq::Error::Transport(transport)) => {
+            let mut failure = ApiFailure::new(
+                None,
+                format!("Request to {} failed: {}", request.url, transport),
+            );
+            failure.retryable = transport.kind() == ureq::ErrorKind::Io;
+            return Err(failure);
+        }
+    };
+
+    let body: Value = response
+        .into_json()
+        .map_err(|e| ApiFailure::new(None, format!("Invalid JSON response: {}", e)))?;
+
+    http.extract_response(&body).ok_or_else(|| {
+        ApiFailure::new(
+            None,
+            format!(
+                "Unexpected response shape: {}",
+                truncate_error(&body.to_string())
+            ),
+        )
+    })
+}
+
+fn describe_status(status: u16, body: &str, api_key_env: &str) -> String {
+    let detail = serde_json::from_str::<Value>(body)
+        .ok()
+        .and_then(|v| {
+            v.pointer("/error/message")
+                .and_then(|m| m.as_str())
+                .map(String::from)
+        })
+        .unwrap_or_else(|| truncate_error(body));
+
+    match status {
+        401 | 403 => format!("API key rejected (check {}): {}", api_key_env, detail),
+        404 => format!("Endpoint or model not found: {}", detail),
+        429 => format!("Rate limit exceeded: {}", detail),
+        529 => format!("Provider overloaded: {}", detail),
+        _ => format!("HTTP {}: {}", status, detail),
+    }
+}
+
+pub fn issues_schema() -> Value {
+    json!({
+        "type": "object",
+        "properties": {
+            "issues": {
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "properties": {
+                        "file": { "type": "string" },
+                        "line": { "type": "integer", "minimum": 1 },
+                        "column": { "type": "integer", "minimum": 1 },
+                        "message": { "type": "string" }
+                    },
+                    "required": ["file", "line", "message"]
+                }
+            }
+        },
+        "required": ["issues"]
+    })
+}
@@ -1,9 +1,17 @@
+mod anthropic;
 mod claude;
 mod common;
 mod custom;
 mod gemini;
+mod http;
+mod openai;
 
+pub use anthropic::AnthropicProvider;
 pub use claude::ClaudeProvider;
-pub use common::{parse_provider_error, resolve_provider_command, AiProviderImpl};
+pub use common::{
+    is_transient_provider_error, parse_provider_error, resolve_provider_command, AiProviderImpl,
+};
 pub use custom::CustomProvider;
 pub use gemini::GeminiProvider;
+pub use http::{call_http_provider, AiHttpProvider, ApiFailure};
+pub use openai::OpenAiProvider;
@@ -0,0 +1,49 @@
+use serde_json::{json, Value};
+use tscanner_constants::{openai_api_key_env, openai_base_url};
+
+use super::http::{AiHttpProvider, HttpRequest, HttpSettings};
+
+pub struct OpenAiProvider;
+
+impl AiHttpProvider for OpenAiProvider {
+    fn default_base_url(&self) -> &'static str {
+        openai_base_url()
+    }
+
+    fn default_api_key_env(&self) -> &'static str {
+        openai_api_key_env()
+    }
+
+    fn requires_api_key(&self) -> bool {
+        false
+    }
+
+    fn build_request(&self, settings: &HttpSettings, prompt: &str) -> HttpRequest {
+        let mut body = json!({
+            "model": settings.model,
+            "messages": [{ "role": "user", "content": prompt }],
+            "max_tokens": settings.max_tokens,
+            "response_format": { "type": "json_object" },
+        });
+        if let Some(temperature) = settings.temperature {
+            body["temperature"] = json!(temperature);
+        }
+
+        let mut headers = Vec::new();
+        if let Some(ref key) = settings.api_key {
+            headers.push(("Authorization", format!("Bearer {}", key)));
+        }
+
+        HttpRequest {
+            url: format!("{}/chat/completions", settings.base_url),
+            headers,
+            body,
+        }
+    }
+
+    fn extract_response(&self, body: &Value) -> Option<String> {
+        body.pointer("/choices/0/message/content")
+            .and_then(|content| content.as_str())
+            .map(String::from)
+    }
+}
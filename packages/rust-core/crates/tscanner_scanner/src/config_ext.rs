@@ -1,7 +1,6 @@
-use std::collections::hash_map::DefaultHasher;
-use std::collections::BTreeMap;
-use std::hash::{Hash, Hasher};
+use serde_json::{json, Value};
 use std::path::Path;
+use tscanner_cache::{rule_fingerprint, CacheLimits, RuleFingerprints};
 
 use tscanner_config::{
     compile_globset, compile_optional_globset, CompiledRuleConfig, TscannerConfig,
@@ -111,7 +110,10 @@ pub trait ConfigExt {
     ) -> Result<CompiledRuleConfig, Box<dyn std::error::Error>>;
     fn count_enabled_rules(&self) -> usize;
     fn count_enabled_rules_breakdown(&self) -> (usize, usize, usize, usize);
-    fn compute_hash(&self) -> u64;
+    fn file_rule_fingerprints(&self) -> RuleFingerprints;
+    fn script_rule_fingerprints(&self) -> RuleFingerprints;
+    fn ai_rule_fingerprints(&self) -> RuleFingerprints;
+    fn cache_limits(&self) -> CacheLimits;
 }
 
 impl ConfigExt for TscannerConfig {
@@ -250,91 +252,145 @@ impl ConfigExt for TscannerConfig {
         (enabled_builtin, enabled_regex, enabled_script, enabled_ai)
     }
 
-    fn compute_hash(&self) -> u64 {
-        let mut hasher = DefaultHasher::new();
+    fn file_rule_fingerprints(&self) -> RuleFingerprints {
+        let files = json!(self.files);
+        let builtin = self.rules.builtin.iter().map(|(name, config)| {
+            let config = json!({ "rule": config, "files": files });
+            (name.clone(), rule_fingerprint("builtin", &config))
+        });
+        let regex = self.rules.regex.iter().map(|(name, config)| {
+            let config = json!({ "rule": config, "files": files });
+            (name.clone(), rule_fingerprint("regex", &config))
+        });
+        builtin.chain(regex).collect()
+    }
 
-        for pattern in &self.files.include {
-            pattern.hash(&mut hasher);
-        }
-        for pattern in &self.files.exclude {
-            pattern.hash(&mut hasher);
-        }
+    fn script_rule_fingerprints(&self) -> RuleFingerprints {
+        self.rules
+            .script
+            .iter()
+            .map(|(name, config)| {
+                let config = without_message(json!(config));
+                (name.clone(), rule_fingerprint("script", &config))
+            })
+            .collect()
+    }
 
-        let sorted_builtin: BTreeMap<_, _> = self.rules.builtin.iter().collect();
-        for (name, config) in sorted_builtin {
-            name.hash(&mut hasher);
-            format!("{:?}", config.severity).hash(&mut hasher);
-            for pattern in &config.include {
-                pattern.hash(&mut hasher);
-            }
-            for pattern in &config.exclude {
-                pattern.hash(&mut hasher);
-            }
-            if !config.options.is_empty() {
-                if let Ok(json) = serde_json::to_string(&config.options) {
-                    json.hash(&mut hasher);
-                }
-            }
-        }
+    fn ai_rule_fingerprints(&self) -> RuleFingerprints {
+        let ai = json!(self.ai);
+        self.ai_rules
+            .iter()
+            .map(|(name, config)| {
+                let config = json!({ "rule": without_message(json!(config)), "ai": ai });
+                (name.clone(), rule_fingerprint("ai", &config))
+            })
+            .collect()
+    }
 
-        let sorted_regex: BTreeMap<_, _> = self.rules.regex.iter().collect();
-        for (name, config) in sorted_regex {
-            name.hash(&mut hasher);
-            config.pattern.hash(&mut hasher);
-            for pattern in &config.include {
-                pattern.hash(&mut hasher);
-            }
-            for pattern in &config.exclude {
-                pattern.hash(&mut hasher);
-            }
-        }
+    fn cache_limits(&self) -> CacheLimits {
+        let cache = self.cache.clone().unwrap_or_default();
+        CacheLimits::new(cache.max_size_mb, cache.max_age_days)
+    }
+}
 
-        let sorted_script: BTreeMap<_, _> = self.rules.script.iter().collect();
-        for (name, config) in sorted_script {
-            name.hash(&mut hasher);
-            config.command.hash(&mut hasher);
-            format!("{:?}", config.severity).hash(&mut hasher);
-            for pattern in &config.include {
-                pattern.hash(&mut hasher);
-            }
-            for pattern in &config.exclude {
-                pattern.hash(&mut hasher);
-            }
-            if !config.options.is_null() {
-                if let Ok(json) = serde_json::to_string(&config.options) {
-                    json.hash(&mut hasher);
+fn without_message(mut config: Value) -> Value {
+    if let Some(object) = config.as_object_mut() {
+        object.remove("message");
+    }
+    config
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(value: Value) -> TscannerConfig {
+        let mut value = value;
+        value["files"] = json!({ "include": ["**/*.ts"], "exclude": [] });
+        serde_json::from_value(value).unwrap()
+    }
+
+    fn changed(before: &RuleFingerprints, after: &RuleFingerprints) -> Vec<String> {
+        before
+            .iter()
+            .filter(|(rule, fingerprint)| after.get(*rule) != Some(fingerprint))
+            .map(|(rule, _)| rule.clone())
+            .collect()
+    }
+
+    #[test]
+    fn option_change_only_changes_that_rule() {
+        let before = config(json!({
+            "rules": { "builtin": { "no-console": {}, "no-var": {} } }
+        }));
+        let after = config(json!({
+            "rules": { "builtin": { "no-console": { "methods": ["log"] }, "no-var": {} } }
+        }));
+
+        assert_eq!(
+            changed(
+                &before.file_rule_fingerprints(),
+                &after.file_rule_fingerprints()
+            ),
+            vec!["no-console"]
+        );
+    }
+
+    #[test]
+    fn message_edit_keeps_script_and_ai_fingerprints() {
+        let rules = |message: &str| {
+            config(json!({
+                "rules": {
+                    "script": { "check": { "command": "node check.js", "message": message } }
+                },
+                "aiRules": {
+                    "review": { "prompt": "review.md", "message": message }
                 }
-            }
-        }
+            }))
+        };
+        let before = rules("Old message");
+        let after = rules("New message");
+
+        assert_eq!(
+            before.script_rule_fingerprints(),
+            after.script_rule_fingerprints()
+        );
+        assert_eq!(before.ai_rule_fingerprints(), after.ai_rule_fingerprints());
+    }
 
-        let sorted_ai: BTreeMap<_, _> = self.ai_rules.iter().collect();
-        for (name, config) in sorted_ai {
-            name.hash(&mut hasher);
-            config.prompt.hash(&mut hasher);
-            format!("{:?}", config.mode).hash(&mut hasher);
-            format!("{:?}", config.severity).hash(&mut hasher);
-            for pattern in &config.include {
-                pattern.hash(&mut hasher);
-            }
-            for pattern in &config.exclude {
-                pattern.hash(&mut hasher);
-            }
-            if !config.options.is_null() {
-                if let Ok(json) = serde_json::to_string(&config.options) {
-                    json.hash(&mut hasher);
+    #[test]
+    fn regex_message_is_part_of_its_fingerprint() {
+        let rules = |message: &str| {
+            config(json!({
+                "rules": {
+                    "regex": {
+                        "no-todo": { "pattern": "TODO", "message": message },
+                        "no-fixme": { "pattern": "FIXME", "message": "Fix it" }
+                    }
                 }
-            }
-        }
+            }))
+        };
 
-        if let Some(ref ai_config) = self.ai {
-            if let Some(provider) = ai_config.provider {
-                format!("{:?}", provider).hash(&mut hasher);
-            }
-            if let Some(ref command) = ai_config.command {
-                command.hash(&mut hasher);
-            }
-        }
+        assert_eq!(
+            changed(
+                &rules("Old").file_rule_fingerprints(),
+                &rules("New").file_rule_fingerprints()
+            ),
+            vec!["no-todo"]
+        );
+    }
 
-        hasher.finish()
+    #[test]
+    fn fingerprint_depends_on_rule_kind() {
+        let config = json!({ "message": "same" });
+
+        assert_ne!(
+            rule_fingerprint("script", &config),
+            rule_fingerprint("ai", &config)
+        );
+        assert_eq!(
+            rule_fingerprint("ai", &config),
+            rule_fingerprint("ai", &config)
+        );
     }
 }
@@ -0,0 +1,170 @@
+use std::path::{Path, PathBuf};
+use tscanner_config::{AiMode, AiRuleConfig};
+use tscanner_constants::ai_chars_per_token;
+
+pub type AiBatch<'a> = Vec<&'a (PathBuf, String)>;
+
+const FILE_ENTRY_OVERHEAD_TOKENS: usize = 16;
+
+pub fn estimate_tokens(text: &str) -> usize {
+    text.len().div_ceil(ai_chars_per_token().max(1))
+}
+
+fn estimate_file_tokens(path: &Path, content: &str, workspace_root: &Path, mode: &AiMode) -> usize {
+    let relative = path.strip_prefix(workspace_root).unwrap_or(path);
+    let path_tokens = estimate_tokens(&relative.to_string_lossy()) + FILE_ENTRY_OVERHEAD_TOKENS;
+    match mode {
+        AiMode::Content => path_tokens + estimate_tokens(content),
+        AiMode::Paths | AiMode::Agentic => path_tokens,
+    }
+}
+
+pub fn plan_batches<'a>(
+    files: &[&'a (PathBuf, String)],
+    workspace_root: &Path,
+    rule_config: &AiRuleConfig,
+    base_prompt_tokens: usize,
+) -> Vec<AiBatch<'a>> {
+    let mut sorted: Vec<&'a (PathBuf, String)> = files.to_vec();
+    sorted.sort_by(|(a, _), (b, _)| a.parent().cmp(&b.parent()).then_with(|| a.cmp(b)));
+
+    let max_files = match rule_config.batch_size {
+        0 => usize::MAX,
+        size => size as usize,
+    };
+    let token_budget = match rule_config.max_prompt_tokens {
+        0 => usize::MAX,
+        tokens => (tokens as usize).saturating_sub(base_prompt_tokens),
+    };
+
+    let mut batches: Vec<AiBatch<'a>> = Vec::new();
+    let mut current: AiBatch<'a> = Vec::new();
+    let mut current_tokens = 0;
+
+    for file in sorted {
+        let (path, content) = file;
+        let tokens = estimate_file_tokens(path, content, workspace_root, &rule_config.mode);
+        let full = current.len() >= max_files || current_tokens + tokens > token_budget;
+        if full && !current.is_empty() {
+            batches.push(std::mem::take(&mut current));
+            current_tokens = 0;
+        }
+        current.push(file);
+        current_tokens += tokens;
+    }
+
+    if !current.is_empty() {
+        batches.push(current);
+    }
+
+    batches
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    const ROOT: &str = "/repo";
+
+    fn rule(batch_size: u64, max_prompt_tokens: u64) -> AiRuleConfig {
+        serde_json::from_value(json!({
+            "prompt": "rule.md",
+            "message": "Review",
+            "mode": "content",
+            "batchSize": batch_size,
+            "maxPromptTokens": max_prompt_tokens
+        }))
+        .unwrap()
+    }
+
+    fn files(entries: &[(&str, usize)]) -> Vec<(PathBuf, String)> {
+        entries
+            .iter()
+            .map(|(path, len)| (Path::new(ROOT).join(path), "x".repeat(*len)))
+            .collect()
+    }
+
+    fn plan(files: &[(PathBuf, String)], rule: &AiRuleConfig, base: usize) -> Vec<Vec<String>> {
+        let refs: Vec<_> = files.iter().collect();
+        plan_batches(&refs, Path::new(ROOT), rule, base)
+            .into_iter()
+            .map(|batch| {
+                batch
+                    .iter()
+                    .map(|(path, _)| {
+                        path.strip_prefix(ROOT)
+                            .unwrap()
+                            .to_string_lossy()
+                            .to_string()
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn zero_limits_put_everything_in_one_batch() {
+        let files = files(&[("a.ts", 40_000), ("b.ts", 40_000), ("c.ts", 40_000)]);
+
+        assert_eq!(plan(&files, &rule(0, 0), 0).len(), 1);
+    }
+
+    #[test]
+    fn batch_size_limits_files_per_batch() {
+        let files = files(&[("a.ts", 10), ("b.ts", 10), ("c.ts", 10)]);
+
+        assert_eq!(
+            plan(&files, &rule(2, 0), 0),
+            vec![vec!["a.ts", "b.ts"], vec!["c.ts"]]
+        );
+    }
+
+    #[test]
+    fn token_budget_excludes_base_prompt() {
+        // Each file costs 17 path tokens plus 25 content tokens.
+        let files = files(&[("a.ts", 100), ("b.ts", 100), ("c.ts", 100)]);
+
+        assert_eq!(plan(&files, &rule(0, 86), 0).len(), 2);
+        assert_eq!(plan(&files, &rule(0, 86 + 50), 50).len(), 2);
+        assert_eq!(plan(&files, &rule(0, 86), 50).len(), 3);
+        assert_eq!(plan(&files, &rule(0, 129), 0).len(), 1);
+    }
+
+    #[test]
+    fn files_are_grouped_by_directory() {
+        let files = files(&[
+            ("src/ui/b.ts", 10),
+            ("src/api/b.ts", 10),
+            ("src/ui/a.ts", 10),
+            ("src/api/a.ts", 10),
+        ]);
+
+        assert_eq!(
+            plan(&files, &rule(2, 0), 0),
+            vec![
+                vec!["src/api/a.ts", "src/api/b.ts"],
+                vec!["src/ui/a.ts", "src/ui/b.ts"],
+            ]
+        );
+    }
+
+    #[test]
+    fn oversized_file_gets_its_own_batch() {
+        let files = files(&[("a.ts", 10), ("big.ts", 10_000), ("c.ts", 10)]);
+
+        assert_eq!(
+            plan(&files, &rule(0, 200), 0),
+            vec![vec!["a.ts"], vec!["big.ts"], vec!["c.ts"]]
+        );
+    }
+
+    #[test]
+    fn paths_mode_ignores_content_size() {
+        let mut paths_rule = rule(0, 100);
+        paths_rule.mode = AiMode::Paths;
+        let files = files(&[("a.ts", 10_000), ("b.ts", 10_000)]);
+
+        assert_eq!(plan(&files, &paths_rule, 0).len(), 1);
+    }
+}
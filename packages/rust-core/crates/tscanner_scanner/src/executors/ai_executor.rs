@@ -1,4 +1,8 @@
-use crate::ai_providers::{parse_provider_error, resolve_provider_command};
+use super::ai_batches::{estimate_tokens, plan_batches, AiBatch};
+use super::ai_limits::{backoff_delay, AiRequestLimiter};
+use crate::ai_providers::{
+    call_http_provider, is_transient_provider_error, parse_provider_error, resolve_provider_command,
+};
 use dashmap::DashMap;
 use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
@@ -12,8 +16,8 @@ use std::time::{Duration, Instant};
 use tscanner_cache::AiCache;
 use tscanner_config::{AiConfig, AiMode, AiRuleConfig};
 use tscanner_constants::{
-    ai_placeholder_content, ai_placeholder_files, ai_placeholder_options, ai_rules_dir,
-    ai_temp_dir, config_dir_name,
+    ai_placeholder_content, ai_placeholder_files, ai_placeholder_options, ai_repair_max_attempts,
+    ai_repair_max_errors, ai_repair_max_response_chars, ai_rules_dir, ai_temp_dir, config_dir_name,
 };
 use tscanner_types::{Issue, IssueRuleType};
 
@@ -23,9 +27,20 @@ pub type ChangedLinesMap = HashMap<PathBuf, HashSet<usize>>;
 #[serde(rename_all = "snake_case")]
 pub enum AiRuleStatus {
     Pending {},
-    Running {},
-    Completed { issues_found: usize },
-    Failed { error: String },
+    Running {
+        completed_batches: usize,
+        total_batches: usize,
+    },
+    Retrying {
+        attempt: usize,
+        max_retries: usize,
+    },
+    Completed {
+        issues_found: usize,
+    },
+    Failed {
+        error: String,
+    },
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -41,11 +56,7 @@ pub type RegularRulesCompleteCallback = Arc<dyn Fn(u128) + Send + Sync>;
 
 const AI_RULE_WRAPPER: &str =
     include_str!("../../../../../../assets/prompts/ai-rule-wrapper.prompt.md");
-
-#[derive(Debug, Deserialize)]
-pub struct AiResponse {
-    pub issues: Vec<AiIssue>,
-}
+const AI_REPAIR_PROMPT: &str = include_str!("../../../../../../assets/prompts/ai-repair.prompt.md");
 
 #[derive(Debug, Deserialize)]
 pub struct AiIssue {
@@ -60,7 +71,18 @@ pub struct AiIssue {
 pub enum AiError {
     IoError(std::io::Error),
     Timeout(u64),
-    NonZeroExit { code: Option<i32>, stderr: String },
+    NonZeroExit {
+        code: Option<i32>,
+        stderr: String,
+    },
+    ApiError {
+        status: Option<u16>,
+        message: String,
+    },
+    Transient {
+        error: Box<AiError>,
+        retry_after: Option<Duration>,
+    },
     InvalidOutput(String),
     ProviderNotFound(String),
     PromptNotFound(PathBuf),
@@ -74,6 +96,11 @@ impl std::fmt::Display for AiError {
             AiError::NonZeroExit { code, stderr } => {
                 write!(f, "AI command exited with code {:?}: {}", code, stderr)
             }
+            AiError::ApiError { status, message } => match status {
+                Some(status) => write!(f, "AI API returned {}: {}", status, message),
+                None => write!(f, "AI API request failed: {}", message),
+            },
+            AiError::Transient { error, .. } => write!(f, "{}", error),
             AiError::InvalidOutput(msg) => write!(f, "Invalid AI output: {}", msg),
             AiError::ProviderNotFound(cmd) => {
                 write!(
@@ -99,6 +126,33 @@ pub struct AiExecutionResult {
     pub warnings: Vec<String>,
     pub errors: Vec<String>,
     pub cache_hits: usize,
+    pub invalid_issues: HashMap<String, usize>,
+}
+
+#[derive(Default)]
+struct AiRuleRun {
+    issues: Vec<Issue>,
+    errors: Vec<String>,
+    cached_files: usize,
+    invalid_issues: usize,
+}
+
+#[derive(Default)]
+struct AiBatchIssues {
+    issues: Vec<Issue>,
+    invalid_issues: usize,
+}
+
+struct AiBatchOutcome {
+    index: usize,
+    file_count: usize,
+    result: Result<AiBatchIssues, AiError>,
+    was_cache_hit: bool,
+}
+
+struct ParsedResponse {
+    issues: Vec<Issue>,
+    problems: Vec<String>,
 }
 
 pub struct AiExecutor {
@@ -107,6 +161,7 @@ pub struct AiExecutor {
     ai_config: Option<AiConfig>,
     cache: Arc<AiCache>,
     in_flight: DashMap<String, Arc<AtomicBool>>,
+    limiter: AiRequestLimiter,
     log_warn: fn(&str),
     log_debug: fn(&str),
 }
@@ -126,6 +181,7 @@ impl AiExecutor {
         Self {
             workspace_root: workspace_root.to_path_buf(),
             ai_rules_dir: ai_rules_dir_path,
+            limiter: AiRequestLimiter::new(ai_config.as_ref()),
             ai_config,
             cache,
             in_flight: DashMap::new(),
@@ -222,6 +278,8 @@ impl AiExecutor {
         let cache_hits = Arc::new(AtomicUsize::new(0));
         let errors: Arc<std::sync::Mutex<Vec<String>>> =
             Arc::new(std::sync::Mutex::new(Vec::new()));
+        let invalid_issues: Arc<std::sync::Mutex<HashMap<String, usize>>> =
+            Arc::new(std::sync::Mutex::new(HashMap::new()));
 
         if let Some(ref cb) = progress_callback {
             for (idx, (rule_name, _)) in rules.iter().enumerate() {
@@ -236,19 +294,11 @@ impl AiExecutor {
 
         let cache_hits_ref = cache_hits.clone();
         let errors_ref = errors.clone();
+        let invalid_issues_ref = invalid_issues.clone();
         let all_issues: Vec<Issue> = rules
             .par_iter()
             .enumerate()
             .flat_map(|(idx, (rule_name, rule_config))| {
-                if let Some(ref cb) = progress_callback {
-                    cb(AiProgressEvent {
-                        rule_name: rule_name.clone(),
-                        rule_index: idx,
-                        total_rules,
-                        status: AiRuleStatus::Running {},
-                    });
-                }
-
                 let matching_files: Vec<_> = files
                     .iter()
                     .filter(|(path, _)| self.file_matches_rule(path, workspace_root, rule_config))
@@ -267,34 +317,53 @@ impl AiExecutor {
                     return vec![];
                 }
 
-                let (result, was_cache_hit) = self.execute_rule(
+                let on_status = |status: AiRuleStatus| {
+                    if let Some(ref cb) = progress_callback {
+                        cb(AiProgressEvent {
+                            rule_name: rule_name.clone(),
+                            rule_index: idx,
+                            total_rules,
+                            status,
+                        });
+                    }
+                };
+
+                let result = self.execute_rule(
                     rule_name,
                     rule_config,
                     &matching_files,
                     workspace_root,
                     ai_config,
                     changed_lines,
+                    &on_status,
                 );
 
-                if was_cache_hit {
-                    cache_hits_ref.fetch_add(matching_files.len(), Ordering::SeqCst);
-                }
-
                 completed_count.fetch_add(1, Ordering::SeqCst);
 
                 match result {
-                    Ok(issues) => {
+                    Ok(run) => {
+                        cache_hits_ref.fetch_add(run.cached_files, Ordering::SeqCst);
+                        if run.invalid_issues > 0 {
+                            if let Ok(mut counts) = invalid_issues_ref.lock() {
+                                counts.insert(rule_name.clone(), run.invalid_issues);
+                            }
+                        }
+                        if !run.errors.is_empty() {
+                            if let Ok(mut errs) = errors_ref.lock() {
+                                errs.extend(run.errors);
+                            }
+                        }
                         if let Some(ref cb) = progress_callback {
                             cb(AiProgressEvent {
                                 rule_name: rule_name.clone(),
                                 rule_index: idx,
                                 total_rules,
                                 status: AiRuleStatus::Completed {
-                                    issues_found: issues.len(),
+                                    issues_found: run.issues.len(),
                                 },
                             });
                         }
-                        issues
+                        run.issues
                     }
                     Err(e) => {
                         let error_msg = format!("AI rule '{}' failed: {}", rule_name, e);
@@ -319,11 +388,26 @@ impl AiExecutor {
             .collect();
 
         let final_errors = errors.lock().map(|e| e.clone()).unwrap_or_default();
+        let invalid_issues = invalid_issues.lock().map(|c| c.clone()).unwrap_or_default();
+
+        let mut invalid_rules: Vec<_> = invalid_issues.iter().collect();
+        invalid_rules.sort();
+        let warnings = invalid_rules
+            .into_iter()
+            .map(|(rule_name, count)| {
+                format!(
+                    "AI rule '{}' dropped {} invalid issues after repair attempts",
+                    rule_name, count
+                )
+            })
+            .collect();
+
         AiExecutionResult {
             issues: all_issues,
-            warnings: vec![],
+            warnings,
             errors: final_errors,
             cache_hits: cache_hits.load(Ordering::SeqCst),
+            invalid_issues,
         }
     }
 
@@ -341,6 +425,7 @@ impl AiExecutor {
         )
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn execute_rule(
         &self,
         rule_name: &str,
@@ -349,71 +434,248 @@ impl AiExecutor {
         workspace_root: &Path,
         ai_config: &AiConfig,
         changed_lines: Option<&ChangedLinesMap>,
-    ) -> (Result<Vec<Issue>, AiError>, bool) {
+        on_status: &(dyn Fn(AiRuleStatus) + Sync),
+    ) -> Result<AiRuleRun, AiError> {
         let prompt_path = self.ai_rules_dir.join(&rule_config.prompt);
         if !prompt_path.exists() {
-            return (Err(AiError::PromptNotFound(prompt_path)), false);
+            return Err(AiError::PromptNotFound(prompt_path));
         }
-
-        let files_owned: Vec<(PathBuf, String)> =
-            files.iter().map(|(p, c)| (p.clone(), c.clone())).collect();
+        let prompt_content = std::fs::read_to_string(&prompt_path)?;
 
         if let Some(in_flight_flag) = self.in_flight.get(rule_name) {
             in_flight_flag.store(true, Ordering::SeqCst);
             std::thread::sleep(Duration::from_millis(100));
         }
 
-        if let Some(cached_issues) = self.cache.get(rule_name, &prompt_path, &files_owned) {
+        let per_file = rule_config.mode != AiMode::Agentic;
+        let mut run = AiRuleRun::default();
+        let pending: Vec<&(PathBuf, String)> = if per_file {
+            let (cached_issues, pending) =
+                self.collect_cached_files(rule_name, &prompt_path, files, workspace_root);
+            run.cached_files = files.len() - pending.len();
+            run.issues = cached_issues;
+            pending
+        } else {
+            files.to_vec()
+        };
+
+        let base_prompt_tokens =
+            estimate_tokens(AI_RULE_WRAPPER) + estimate_tokens(&prompt_content);
+        let batches = plan_batches(&pending, workspace_root, rule_config, base_prompt_tokens);
+        let total_batches = batches.len();
+        on_status(AiRuleStatus::Running {
+            completed_batches: 0,
+            total_batches,
+        });
+
+        let cancelled = Arc::new(AtomicBool::new(false));
+        self.in_flight
+            .insert(rule_name.to_string(), cancelled.clone());
+
+        let completed_batches = AtomicUsize::new(0);
+        let outcomes: Vec<AiBatchOutcome> = batches
+            .par_iter()
+            .enumerate()
+            .map(|(index, batch)| {
+                let (result, was_cache_hit) = self.execute_batch(
+                    rule_name,
+                    rule_config,
+                    &prompt_path,
+                    &prompt_content,
+                    (index, total_batches),
+                    batch,
+                    per_file,
+                    workspace_root,
+                    ai_config,
+                    changed_lines,
+                    &cancelled,
+                    on_status,
+                );
+                on_status(AiRuleStatus::Running {
+                    completed_batches: completed_batches.fetch_add(1, Ordering::SeqCst) + 1,
+                    total_batches,
+                });
+                AiBatchOutcome {
+                    index,
+                    file_count: batch.len(),
+                    result,
+                    was_cache_hit,
+                }
+            })
+            .collect();
+
+        self.in_flight.remove(rule_name);
+
+        if cancelled.load(Ordering::SeqCst) {
+            (self.log_debug)(&format!("AI rule '{}' was cancelled", rule_name));
+            return Ok(AiRuleRun::default());
+        }
+
+        let mut failures = Vec::new();
+        for outcome in outcomes {
+            if outcome.was_cache_hit {
+                run.cached_files += outcome.file_count;
+            }
+            match outcome.result {
+                Ok(batch) => {
+                    run.issues.extend(batch.issues);
+                    run.invalid_issues += batch.invalid_issues;
+                }
+                Err(e) => failures.push((outcome.index, e)),
+            }
+        }
+
+        if !failures.is_empty() && failures.len() == total_batches {
+            let (_, e) = failures.remove(0);
+            return Err(e);
+        }
+
+        for (index, e) in failures {
+            let error_msg = format!(
+                "AI rule '{}' batch {}/{} failed: {}",
+                rule_name,
+                index + 1,
+                total_batches,
+                e
+            );
+            (self.log_warn)(&error_msg);
+            run.errors.push(error_msg);
+        }
+
+        Ok(run)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn execute_batch(
+        &self,
+        rule_name: &str,
+        rule_config: &AiRuleConfig,
+        prompt_path: &Path,
+        prompt_content: &str,
+        (index, total_batches): (usize, usize),
+        batch: &AiBatch,
+        per_file: bool,
+        workspace_root: &Path,
+        ai_config: &AiConfig,
+        changed_lines: Option<&ChangedLinesMap>,
+        cancelled: &Arc<AtomicBool>,
+        on_status: &(dyn Fn(AiRuleStatus) + Sync),
+    ) -> (Result<AiBatchIssues, AiError>, bool) {
+        let batch_owned: Vec<(PathBuf, String)> =
+            batch.iter().map(|(p, c)| (p.clone(), c.clone())).collect();
+
+        let cached = if per_file {
+            None
+        } else {
+            self.cache.get(rule_name, prompt_path, &batch_owned)
+        };
+
+        if let Some(cached_issues) = cached {
             (self.log_warn)(&format!(
-                "AI rule '{}' cache hit ({} cached issues)",
+                "AI rule '{}' batch {}/{} cache hit ({} cached issues)",
                 rule_name,
+                index + 1,
+                total_batches,
                 cached_issues.len()
             ));
+            let issues = self.validate_cached_issues(&cached_issues, batch, workspace_root);
             return (
-                Ok(self.validate_cached_issues(&cached_issues, files, workspace_root)),
+                Ok(AiBatchIssues {
+                    issues,
+                    invalid_issues: 0,
+                }),
                 true,
             );
         }
 
-        let prompt_content = match std::fs::read_to_string(&prompt_path) {
-            Ok(content) => content,
-            Err(e) => return (Err(AiError::IoError(e)), false),
+        let prompt_name = if total_batches > 1 {
+            format!("{}-batch-{}", rule_name, index + 1)
+        } else {
+            rule_name.to_string()
         };
 
-        let cancelled = Arc::new(AtomicBool::new(false));
-        self.in_flight
-            .insert(rule_name.to_string(), cancelled.clone());
-
         let result = self.call_ai_and_parse(
             rule_name,
+            &prompt_name,
             rule_config,
-            &prompt_content,
-            files,
+            prompt_content,
+            batch,
             workspace_root,
             ai_config,
             changed_lines,
-            &cancelled,
+            cancelled,
+            on_status,
         );
 
-        self.in_flight.remove(rule_name);
-
         if cancelled.load(Ordering::SeqCst) {
-            (self.log_debug)(&format!("AI rule '{}' was cancelled", rule_name));
-            return (Ok(vec![]), false);
+            return (Ok(AiBatchIssues::default()), false);
         }
 
-        if let Ok(ref issues) = result {
-            self.cache
-                .insert(rule_name, &prompt_path, &files_owned, issues.clone());
+        match result {
+            Ok(ref batch) if per_file => {
+                for file in batch_owned {
+                    let file_issues: Vec<Issue> = batch
+                        .issues
+                        .iter()
+                        .filter(|issue| issue.file == file.0)
+                        .cloned()
+                        .collect();
+                    self.cache
+                        .insert(rule_name, prompt_path, &[file], file_issues);
+                }
+            }
+            Ok(ref batch) => {
+                self.cache
+                    .insert(rule_name, prompt_path, &batch_owned, batch.issues.clone());
+            }
+            Err(_) => {}
         }
 
         (result, false)
     }
 
+    fn collect_cached_files<'a>(
+        &self,
+        rule_name: &str,
+        prompt_path: &Path,
+        files: &[&'a (PathBuf, String)],
+        workspace_root: &Path,
+    ) -> (Vec<Issue>, Vec<&'a (PathBuf, String)>) {
+        let mut issues = Vec::new();
+        let mut pending = Vec::new();
+
+        for file in files {
+            match self
+                .cache
+                .get(rule_name, prompt_path, std::slice::from_ref(*file))
+            {
+                Some(cached_issues) => issues.extend(self.validate_cached_issues(
+                    &cached_issues,
+                    &[*file],
+                    workspace_root,
+                )),
+                None => pending.push(*file),
+            }
+        }
+
+        if pending.len() < files.len() {
+            (self.log_warn)(&format!(
+                "AI rule '{}' cache hit for {}/{} files ({} cached issues)",
+                rule_name,
+                files.len() - pending.len(),
+                files.len(),
+                issues.len()
+            ));
+        }
+
+        (issues, pending)
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn call_ai_and_parse(
         &self,
         rule_name: &str,
+        prompt_name: &str,
         rule_config: &AiRuleConfig,
         prompt_content: &str,
         files: &[&(PathBuf, String)],
@@ -421,7 +683,8 @@ impl AiExecutor {
         ai_config: &AiConfig,
         changed_lines: Option<&ChangedLinesMap>,
         cancelled: &Arc<AtomicBool>,
-    ) -> Result<Vec<Issue>, AiError> {
+        on_status: &(dyn Fn(AiRuleStatus) + Sync),
+    ) -> Result<AiBatchIssues, AiError> {
         let files_section =
             self.format_files_section(files, workspace_root, &rule_config.mode, changed_lines);
         let options_section = if rule_config.options.is_null() {
@@ -437,51 +700,246 @@ impl AiExecutor {
             .replace(ai_placeholder_options(), &options_section);
         let full_prompt = AI_RULE_WRAPPER.replace(ai_placeholder_content(), &rule_prompt);
 
-        self.save_prompt_to_tmp(rule_name, &full_prompt);
+        self.save_prompt_to_tmp(prompt_name, &full_prompt);
+
+        let mut prompt = full_prompt.clone();
+        let mut attempt = 0;
+        loop {
+            let response = self.request_with_retry(
+                prompt_name,
+                &prompt,
+                rule_config,
+                files.len(),
+                ai_config,
+                cancelled,
+                on_status,
+            )?;
+
+            if cancelled.load(Ordering::SeqCst) {
+                return Ok(AiBatchIssues::default());
+            }
+
+            let parsed =
+                self.parse_response(rule_name, rule_config, &response, workspace_root, files);
+            let problems = match &parsed {
+                Ok(parsed) => parsed.problems.clone(),
+                Err(e) => vec![e.to_string()],
+            };
+
+            if problems.is_empty() || attempt >= ai_repair_max_attempts() {
+                let parsed = parsed?;
+                if !parsed.problems.is_empty() {
+                    (self.log_warn)(&format!(
+                        "AI rule '{}': dropping {} invalid issues",
+                        prompt_name,
+                        parsed.problems.len()
+                    ));
+                }
+                return Ok(AiBatchIssues {
+                    issues: parsed.issues,
+                    invalid_issues: parsed.problems.len(),
+                });
+            }
+
+            attempt += 1;
+            (self.log_warn)(&format!(
+                "AI rule '{}': response failed validation ({} problems), requesting repair {}/{}",
+                prompt_name,
+                problems.len(),
+                attempt,
+                ai_repair_max_attempts()
+            ));
+            prompt =
+                self.build_repair_prompt(&full_prompt, &response, &problems, files, workspace_root);
+            self.save_prompt_to_tmp(&format!("{}-repair-{}", prompt_name, attempt), &prompt);
+        }
+    }
 
+    #[allow(clippy::too_many_arguments)]
+    fn request_with_retry(
+        &self,
+        prompt_name: &str,
+        prompt: &str,
+        rule_config: &AiRuleConfig,
+        file_count: usize,
+        ai_config: &AiConfig,
+        cancelled: &Arc<AtomicBool>,
+        on_status: &(dyn Fn(AiRuleStatus) + Sync),
+    ) -> Result<String, AiError> {
+        let max_retries = self.limiter.max_retries();
+        let mut attempt = 0;
+        loop {
+            let result = {
+                let _permit = self.limiter.acquire();
+                self.request_ai(
+                    prompt_name,
+                    prompt,
+                    rule_config,
+                    file_count,
+                    ai_config,
+                    cancelled,
+                )
+            };
+
+            let (error, retry_after) = match result {
+                Ok(response) => return Ok(response),
+                Err(AiError::Transient { error, retry_after })
+                    if attempt < max_retries && !cancelled.load(Ordering::SeqCst) =>
+                {
+                    (error, retry_after)
+                }
+                Err(AiError::Transient { error, .. }) => return Err(*error),
+                Err(e) => return Err(e),
+            };
+
+            attempt += 1;
+            let delay = backoff_delay(attempt, retry_after);
+            (self.log_warn)(&format!(
+                "AI rule '{}': {} - retrying {}/{} in {}ms",
+                prompt_name,
+                error,
+                attempt,
+                max_retries,
+                delay.as_millis()
+            ));
+            on_status(AiRuleStatus::Retrying {
+                attempt,
+                max_retries,
+            });
+
+            let deadline = Instant::now() + delay;
+            while Instant::now() < deadline {
+                if cancelled.load(Ordering::SeqCst) {
+                    return Err(*error);
+                }
+                std::thread::sleep(
+                    deadline
+                        .saturating_duration_since(Instant::now())
+                        .min(Duration::from_millis(100)),
+                );
+            }
+        }
+    }
+
+    fn request_ai(
+        &self,
+        prompt_name: &str,
+        prompt: &str,
+        rule_config: &AiRuleConfig,
+        file_count: usize,
+        ai_config: &AiConfig,
+        cancelled: &Arc<AtomicBool>,
+    ) -> Result<String, AiError> {
         let timeout_secs = rule_config.timeout;
         let timeout_ms = if timeout_secs > 0 {
             timeout_secs * 1000
         } else {
             0
         };
-        let (program, args) =
-            resolve_provider_command(ai_config.provider.as_ref(), ai_config.command.as_deref())
+        let mode_str = rule_config.mode.as_str();
+
+        match ai_config.provider {
+            Some(provider) if provider.is_http() => {
+                (self.log_warn)(&format!(
+                    "AI rule '{}': calling {} API with {} files, mode={} (timeout: {}s)",
+                    prompt_name,
+                    provider.as_str(),
+                    file_count,
+                    mode_str,
+                    timeout_secs
+                ));
+                call_http_provider(provider, ai_config, prompt, timeout_ms).map_err(|failure| {
+                    let error = AiError::ApiError {
+                        status: failure.status,
+                        message: failure.message,
+                    };
+                    if failure.retryable {
+                        AiError::Transient {
+                            error: Box::new(error),
+                            retry_after: failure.retry_after,
+                        }
+                    } else {
+                        error
+                    }
+                })
+            }
+            _ => {
+                let (program, args) = resolve_provider_command(
+                    ai_config.provider.as_ref(),
+                    ai_config.command.as_deref(),
+                )
                 .map_err(AiError::InvalidOutput)?;
 
-        let mode_str = match rule_config.mode {
-            AiMode::Paths => "paths",
-            AiMode::Content => "content",
-            AiMode::Agentic => "agentic",
-        };
+                (self.log_warn)(&format!(
+                    "AI rule '{}': calling {} with {} files, mode={} (timeout: {}s)",
+                    prompt_name, program, file_count, mode_str, timeout_secs
+                ));
 
-        (self.log_warn)(&format!(
-            "AI rule '{}': calling {} with {} files, mode={} (timeout: {}s)",
-            rule_name,
-            program,
-            files.len(),
-            mode_str,
-            timeout_secs
-        ));
+                self.spawn_ai_command(&program, &args, prompt, timeout_ms, cancelled)
+                    .map_err(|e| match e {
+                        AiError::NonZeroExit { code, stderr } => {
+                            let provider = ai_config.provider.as_ref();
+                            let error = AiError::NonZeroExit {
+                                code,
+                                stderr: parse_provider_error(provider, &stderr),
+                            };
+                            if is_transient_provider_error(provider, &stderr) {
+                                AiError::Transient {
+                                    error: Box::new(error),
+                                    retry_after: None,
+                                }
+                            } else {
+                                error
+                            }
+                        }
+                        other => other,
+                    })
+            }
+        }
+    }
 
-        let response = self
-            .spawn_ai_command(&program, &args, &full_prompt, timeout_ms, cancelled)
-            .map_err(|e| match e {
-                AiError::NonZeroExit { code, stderr } => {
-                    let friendly = parse_provider_error(ai_config.provider.as_ref(), &stderr);
-                    AiError::NonZeroExit {
-                        code,
-                        stderr: friendly,
-                    }
-                }
-                other => other,
-            })?;
+    fn build_repair_prompt(
+        &self,
+        full_prompt: &str,
+        response: &str,
+        problems: &[String],
+        files: &[&(PathBuf, String)],
+        workspace_root: &Path,
+    ) -> String {
+        let max_errors = ai_repair_max_errors();
+        let mut details = String::from("### Validation errors\n\n");
+        for problem in problems.iter().take(max_errors) {
+            details.push_str(&format!("- {}\n", problem));
+        }
+        if problems.len() > max_errors {
+            details.push_str(&format!("- ... and {} more\n", problems.len() - max_errors));
+        }
 
-        if cancelled.load(Ordering::SeqCst) {
-            return Ok(vec![]);
+        details.push_str("\n### Valid files\n\n");
+        for (path, content) in files {
+            let relative = path.strip_prefix(workspace_root).unwrap_or(path);
+            details.push_str(&format!(
+                "- {} (lines 1-{})\n",
+                relative.display(),
+                content.lines().count()
+            ));
         }
 
-        self.parse_response(rule_name, rule_config, &response, workspace_root, files)
+        let max_chars = ai_repair_max_response_chars();
+        let mut previous: String = response.chars().take(max_chars).collect();
+        if response.chars().count() > max_chars {
+            previous.push_str("\n... (truncated)");
+        }
+        details.push_str(&format!(
+            "\n### Previous response\n\n```\n{}\n```\n",
+            previous
+        ));
+
+        format!(
+            "{}\n\n---\n\n{}",
+            full_prompt,
+            AI_REPAIR_PROMPT.replace(ai_placeholder_content(), &details)
+        )
     }
 
     fn format_files_section(
@@ -718,7 +1176,7 @@ impl AiExecutor {
         response: &str,
         workspace_root: &Path,
         files: &[&(PathBuf, String)],
-    ) -> Result<Vec<Issue>, AiError> {
+    ) -> Result<ParsedResponse, AiError> {
         let json_start = response.find('{');
         let json_end = response.rfind('}');
 
@@ -726,18 +1184,24 @@ impl AiExecutor {
             (Some(start), Some(end)) if end >= start => &response[start..=end],
             _ => {
                 if response.trim().is_empty() {
-                    return Ok(vec![]);
+                    return Ok(ParsedResponse {
+                        issues: vec![],
+                        problems: vec![],
+                    });
                 }
                 (self.log_debug)(&format!(
                     "AI rule '{}': no JSON found in response ({}chars)",
                     rule_name,
                     response.len()
                 ));
-                return Ok(vec![]);
+                return Err(AiError::InvalidOutput(format!(
+                    "No JSON object found - Output: {}",
+                    response.chars().take(500).collect::<String>()
+                )));
             }
         };
 
-        let ai_response: AiResponse = serde_json::from_str(json_str).map_err(|e| {
+        let value: serde_json::Value = serde_json::from_str(json_str).map_err(|e| {
             AiError::InvalidOutput(format!(
                 "Failed to parse JSON: {} - Output: {}",
                 e,
@@ -745,10 +1209,16 @@ impl AiExecutor {
             ))
         })?;
 
+        let Some(raw_issues) = value.get("issues").and_then(|i| i.as_array()) else {
+            return Err(AiError::InvalidOutput(
+                "Missing \"issues\" array in JSON response".to_string(),
+            ));
+        };
+
         (self.log_warn)(&format!(
             "AI rule '{}': parsed {} raw issues from response",
             rule_name,
-            ai_response.issues.len()
+            raw_issues.len()
         ));
 
         let file_lines: HashMap<PathBuf, Vec<&str>> = files
@@ -759,59 +1229,68 @@ impl AiExecutor {
             })
             .collect();
 
-        if !ai_response.issues.is_empty() {
-            let known_files: Vec<_> = file_lines.keys().map(|p| p.display().to_string()).collect();
-            (self.log_warn)(&format!(
-                "AI rule '{}': known files ({}): {:?}",
-                rule_name,
-                known_files.len(),
-                known_files.iter().take(5).collect::<Vec<_>>()
-            ));
-        }
+        let mut issues = Vec::new();
+        let mut problems = Vec::new();
 
-        let issues: Vec<_> = ai_response
-            .issues
-            .into_iter()
-            .filter_map(|issue| {
-                let file_path = PathBuf::from(&issue.file);
-
-                if let Some(lines) = file_lines.get(&file_path) {
-                    if issue.line == 0 || issue.line > lines.len() {
-                        (self.log_warn)(&format!(
-                            "AI returned invalid line {} for file {} (max: {})",
-                            issue.line,
-                            issue.file,
-                            lines.len()
-                        ));
-                        return None;
-                    }
-                } else {
-                    (self.log_warn)(&format!(
-                        "AI returned unknown file: {} (not in input files: {:?})",
-                        issue.file,
-                        file_lines.keys().take(3).collect::<Vec<_>>()
-                    ));
-                    return None;
+        for (index, raw_issue) in raw_issues.iter().enumerate() {
+            let issue = match serde_json::from_value::<AiIssue>(raw_issue.clone()) {
+                Ok(issue) => issue,
+                Err(e) => {
+                    problems.push(format!("issues[{}]: {}", index, e));
+                    continue;
                 }
+            };
 
-                let line_text = file_lines
-                    .get(&file_path)
-                    .and_then(|lines| super::utils::extract_line_text(lines, issue.line));
-
-                Some(Issue {
-                    rule: rule_name.to_string(),
-                    file: workspace_root.join(&issue.file),
-                    line: issue.line,
-                    column: issue.column.max(1),
-                    end_column: issue.column.max(1) + 1,
-                    message: issue.message,
-                    severity: rule_config.severity,
-                    line_text,
-                    category: None,
-                    rule_type: IssueRuleType::Ai,
-                })
-            })
-            .collect();
+            let file_path = PathBuf::from(&issue.file);
+            let Some(lines) = file_lines.get(&file_path) else {
+                problems.push(format!(
+                    "issues[{}]: unknown file \"{}\" (not in the provided files)",
+                    index, issue.file
+                ));
+                continue;
+            };
+
+            if issue.line == 0 || issue.line > lines.len() {
+                problems.push(format!(
+                    "issues[{}]: line {} is out of range for {} (1-{})",
+                    index,
+                    issue.line,
+                    issue.file,
+                    lines.len()
+                ));
+                continue;
+            }
+
+            if issue.message.trim().is_empty() {
+                problems.push(format!("issues[{}]: message is empty", index));
+                continue;
+            }
+
+            let line_text = super::utils::extract_line_text(lines, issue.line);
+
+            issues.push(Issue {
+                rule: rule_name.to_string(),
+                file: workspace_root.join(&issue.file),
+                line: issue.line,
+                end_line: issue.line,
+                column: issue.column.max(1),
+                end_column: issue.column.max(1) + 1,
+                message: issue.message,
+                severity: rule_config.severity,
+                line_text,
+                category: None,
+                rule_type: IssueRuleType::Ai,
+                fix: None,
+                note: None,
+                docs_url: None,
+                related: Vec::new(),
+                blame: None,
+            });
+        }
+
+        for problem in &problems {
+            (self.log_warn)(&format!("AI rule '{}': {}", rule_name, problem));
+        }
 
         (self.log_warn)(&format!(
             "AI rule '{}': {} issues after validation",
@@ -819,7 +1298,7 @@ impl AiExecutor {
             issues.len()
         ));
 
-        Ok(issues)
+        Ok(ParsedResponse { issues, problems })
     }
 
     fn validate_cached_issues(
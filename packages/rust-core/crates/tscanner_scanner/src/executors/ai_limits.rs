@@ -0,0 +1,138 @@
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::sync::{Condvar, Mutex};
+use std::time::{Duration, Instant};
+use tscanner_config::AiConfig;
+use tscanner_constants::{
+    ai_backoff_base_ms, ai_backoff_max_ms, ai_default_max_concurrency, ai_default_max_retries,
+};
+
+struct TokenBucket {
+    tokens: f64,
+    capacity: f64,
+    refill_per_sec: f64,
+    last_refill: Instant,
+}
+
+impl TokenBucket {
+    fn new(requests_per_minute: u64, capacity: usize) -> Self {
+        let capacity = capacity.max(1) as f64;
+        Self {
+            tokens: capacity,
+            capacity,
+            refill_per_sec: requests_per_minute as f64 / 60.0,
+            last_refill: Instant::now(),
+        }
+    }
+
+    fn try_take(&mut self) -> Result<(), Duration> {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.last_refill).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.refill_per_sec).min(self.capacity);
+        self.last_refill = now;
+
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            Ok(())
+        } else {
+            Err(Duration::from_secs_f64(
+                (1.0 - self.tokens) / self.refill_per_sec,
+            ))
+        }
+    }
+}
+
+pub struct AiRequestLimiter {
+    max_concurrency: usize,
+    max_retries: usize,
+    active: Mutex<usize>,
+    released: Condvar,
+    bucket: Option<Mutex<TokenBucket>>,
+}
+
+pub struct AiRequestPermit<'a> {
+    limiter: &'a AiRequestLimiter,
+}
+
+impl Drop for AiRequestPermit<'_> {
+    fn drop(&mut self) {
+        if let Ok(mut active) = self.limiter.active.lock() {
+            *active = active.saturating_sub(1);
+        }
+        self.limiter.released.notify_one();
+    }
+}
+
+impl AiRequestLimiter {
+    pub fn new(ai_config: Option<&AiConfig>) -> Self {
+        let max_concurrency = ai_config
+            .and_then(|c| c.max_concurrency)
+            .map(|n| n as usize)
+            .unwrap_or(ai_default_max_concurrency())
+            .max(1);
+        let max_retries = ai_config
+            .and_then(|c| c.max_retries)
+            .map(|n| n as usize)
+            .unwrap_or(ai_default_max_retries());
+        let bucket = ai_config
+            .and_then(|c| c.requests_per_minute)
+            .filter(|rpm| *rpm > 0)
+            .map(|rpm| Mutex::new(TokenBucket::new(rpm, max_concurrency)));
+
+        Self {
+            max_concurrency,
+            max_retries,
+            active: Mutex::new(0),
+            released: Condvar::new(),
+            bucket,
+        }
+    }
+
+    pub fn max_retries(&self) -> usize {
+        self.max_retries
+    }
+
+    pub fn acquire(&self) -> AiRequestPermit<'_> {
+        let mut active = self.active.lock().unwrap_or_else(|e| e.into_inner());
+        while *active >= self.max_concurrency {
+            active = self
+                .released
+                .wait(active)
+                .unwrap_or_else(|e| e.into_inner());
+        }
+        *active += 1;
+        drop(active);
+
+        let permit = AiRequestPermit { limiter: self };
+
+        if let Some(bucket) = &self.bucket {
+            loop {
+                let wait = match bucket.lock() {
+                    Ok(mut bucket) => bucket.try_take(),
+                    Err(_) => Ok(()),
+                };
+                match wait {
+                    Ok(()) => break,
+                    Err(delay) => std::thread::sleep(delay),
+                }
+            }
+        }
+
+        permit
+    }
+}
+
+pub fn backoff_delay(attempt: usize, retry_after: Option<Duration>) -> Duration {
+    let max = Duration::from_millis(ai_backoff_max_ms());
+    if let Some(retry_after) = retry_after {
+        return retry_after.min(max);
+    }
+
+    let exponent = attempt.saturating_sub(1).min(16) as u32;
+    let ceiling = ai_backoff_base_ms()
+        .saturating_mul(1 << exponent)
+        .min(ai_backoff_max_ms());
+    let half = ceiling / 2;
+    let jitter = RandomState::new().build_hasher().finish() % (half + 1);
+    Duration::from_millis(half + jitter)
+}
@@ -1,8 +1,9 @@
 use crate::disable_comments::DisableDirectives;
 use crate::parser::parse_file;
+use std::collections::HashSet;
 use std::path::Path;
 use tscanner_config::{CompiledRuleConfig, TscannerConfig, TscannerConfigExt};
-use tscanner_constants::is_js_ts_extension;
+use tscanner_constants::{is_js_ts_extension, rule_docs_url};
 use tscanner_rules::{RuleContext, RuleRegistry};
 use tscanner_types::FileSource;
 use tscanner_types::{FileResult, Issue, IssueRuleType};
@@ -54,8 +55,17 @@ impl<'a> BuiltinExecutor<'a> {
     }
 
     pub fn execute(&self, path: &Path, source: &str) -> ExecuteResult {
+        self.execute_selected(path, source, None)
+    }
+
+    pub fn execute_selected(
+        &self,
+        path: &Path,
+        source: &str,
+        only: Option<&HashSet<String>>,
+    ) -> ExecuteResult {
         if !is_js_ts_file(path) {
-            return self.execute_regex_only(path, source);
+            return self.execute_regex_only(path, source, only);
         }
 
         let directives = DisableDirectives::from_source(source);
@@ -87,6 +97,7 @@ impl<'a> BuiltinExecutor<'a> {
 
         let issues: Vec<Issue> = enabled_rules
             .iter()
+            .filter(|(rule, _)| only.is_none_or(|rules| rules.contains(rule.name())))
             .filter(|(rule, _)| !(rule.is_typescript_only() && file_source.is_javascript()))
             .flat_map(|(rule, severity)| {
                 let signals = rule.signals(&ctx);
@@ -102,6 +113,9 @@ impl<'a> BuiltinExecutor<'a> {
                         } else {
                             IssueRuleType::Builtin
                         };
+                        if issue.rule_type == IssueRuleType::Builtin {
+                            issue.docs_url = category.map(|c| rule_docs_url(c, rule.name()));
+                        }
                         if issue.line > 0 && issue.line <= source_lines.len() {
                             issue.line_text = Some(source_lines[issue.line - 1].to_string());
                         }
@@ -122,8 +136,13 @@ impl<'a> BuiltinExecutor<'a> {
         }
     }
 
-    fn execute_regex_only(&self, path: &Path, source: &str) -> ExecuteResult {
-        let enabled_rules = self.registry.get_enabled_regex_rules(
+    fn execute_regex_only(
+        &self,
+        path: &Path,
+        source: &str,
+        only: Option<&HashSet<String>>,
+    ) -> ExecuteResult {
+        let mut enabled_rules = self.registry.get_enabled_regex_rules(
             path,
             self.root,
             |file_path: &Path, root: &Path, compiled: &CompiledRuleConfig| {
@@ -131,6 +150,7 @@ impl<'a> BuiltinExecutor<'a> {
                     .matches_file_with_root(file_path, root, compiled)
             },
         );
+        enabled_rules.retain(|(rule, _)| only.is_none_or(|rules| rules.contains(rule.name())));
 
         if enabled_rules.is_empty() {
             return ExecuteResult::Skip;
@@ -161,6 +181,9 @@ impl<'a> BuiltinExecutor<'a> {
                         } else {
                             IssueRuleType::Builtin
                         };
+                        if issue.rule_type == IssueRuleType::Builtin {
+                            issue.docs_url = category.map(|c| rule_docs_url(c, rule.name()));
+                        }
                         if issue.line > 0 && issue.line <= source_lines.len() {
                             issue.line_text = Some(source_lines[issue.line - 1].to_string());
                         }
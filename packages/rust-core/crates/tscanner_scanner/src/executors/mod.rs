@@ -1,4 +1,6 @@
+mod ai_batches;
 mod ai_executor;
+mod ai_limits;
 mod builtin_executor;
 mod script_executor;
 mod utils;
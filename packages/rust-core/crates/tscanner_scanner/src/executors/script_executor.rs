@@ -37,6 +37,8 @@ pub struct ScriptIssue {
 #[derive(Debug, Deserialize)]
 pub struct ScriptOutput {
     pub issues: Vec<ScriptIssue>,
+    #[serde(default)]
+    pub reads: Vec<String>,
 }
 
 #[derive(Debug)]
@@ -70,6 +72,8 @@ impl From<std::io::Error> for ScriptError {
     }
 }
 
+type ScriptRun = (Vec<Issue>, Vec<String>);
+
 pub struct ScriptExecutor {
     cache: Arc<ScriptCache>,
     config_dir: PathBuf,
@@ -194,15 +198,27 @@ impl ScriptExecutor {
         let files_owned: Vec<(PathBuf, String)> =
             files.iter().map(|(p, c)| (p.clone(), c.clone())).collect();
 
-        if let Some(cached) = self.cache.get(rule_name, &script_path, &files_owned) {
+        let mut dependencies =
+            super::utils::expand_patterns(workspace_root, &rule_config.depends_on);
+
+        if let Some(cached) = self
+            .cache
+            .get(rule_name, &script_path, &files_owned, &dependencies)
+        {
             (self.log_debug)(&format!("Script rule '{}' cache hit", rule_name));
             return Ok(cached);
         }
 
-        let issues = self.execute_batch(rule_name, rule_config, files, workspace_root)?;
+        let (issues, reads) = self.execute_batch(rule_name, rule_config, files, workspace_root)?;
+        dependencies.extend(reads.iter().map(|read| workspace_root.join(read)));
 
-        self.cache
-            .insert(rule_name, &script_path, &files_owned, issues.clone());
+        self.cache.insert(
+            rule_name,
+            &script_path,
+            &files_owned,
+            &dependencies,
+            issues.clone(),
+        );
 
         Ok(issues)
     }
@@ -222,7 +238,7 @@ impl ScriptExecutor {
         rule_config: &ScriptRuleConfig,
         files: &[&(PathBuf, String)],
         workspace_root: &Path,
-    ) -> Result<Vec<Issue>, ScriptError> {
+    ) -> Result<ScriptRun, ScriptError> {
         let script_files: Vec<ScriptFile> = files
             .iter()
             .map(|(path, content)| {
@@ -344,7 +360,7 @@ impl ScriptExecutor {
         output: &[u8],
         workspace_root: &Path,
         files: &[&(PathBuf, String)],
-    ) -> Result<Vec<Issue>, ScriptError> {
+    ) -> Result<ScriptRun, ScriptError> {
         let output_str = String::from_utf8_lossy(output);
 
         let json_start = output_str.find('{');
@@ -352,7 +368,7 @@ impl ScriptExecutor {
             Some(start) => &output_str[start..],
             None => {
                 if output_str.trim().is_empty() {
-                    return Ok(vec![]);
+                    return Ok((vec![], vec![]));
                 }
                 return Err(ScriptError::InvalidOutput(format!(
                     "No JSON found in output: {}",
@@ -378,7 +394,7 @@ impl ScriptExecutor {
             })
             .collect();
 
-        Ok(script_output
+        let issues = script_output
             .issues
             .into_iter()
             .map(|issue| {
@@ -391,6 +407,7 @@ impl ScriptExecutor {
                     rule: rule_name.to_string(),
                     file: file_path,
                     line: issue.line,
+                    end_line: issue.line,
                     column: if issue.column > 0 { issue.column } else { 1 },
                     end_column: if issue.column > 0 {
                         issue.column + 1
@@ -402,9 +419,16 @@ impl ScriptExecutor {
                     line_text,
                     category: None,
                     rule_type: IssueRuleType::CustomScript,
+                    fix: None,
+                    note: None,
+                    docs_url: None,
+                    related: Vec::new(),
+                    blame: None,
                 }
             })
-            .collect())
+            .collect();
+
+        Ok((issues, script_output.reads))
     }
 
     pub fn clear_cache(&self) {
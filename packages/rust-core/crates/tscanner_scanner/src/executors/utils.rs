@@ -1,4 +1,5 @@
-use std::path::Path;
+use ignore::WalkBuilder;
+use std::path::{Path, PathBuf};
 
 pub fn file_matches_patterns(
     path: &Path,
@@ -34,3 +35,119 @@ pub fn extract_line_text(lines: &[&str], line_num: usize) -> Option<String> {
         None
     }
 }
+
+pub fn expand_patterns(workspace_root: &Path, patterns: &[String]) -> Vec<PathBuf> {
+    let mut paths: Vec<PathBuf> = Vec::new();
+
+    for pattern in patterns {
+        let base: PathBuf = Path::new(pattern)
+            .components()
+            .take_while(|c| {
+                !c.as_os_str()
+                    .to_string_lossy()
+                    .contains(['*', '?', '[', '{'])
+            })
+            .collect();
+
+        if base.as_os_str() == pattern.as_str() {
+            let path = workspace_root.join(pattern);
+            if path.is_file() {
+                paths.push(path);
+            }
+            continue;
+        }
+
+        let walker = WalkBuilder::new(workspace_root.join(&base))
+            .standard_filters(false)
+            .hidden(false)
+            .filter_entry(|entry| entry.file_name() != ".git")
+            .build();
+
+        for entry in walker.flatten() {
+            if !entry.file_type().is_some_and(|t| t.is_file()) {
+                continue;
+            }
+            let relative = entry
+                .path()
+                .strip_prefix(workspace_root)
+                .unwrap_or(entry.path());
+            if glob_match::glob_match(pattern, &relative.to_string_lossy()) {
+                paths.push(entry.into_path());
+            }
+        }
+    }
+
+    paths.sort();
+    paths.dedup();
+    paths
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tscanner_cache::ScriptCache;
+
+    fn workspace(name: &str) -> PathBuf {
+        let root =
+            std::env::temp_dir().join(format!("tscanner-deps-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("generated")).unwrap();
+        let git = std::process::Command::new("git")
+            .args(["init", "-q"])
+            .current_dir(&root)
+            .status()
+            .unwrap();
+        assert!(git.success());
+        fs::write(root.join(".gitignore"), "generated/\n").unwrap();
+        fs::write(root.join("generated/schema.json"), "{\"version\": 1}").unwrap();
+        fs::write(root.join("check.js"), "// script").unwrap();
+        root
+    }
+
+    fn patterns(values: &[&str]) -> Vec<String> {
+        values.iter().map(|v| v.to_string()).collect()
+    }
+
+    #[test]
+    fn expands_gitignored_dependencies_but_skips_git_dir() {
+        let root = workspace("gitignored");
+
+        let paths = expand_patterns(&root, &patterns(&["**/*.json", "**/config"]));
+
+        assert_eq!(paths, vec![root.join("generated/schema.json")]);
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn changed_dependency_misses_script_cache() {
+        let root = workspace("changed");
+        let script = root.join("check.js");
+        let files = vec![(root.join("src/a.ts"), "const a = 1;".to_string())];
+        let depends_on = patterns(&["generated/**/*.json"]);
+        let cache = ScriptCache::new();
+
+        let dependencies = expand_patterns(&root, &depends_on);
+        cache.insert("check", &script, &files, &dependencies, Vec::new());
+        assert!(cache
+            .get(
+                "check",
+                &script,
+                &files,
+                &expand_patterns(&root, &depends_on)
+            )
+            .is_some());
+
+        fs::write(root.join("generated/schema.json"), "{\"version\": 2}").unwrap();
+
+        assert!(cache
+            .get(
+                "check",
+                &script,
+                &files,
+                &expand_patterns(&root, &depends_on)
+            )
+            .is_none());
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
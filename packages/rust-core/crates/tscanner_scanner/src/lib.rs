@@ -14,5 +14,8 @@ pub use executors::{
     ScriptInput, ScriptOutput,
 };
 pub use parser::parse_file;
-pub use scanner::{BranchScanResult, ScanCallbacks, Scanner, StagedScanResult};
+pub use scanner::{
+    BranchScanResult, GitContentSource, IncrementalScanResult, ScanCallbacks, Scanner,
+    StagedScanResult,
+};
 pub use tscanner_constants::{ignore_comment, ignore_next_line_comment};
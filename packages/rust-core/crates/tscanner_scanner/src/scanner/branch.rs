@@ -103,18 +103,11 @@ impl Scanner {
             .filter_map(|path| {
                 processed.fetch_add(1, Ordering::Relaxed);
 
-                if let Some(cached_issues) = self.cache.get(path) {
+                let (result, cache_hit) = self.run_cached_builtin_executor(path);
+                if cache_hit {
                     cache_hits.fetch_add(1, Ordering::Relaxed);
-                    if cached_issues.is_empty() {
-                        return None;
-                    }
-                    return Some(FileResult {
-                        file: path.clone(),
-                        issues: cached_issues,
-                    });
                 }
-
-                self.run_builtin_executor(path)
+                result
             })
             .filter(|r| !r.issues.is_empty())
             .collect();
@@ -5,6 +5,7 @@ use std::path::PathBuf;
 use std::sync::Arc;
 use tscanner_cache::{AiCache, FileCache, ScriptCache};
 use tscanner_config::{compile_globset, TscannerConfig, TscannerConfigExt};
+use tscanner_constants::rule_tests_dir;
 use tscanner_logger::{log_debug, log_error, log_info, log_warn};
 use tscanner_rules::RuleRegistry;
 
@@ -106,8 +107,10 @@ impl Scanner {
         log_error: fn(&str),
         log_warn: fn(&str),
     ) -> Result<Self, Box<dyn std::error::Error>> {
-        let config_hash = config.compute_hash();
-        let cache = Arc::new(FileCache::with_config_hash(config_hash));
+        let cache = Arc::new(
+            FileCache::for_project(&root, None, config.file_rule_fingerprints())
+                .with_limits(config.cache_limits()),
+        );
         Self::with_cache_and_logger(
             config, cache, root, None, log_info, log_debug, log_error, log_warn,
         )
@@ -124,8 +127,10 @@ impl Scanner {
         log_error: fn(&str),
         log_warn: fn(&str),
     ) -> Result<Self, Box<dyn std::error::Error>> {
-        let config_hash = config.compute_hash();
-        let ai_cache = Arc::new(AiCache::with_config_hash(config_hash));
+        let ai_cache = Arc::new(
+            AiCache::for_project(&root, None, config.ai_rule_fingerprints())
+                .with_limits(config.cache_limits()),
+        );
         Self::with_caches_and_logger(
             config, cache, ai_cache, root, config_dir, log_info, log_debug, log_error, log_warn,
         )
@@ -143,8 +148,10 @@ impl Scanner {
         log_error: fn(&str),
         log_warn: fn(&str),
     ) -> Result<Self, Box<dyn std::error::Error>> {
-        let config_hash = config.compute_hash();
-        let script_cache = Arc::new(ScriptCache::with_config_hash(config_hash));
+        let script_cache = Arc::new(
+            ScriptCache::for_project(&root, None, config.script_rule_fingerprints())
+                .with_limits(config.cache_limits()),
+        );
         Self::with_all_caches_and_logger(
             config,
             cache,
@@ -180,7 +187,15 @@ impl Scanner {
             log_error,
         )?;
         let global_include = compile_globset(&config.files.include)?;
-        let global_exclude = compile_globset(&config.files.exclude)?;
+        let mut exclude_patterns = config.files.exclude.clone();
+        if let Some(name) = config_dir.as_ref().and_then(|dir| dir.file_name()) {
+            exclude_patterns.push(format!(
+                "**/{}/{}/**",
+                name.to_string_lossy(),
+                rule_tests_dir()
+            ));
+        }
+        let global_exclude = compile_globset(&exclude_patterns)?;
         let custom_patterns = config.get_rule_specific_include_patterns();
         let custom_include = if custom_patterns.is_empty() {
             None
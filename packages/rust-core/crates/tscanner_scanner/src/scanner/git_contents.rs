@@ -0,0 +1,178 @@
+use super::codebase::ScanCallbacks;
+use super::Scanner;
+use crate::executors::{AiExecutionResult, ChangedLinesMap};
+use rayon::prelude::*;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+use tscanner_config::AiExecutionMode;
+use tscanner_git::{get_file_at_revision, get_staged_file_content};
+use tscanner_types::{FileResult, Issue, ScanResult};
+
+#[derive(Debug, Clone)]
+pub enum GitContentSource {
+    Index,
+    Revision(String),
+}
+
+impl GitContentSource {
+    pub fn read(&self, root: &Path, path: &Path) -> anyhow::Result<String> {
+        match self {
+            GitContentSource::Index => get_staged_file_content(root, path),
+            GitContentSource::Revision(revision) => get_file_at_revision(root, revision, path),
+        }
+    }
+
+    fn label(&self) -> String {
+        match self {
+            GitContentSource::Index => "the index".to_string(),
+            GitContentSource::Revision(revision) => format!("revision {}", revision),
+        }
+    }
+}
+
+impl Scanner {
+    pub fn scan_git_contents(
+        &self,
+        source: &GitContentSource,
+        files: &HashSet<PathBuf>,
+    ) -> ScanResult {
+        self.scan_git_contents_with_callbacks(
+            source,
+            files,
+            AiExecutionMode::Ignore,
+            None,
+            ScanCallbacks {
+                on_regular_rules_complete: None,
+                on_ai_progress: None,
+            },
+        )
+    }
+
+    pub fn scan_git_contents_with_callbacks(
+        &self,
+        source: &GitContentSource,
+        files: &HashSet<PathBuf>,
+        ai_mode: AiExecutionMode,
+        changed_lines: Option<&ChangedLinesMap>,
+        callbacks: ScanCallbacks,
+    ) -> ScanResult {
+        let start = Instant::now();
+        (self.log_info)(&format!(
+            "Starting scan of {} files from {} (ai_mode: {:?})",
+            files.len(),
+            source.label(),
+            ai_mode
+        ));
+
+        let files: Vec<PathBuf> = files
+            .iter()
+            .filter(|p| self.matches_file_patterns(p))
+            .cloned()
+            .collect();
+        let file_count = files.len();
+
+        let regular_start = Instant::now();
+        let (contents, mut warnings) = self.read_git_contents(source, &files);
+        let mut results = if ai_mode == AiExecutionMode::Only {
+            Vec::new()
+        } else {
+            let (results, script_warnings) = self.scan_file_contents(&contents);
+            warnings.extend(script_warnings);
+            results
+        };
+        let regular_duration = regular_start.elapsed();
+
+        if let Some(ref cb) = callbacks.on_regular_rules_complete {
+            cb(regular_duration.as_millis());
+        }
+
+        let ai_start = Instant::now();
+        let ai_result = if ai_mode == AiExecutionMode::Ignore || contents.is_empty() {
+            AiExecutionResult::default()
+        } else {
+            self.run_ai_rules_on_contents(&contents, changed_lines, callbacks.on_ai_progress)
+        };
+        let ai_duration = ai_start.elapsed();
+
+        self.merge_issues(&mut results, ai_result.issues);
+        warnings.extend(ai_result.warnings);
+
+        self.ai_cache.flush();
+        self.script_cache.flush();
+
+        let total_issues: usize = results.iter().map(|r| r.issues.len()).sum();
+
+        ScanResult {
+            files: results,
+            total_issues,
+            duration_ms: start.elapsed().as_millis(),
+            regular_rules_duration_ms: regular_duration.as_millis(),
+            ai_rules_duration_ms: ai_duration.as_millis(),
+            total_files: file_count,
+            cached_files: ai_result.cache_hits,
+            scanned_files: file_count.saturating_sub(ai_result.cache_hits),
+            notes: Vec::new(),
+            warnings,
+            errors: ai_result.errors,
+        }
+    }
+
+    pub(crate) fn read_git_contents(
+        &self,
+        source: &GitContentSource,
+        files: &[PathBuf],
+    ) -> (Vec<(PathBuf, String)>, Vec<String>) {
+        let read: Vec<Result<(PathBuf, String), String>> = files
+            .par_iter()
+            .map(|path| {
+                source
+                    .read(&self.root, path)
+                    .map(|content| (path.clone(), content))
+                    .map_err(|e| {
+                        format!(
+                            "Failed to read {} from {}: {}",
+                            path.display(),
+                            source.label(),
+                            e
+                        )
+                    })
+            })
+            .collect();
+
+        let mut contents = Vec::new();
+        let mut warnings = Vec::new();
+        for entry in read {
+            match entry {
+                Ok(content) => contents.push(content),
+                Err(warning) => warnings.push(warning),
+            }
+        }
+        (contents, warnings)
+    }
+
+    pub(crate) fn scan_file_contents(
+        &self,
+        contents: &[(PathBuf, String)],
+    ) -> (Vec<FileResult>, Vec<String>) {
+        (self.log_debug)(&format!("Scanning {} in-memory files", contents.len()));
+
+        let mut results: Vec<FileResult> = contents
+            .par_iter()
+            .filter_map(|(path, content)| self.run_builtin_executor_no_cache(path, content))
+            .filter(|r| !r.issues.is_empty())
+            .collect();
+
+        let script_rules = self.collect_script_rules();
+        let (script_issues, warnings): (Vec<Issue>, Vec<String>) =
+            if script_rules.is_empty() || contents.is_empty() {
+                (Vec::new(), Vec::new())
+            } else {
+                self.script_executor
+                    .execute_rules(&script_rules, contents, &self.root)
+            };
+        self.merge_issues(&mut results, script_issues);
+
+        (results, warnings)
+    }
+}
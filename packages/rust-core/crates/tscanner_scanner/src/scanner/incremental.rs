@@ -0,0 +1,110 @@
+use super::Scanner;
+use rayon::prelude::*;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+use tscanner_config::ScriptRuleConfig;
+use tscanner_types::{FileResult, Issue};
+
+#[derive(Debug, Default)]
+pub struct IncrementalScanResult {
+    pub files: Vec<FileResult>,
+    pub removed_files: Vec<PathBuf>,
+    pub script_rules: Vec<String>,
+    pub script_issues: Vec<Issue>,
+    pub warnings: Vec<String>,
+    pub duration_ms: u128,
+}
+
+impl IncrementalScanResult {
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty() && self.removed_files.is_empty() && self.script_rules.is_empty()
+    }
+}
+
+impl Scanner {
+    pub fn scan_changed_files(&self, changed_files: &HashSet<PathBuf>) -> IncrementalScanResult {
+        let start = Instant::now();
+        (self.log_info)(&format!(
+            "Starting incremental scan of {} changed files",
+            changed_files.len()
+        ));
+
+        let (scannable, removed_files): (Vec<PathBuf>, Vec<PathBuf>) = changed_files
+            .iter()
+            .cloned()
+            .partition(|path| self.is_scannable_file(path));
+
+        let files: Vec<FileResult> = scannable
+            .par_iter()
+            .map(|path| {
+                self.cache.invalidate(path);
+                self.run_builtin_executor(path)
+                    .unwrap_or_else(|| FileResult {
+                        file: path.clone(),
+                        issues: Vec::new(),
+                    })
+            })
+            .collect();
+
+        let affected_rules = self.collect_affected_script_rules(changed_files);
+        let (script_issues, warnings) = if affected_rules.is_empty() {
+            (Vec::new(), Vec::new())
+        } else {
+            let script_files = self.collect_script_files(&affected_rules);
+            (self.log_debug)(&format!(
+                "Re-running {} script rules on {} files",
+                affected_rules.len(),
+                script_files.len()
+            ));
+            self.script_executor
+                .execute_rules(&affected_rules, &script_files, &self.root)
+        };
+
+        self.cache.flush();
+        self.script_cache.flush();
+
+        IncrementalScanResult {
+            files,
+            removed_files,
+            script_rules: affected_rules.into_iter().map(|(name, _)| name).collect(),
+            script_issues,
+            warnings,
+            duration_ms: start.elapsed().as_millis(),
+        }
+    }
+
+    fn is_scannable_file(&self, path: &Path) -> bool {
+        path.is_file() && self.matches_file_patterns(path)
+    }
+
+    fn collect_affected_script_rules(
+        &self,
+        changed_files: &HashSet<PathBuf>,
+    ) -> Vec<(String, ScriptRuleConfig)> {
+        let relative_paths: Vec<String> = changed_files
+            .iter()
+            .map(|path| {
+                path.strip_prefix(&self.root)
+                    .unwrap_or(path)
+                    .to_string_lossy()
+                    .to_string()
+            })
+            .collect();
+
+        self.collect_script_rules()
+            .into_iter()
+            .filter(|(_, cfg)| {
+                relative_paths.iter().any(|relative| {
+                    cfg.include
+                        .iter()
+                        .any(|pattern| glob_match::glob_match(pattern, relative))
+                        && !cfg
+                            .exclude
+                            .iter()
+                            .any(|pattern| glob_match::glob_match(pattern, relative))
+                })
+            })
+            .collect()
+    }
+}
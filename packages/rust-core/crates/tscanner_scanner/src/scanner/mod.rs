@@ -1,6 +1,8 @@
 mod branch;
 mod codebase;
 mod core;
+mod git_contents;
+mod incremental;
 mod shared;
 mod staged;
 mod uncommitted;
@@ -8,4 +10,6 @@ mod uncommitted;
 pub use branch::BranchScanResult;
 pub use codebase::ScanCallbacks;
 pub use core::Scanner;
+pub use git_contents::GitContentSource;
+pub use incremental::IncrementalScanResult;
 pub use staged::StagedScanResult;
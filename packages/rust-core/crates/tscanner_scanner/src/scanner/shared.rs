@@ -3,27 +3,72 @@ use crate::executors::{AiExecutionResult, AiProgressCallback, BuiltinExecutor, E
 use ignore::WalkBuilder;
 use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
-use tscanner_config::{compile_globset, AiRuleConfig, ScriptRuleConfig};
+use tscanner_cache::CacheLookup;
+use tscanner_config::{compile_globset, AiMode, AiRuleConfig, ScriptRuleConfig};
 use tscanner_types::{FileResult, Issue};
 
 impl Scanner {
     pub(crate) fn run_builtin_executor(&self, path: &Path) -> Option<FileResult> {
         let source = std::fs::read_to_string(path).ok()?;
-        let executor =
-            BuiltinExecutor::with_logger(&self.registry, &self.config, &self.root, self.log_debug);
+        self.execute_and_cache(path, &source, None, Vec::new())
+    }
 
-        match executor.execute(path, &source) {
-            ExecuteResult::Skip | ExecuteResult::Disabled | ExecuteResult::Empty => {
-                self.cache.insert(path.to_path_buf(), Vec::new());
-                None
+    pub(crate) fn run_cached_builtin_executor(&self, path: &Path) -> (Option<FileResult>, bool) {
+        let Ok(source) = std::fs::read_to_string(path) else {
+            return (None, false);
+        };
+
+        match self.cache.lookup(path, &source) {
+            CacheLookup::Hit(issues) => {
+                let result = (!issues.is_empty()).then(|| FileResult {
+                    file: path.to_path_buf(),
+                    issues,
+                });
+                (result, true)
             }
-            ExecuteResult::ParseError => None,
-            ExecuteResult::Ok(file_result) => {
-                self.cache
-                    .insert(path.to_path_buf(), file_result.issues.clone());
-                Some(file_result)
+            CacheLookup::Partial {
+                issues,
+                stale_rules,
+            } => {
+                (self.log_debug)(&format!(
+                    "Re-running {} changed rules on cached {:?}",
+                    stale_rules.len(),
+                    path
+                ));
+                let result = self.execute_and_cache(path, &source, Some(&stale_rules), issues);
+                (result, false)
             }
+            CacheLookup::Miss => (
+                self.execute_and_cache(path, &source, None, Vec::new()),
+                false,
+            ),
+        }
+    }
+
+    fn execute_and_cache(
+        &self,
+        path: &Path,
+        source: &str,
+        only: Option<&HashSet<String>>,
+        mut issues: Vec<Issue>,
+    ) -> Option<FileResult> {
+        let executor =
+            BuiltinExecutor::with_logger(&self.registry, &self.config, &self.root, self.log_debug);
+
+        match executor.execute_selected(path, source, only) {
+            ExecuteResult::Skip | ExecuteResult::Disabled | ExecuteResult::Empty => {}
+            ExecuteResult::ParseError => return None,
+            ExecuteResult::Ok(file_result) => issues.extend(file_result.issues),
         }
+
+        self.cache.insert(path, source, issues.clone());
+        if issues.is_empty() {
+            return None;
+        }
+        Some(FileResult {
+            file: path.to_path_buf(),
+            issues,
+        })
     }
 
     pub(crate) fn run_builtin_executor_no_cache(
@@ -42,6 +87,22 @@ impl Scanner {
         }
     }
 
+    pub(crate) fn matches_file_patterns(&self, path: &Path) -> bool {
+        let relative = path.strip_prefix(&self.root).unwrap_or(path);
+        if relative
+            .ancestors()
+            .any(|p| self.global_exclude.is_match(p))
+        {
+            return false;
+        }
+        self.global_include.is_match(relative)
+            || self
+                .custom_include
+                .as_ref()
+                .map(|g| g.is_match(relative))
+                .unwrap_or(false)
+    }
+
     pub(crate) fn collect_script_rules(&self) -> Vec<(String, ScriptRuleConfig)> {
         self.config
             .rules
@@ -145,6 +206,61 @@ impl Scanner {
         )
     }
 
+    pub(crate) fn run_ai_rules_on_contents(
+        &self,
+        contents: &[(PathBuf, String)],
+        changed_lines: Option<&HashMap<PathBuf, HashSet<usize>>>,
+        progress_callback: Option<AiProgressCallback>,
+    ) -> AiExecutionResult {
+        let ai_rules = self.collect_ai_rules();
+        if ai_rules.is_empty() {
+            return AiExecutionResult::default();
+        }
+
+        let include_patterns: HashSet<&str> = ai_rules
+            .iter()
+            .flat_map(|(_, cfg)| cfg.include.iter().map(|s| s.as_str()))
+            .collect();
+        let exclude_patterns: HashSet<&str> = ai_rules
+            .iter()
+            .flat_map(|(_, cfg)| cfg.exclude.iter().map(|s| s.as_str()))
+            .collect();
+        let files: Vec<(PathBuf, String)> = contents
+            .iter()
+            .filter(|(path, _)| self.matches_patterns(&include_patterns, &exclude_patterns, path))
+            .cloned()
+            .collect();
+
+        if files.is_empty() {
+            return AiExecutionResult::default();
+        }
+
+        let mut result = self.ai_executor.execute_rules_with_progress(
+            &ai_rules,
+            &files,
+            &self.root,
+            changed_lines,
+            progress_callback,
+        );
+
+        let differs_from_disk = files
+            .iter()
+            .any(|(path, content)| std::fs::read_to_string(path).ok().as_ref() != Some(content));
+        if differs_from_disk {
+            for (name, rule) in &ai_rules {
+                if rule.mode != AiMode::Content {
+                    result.warnings.push(format!(
+                        "AI rule '{}' uses '{}' mode and reads files from the working tree, which differs from the scanned contents",
+                        name,
+                        rule.mode.as_str()
+                    ));
+                }
+            }
+        }
+
+        result
+    }
+
     pub(crate) fn collect_ai_files(
         &self,
         ai_rules: &[(String, AiRuleConfig)],
@@ -187,18 +303,7 @@ impl Scanner {
         }
 
         let matches_patterns = |path: &Path| -> bool {
-            if !path.is_file() {
-                return false;
-            }
-            let relative = path.strip_prefix(&self.root).unwrap_or(path);
-            let relative_str = relative.to_string_lossy();
-            let matches_include = include_patterns
-                .iter()
-                .any(|pattern| glob_match::glob_match(pattern, &relative_str));
-            let matches_exclude = exclude_patterns
-                .iter()
-                .any(|pattern| glob_match::glob_match(pattern, &relative_str));
-            matches_include && !matches_exclude
+            path.is_file() && self.matches_patterns(include_patterns, exclude_patterns, path)
         };
 
         match file_filter {
@@ -227,6 +332,23 @@ impl Scanner {
         }
     }
 
+    fn matches_patterns(
+        &self,
+        include_patterns: &HashSet<&str>,
+        exclude_patterns: &HashSet<&str>,
+        path: &Path,
+    ) -> bool {
+        let relative = path.strip_prefix(&self.root).unwrap_or(path);
+        let relative_str = relative.to_string_lossy();
+        let matches_include = include_patterns
+            .iter()
+            .any(|pattern| glob_match::glob_match(pattern, &relative_str));
+        let matches_exclude = exclude_patterns
+            .iter()
+            .any(|pattern| glob_match::glob_match(pattern, &relative_str));
+        matches_include && !matches_exclude
+    }
+
     pub(crate) fn merge_issues(&self, results: &mut Vec<FileResult>, issues: Vec<Issue>) {
         if issues.is_empty() {
             return;
@@ -447,3 +569,109 @@ impl Scanner {
         files
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config_ext::ConfigExt;
+    use serde_json::{json, Value};
+    use std::sync::Arc;
+    use tscanner_cache::{AiCache, FileCache, ScriptCache};
+    use tscanner_config::TscannerConfig;
+
+    const SOURCE: &str = "var a = 1;\nconsole.log(a);\nconsole.warn(a);\n";
+
+    fn scratch_dir(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("tscanner-partial-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn config(no_console: Value) -> TscannerConfig {
+        serde_json::from_value(json!({
+            "rules": { "builtin": { "no-console": no_console, "no-var": {} } },
+            "files": { "include": ["**/*.ts"], "exclude": [] }
+        }))
+        .unwrap()
+    }
+
+    fn scanner(root: &Path, config: TscannerConfig) -> Scanner {
+        let cache = FileCache::for_project(
+            root,
+            Some(&root.join("cache")),
+            config.file_rule_fingerprints(),
+        );
+        Scanner::with_caches_and_config_dir(
+            config,
+            Arc::new(cache),
+            Arc::new(AiCache::new()),
+            Arc::new(ScriptCache::new()),
+            root.to_path_buf(),
+            root.join(".tscanner"),
+        )
+        .unwrap()
+    }
+
+    fn positions(result: Option<FileResult>) -> Vec<(String, usize, usize)> {
+        let mut positions: Vec<_> = result
+            .map(|r| r.issues)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|issue| (issue.rule, issue.line, issue.column))
+            .collect();
+        positions.sort();
+        positions
+    }
+
+    #[test]
+    fn changed_options_rerun_only_that_rule() {
+        let root = scratch_dir("options");
+        let file = root.join("a.ts");
+        std::fs::write(&file, SOURCE).unwrap();
+
+        let first = scanner(&root, config(json!({})));
+        let sentinel = Issue::new("no-var", file.clone(), 1, 99, 100, "cached".to_string());
+        first.cache.insert(&file, SOURCE, vec![sentinel]);
+        first.cache.flush();
+
+        let second = scanner(&root, config(json!({ "methods": ["log"] })));
+        let (result, cached) = second.run_cached_builtin_executor(&file);
+
+        assert!(!cached);
+        assert_eq!(
+            positions(result),
+            vec![
+                ("no-console".to_string(), 2, 1),
+                ("no-var".to_string(), 1, 99),
+            ]
+        );
+
+        let (result, cached) = second.run_cached_builtin_executor(&file);
+        assert!(cached);
+        assert_eq!(positions(result).len(), 2);
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn unchanged_config_hits_without_duplicates() {
+        let root = scratch_dir("unchanged");
+        let file = root.join("a.ts");
+        std::fs::write(&file, SOURCE).unwrap();
+
+        let first = scanner(&root, config(json!({})));
+        let (fresh, cached) = first.run_cached_builtin_executor(&file);
+        assert!(!cached);
+        first.cache.flush();
+
+        let second = scanner(&root, config(json!({})));
+        let (reused, cached) = second.run_cached_builtin_executor(&file);
+
+        assert!(cached);
+        let fresh = positions(fresh);
+        assert_eq!(fresh.len(), 3);
+        assert_eq!(positions(reused), fresh);
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -1,8 +1,7 @@
+use super::git_contents::GitContentSource;
 use super::Scanner;
-use rayon::prelude::*;
 use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
-use std::sync::atomic::{AtomicUsize, Ordering};
 use std::time::Instant;
 use tscanner_git::{get_staged_files, get_staged_modified_lines};
 use tscanner_types::{FileResult, ScanResult};
@@ -78,35 +77,10 @@ impl Scanner {
         let files: Vec<PathBuf> = staged_files.iter().cloned().collect();
         let file_count = files.len();
 
-        let processed = AtomicUsize::new(0);
-        let cache_hits = AtomicUsize::new(0);
-
         let regular_start = Instant::now();
-        let results: Vec<FileResult> = files
-            .par_iter()
-            .filter_map(|path| {
-                processed.fetch_add(1, Ordering::Relaxed);
-
-                if let Some(cached_issues) = self.cache.get(path) {
-                    cache_hits.fetch_add(1, Ordering::Relaxed);
-                    if cached_issues.is_empty() {
-                        return None;
-                    }
-                    return Some(FileResult {
-                        file: path.clone(),
-                        issues: cached_issues,
-                    });
-                }
-
-                self.run_builtin_executor(path)
-            })
-            .filter(|r| !r.issues.is_empty())
-            .collect();
-
-        let (script_issues, script_warnings) = self.run_script_rules(&files);
-
-        let mut all_results = results;
-        self.merge_issues(&mut all_results, script_issues);
+        let (contents, mut warnings) = self.read_git_contents(&GitContentSource::Index, &files);
+        let (mut all_results, script_warnings) = self.scan_file_contents(&contents);
+        warnings.extend(script_warnings);
 
         self.filter_to_staged_lines(&mut all_results, staged_lines);
         let regular_duration = regular_start.elapsed();
@@ -123,15 +97,12 @@ impl Scanner {
         let total_issues: usize = all_results.iter().map(|r| r.issues.len()).sum();
         let duration = start.elapsed();
 
-        self.cache.flush();
         self.ai_cache.flush();
         self.script_cache.flush();
 
-        let regular_cache_hits = cache_hits.load(Ordering::Relaxed);
-        let cached = regular_cache_hits + ai_result.cache_hits;
+        let cached = ai_result.cache_hits;
         let scanned = file_count.saturating_sub(cached);
 
-        let mut warnings: Vec<String> = script_warnings;
         warnings.extend(ai_result.warnings);
 
         ScanResult {
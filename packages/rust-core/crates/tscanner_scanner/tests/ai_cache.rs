@@ -0,0 +1,80 @@
+mod common;
+
+use common::{openai_config, openai_response, prompt_of, run_rule_with_cache, MockServer};
+use serde_json::{json, Value};
+use std::sync::Arc;
+use tscanner_cache::AiCache;
+
+const EDITED: &str = "const a = 2;\nconsole.warn(a);\n";
+
+fn rule(mode: &str) -> Value {
+    json!({
+        "prompt": "rule.md",
+        "message": "No console",
+        "mode": mode
+    })
+}
+
+fn rescan(name: &str, mode: &str) -> Vec<String> {
+    let server = MockServer::start(200, openai_response("{\"issues\": []}"));
+    let cache = Arc::new(AiCache::new());
+    let original = [
+        ("src/a.ts", common::SOURCE),
+        ("src/b.ts", common::SOURCE),
+        ("src/c.ts", common::SOURCE),
+    ];
+
+    let (result, _) = run_rule_with_cache(
+        name,
+        openai_config(&server.url),
+        rule(mode),
+        &original,
+        cache.clone(),
+    );
+    assert!(result.errors.is_empty(), "{:?}", result.errors);
+    assert_eq!(server.requests().len(), 1);
+
+    let mut edited = original;
+    edited[1].1 = EDITED;
+    let (result, _) =
+        run_rule_with_cache(name, openai_config(&server.url), rule(mode), &edited, cache);
+    assert!(result.errors.is_empty(), "{:?}", result.errors);
+
+    server.requests().iter().map(prompt_of).collect()
+}
+
+#[test]
+fn content_rescan_sends_only_edited_file() {
+    let prompts = rescan("rescan-content", "content");
+
+    assert_eq!(prompts.len(), 1);
+    assert!(prompts[0].contains("src/b.ts"));
+    assert!(prompts[0].contains(EDITED));
+    assert!(!prompts[0].contains("src/a.ts"));
+    assert!(!prompts[0].contains("src/c.ts"));
+}
+
+#[test]
+fn paths_rescan_sends_only_edited_file() {
+    let prompts = rescan("rescan-paths", "paths");
+
+    assert_eq!(prompts.len(), 1);
+    assert!(prompts[0].contains("src/b.ts"));
+    assert!(!prompts[0].contains("src/a.ts"));
+    assert!(!prompts[0].contains("src/c.ts"));
+}
+
+#[test]
+fn agentic_rescan_bypasses_per_file_cache() {
+    let prompts = rescan("rescan-agentic", "agentic");
+
+    assert_eq!(prompts.len(), 1);
+    for file in ["src/a.ts", "src/b.ts", "src/c.ts"] {
+        assert!(
+            prompts[0].contains(file),
+            "{} missing: {}",
+            file,
+            prompts[0]
+        );
+    }
+}
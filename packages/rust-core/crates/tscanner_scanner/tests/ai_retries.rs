@@ -0,0 +1,172 @@
+mod common;
+
+use common::{openai_config, openai_response, prompt_of, run_rule_on_files, MockServer};
+use serde_json::{json, Value};
+use std::time::Duration;
+use tscanner_scanner::AiRuleStatus;
+
+fn rule(batch_size: u64) -> Value {
+    json!({
+        "prompt": "rule.md",
+        "message": "No console",
+        "mode": "content",
+        "batchSize": batch_size
+    })
+}
+
+fn config_with(url: &str, limits: Value) -> Value {
+    let mut config = openai_config(url);
+    config
+        .as_object_mut()
+        .unwrap()
+        .extend(limits.as_object().unwrap().clone());
+    config
+}
+
+fn empty_issues() -> Value {
+    openai_response("{\"issues\": []}")
+}
+
+fn retries(statuses: &[AiRuleStatus]) -> Vec<(usize, usize)> {
+    statuses
+        .iter()
+        .filter_map(|status| match status {
+            AiRuleStatus::Retrying {
+                attempt,
+                max_retries,
+            } => Some((*attempt, *max_retries)),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn rate_limited_request_is_retried_and_reported() {
+    let rate_limited = json!({ "error": { "message": "slow down" } });
+    let server = MockServer::sequence(vec![
+        (429, rate_limited.clone()),
+        (429, rate_limited),
+        (200, empty_issues()),
+    ]);
+
+    let (result, statuses) = run_rule_on_files(
+        "retry-429",
+        config_with(&server.url, json!({ "maxRetries": 5 })),
+        rule(0),
+        &["src/a.ts"],
+    );
+
+    assert!(result.errors.is_empty(), "{:?}", result.errors);
+    assert_eq!(server.requests().len(), 3);
+    assert_eq!(retries(&statuses), vec![(1, 5), (2, 5)]);
+    assert!(matches!(
+        statuses.last(),
+        Some(AiRuleStatus::Completed { issues_found: 0 })
+    ));
+}
+
+#[test]
+fn retries_stop_after_max_retries() {
+    let server = MockServer::start(503, json!({ "error": { "message": "unavailable" } }));
+
+    let (result, statuses) = run_rule_on_files(
+        "retry-exhausted",
+        config_with(&server.url, json!({ "maxRetries": 2 })),
+        rule(0),
+        &["src/a.ts"],
+    );
+
+    assert_eq!(server.requests().len(), 3);
+    assert_eq!(retries(&statuses), vec![(1, 2), (2, 2)]);
+    assert_eq!(result.errors.len(), 1);
+    assert!(result.errors[0].contains("503"), "{}", result.errors[0]);
+}
+
+#[test]
+fn client_errors_are_not_retried() {
+    let server = MockServer::start(400, json!({ "error": { "message": "bad request" } }));
+
+    let (result, statuses) = run_rule_on_files(
+        "retry-400",
+        config_with(&server.url, json!({ "maxRetries": 3 })),
+        rule(0),
+        &["src/a.ts"],
+    );
+
+    assert_eq!(server.requests().len(), 1);
+    assert!(retries(&statuses).is_empty());
+    assert_eq!(result.errors.len(), 1);
+}
+
+#[test]
+fn max_concurrency_limits_simultaneous_requests() {
+    let files = ["src/a.ts", "src/b.ts", "src/c.ts", "src/d.ts"];
+
+    let limited = MockServer::with_delay(vec![(200, empty_issues())], Duration::from_millis(100));
+    let (result, _) = run_rule_on_files(
+        "concurrency-1",
+        config_with(&limited.url, json!({ "maxConcurrency": 1 })),
+        rule(1),
+        &files,
+    );
+    assert!(result.errors.is_empty(), "{:?}", result.errors);
+    assert_eq!(limited.requests().len(), 4);
+    assert_eq!(limited.max_in_flight(), 1);
+
+    let wider = MockServer::with_delay(vec![(200, empty_issues())], Duration::from_millis(100));
+    let (result, _) = run_rule_on_files(
+        "concurrency-2",
+        config_with(&wider.url, json!({ "maxConcurrency": 2 })),
+        rule(1),
+        &files,
+    );
+    assert!(result.errors.is_empty(), "{:?}", result.errors);
+    assert!(wider.max_in_flight() <= 2);
+}
+
+#[test]
+fn failed_batch_does_not_drop_other_batches() {
+    let server =
+        MockServer::with_handler(|request| {
+            let prompt = prompt_of(request);
+            match ["src/a.ts", "src/c.ts"].iter().find(|file| prompt.contains(*file)) {
+            Some(file) => (
+                200,
+                openai_response(
+                    &json!({ "issues": [{ "file": file, "line": 2, "message": "console call" }] })
+                        .to_string(),
+                ),
+            ),
+            None => (500, json!({ "error": { "message": "boom" } })),
+        }
+        });
+
+    let (result, _) = run_rule_on_files(
+        "batch-isolation",
+        config_with(&server.url, json!({ "maxRetries": 0 })),
+        rule(1),
+        &["src/a.ts", "src/b.ts", "src/c.ts"],
+    );
+
+    assert_eq!(server.requests().len(), 3);
+    let mut files: Vec<String> = result
+        .issues
+        .iter()
+        .map(|issue| {
+            issue
+                .file
+                .file_name()
+                .unwrap()
+                .to_string_lossy()
+                .to_string()
+        })
+        .collect();
+    files.sort();
+    assert_eq!(files, vec!["a.ts", "c.ts"]);
+    assert_eq!(result.errors.len(), 1, "{:?}", result.errors);
+    assert!(
+        result.errors[0].contains("AI rule 'no-console' batch 2/3 failed"),
+        "{}",
+        result.errors[0]
+    );
+}
@@ -0,0 +1,124 @@
+mod common;
+
+use common::{openai_config, openai_response, prompt_of, run_rule, MockServer};
+use serde_json::json;
+
+fn issues(entries: serde_json::Value) -> String {
+    json!({ "issues": entries }).to_string()
+}
+
+#[test]
+fn out_of_range_issue_triggers_repair_prompt() {
+    let server = MockServer::sequence(vec![
+        (
+            200,
+            openai_response(&issues(json!([
+                { "file": "src/a.ts", "line": 40, "message": "console call" }
+            ]))),
+        ),
+        (
+            200,
+            openai_response(&issues(json!([
+                { "file": "src/a.ts", "line": 2, "message": "console call" }
+            ]))),
+        ),
+    ]);
+
+    let result = run_rule("repair-line", openai_config(&server.url));
+
+    assert!(result.errors.is_empty(), "{:?}", result.errors);
+    assert!(result.warnings.is_empty(), "{:?}", result.warnings);
+    assert!(result.invalid_issues.is_empty());
+    assert_eq!(result.issues.len(), 1);
+    assert_eq!(result.issues[0].line, 2);
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 2);
+    let repair_prompt = prompt_of(&requests[1]);
+    assert!(repair_prompt.starts_with(&prompt_of(&requests[0])));
+    assert!(repair_prompt.contains("line 40 is out of range for src/a.ts (1-2)"));
+    assert!(repair_prompt.contains("src/a.ts (lines 1-2)"));
+}
+
+#[test]
+fn still_invalid_issues_are_dropped_and_counted() {
+    let response = openai_response(&issues(json!([
+        { "file": "src/a.ts", "line": 2, "message": "console call" },
+        { "file": "src/missing.ts", "line": 1, "message": "ghost" },
+        { "file": "src/a.ts", "line": 1, "message": "  " }
+    ])));
+    let server = MockServer::start(200, response);
+
+    let result = run_rule("repair-drop", openai_config(&server.url));
+
+    assert!(result.errors.is_empty(), "{:?}", result.errors);
+    assert_eq!(result.issues.len(), 1);
+    assert_eq!(result.issues[0].message, "console call");
+    assert_eq!(result.invalid_issues.get("no-console"), Some(&2));
+    assert_eq!(result.warnings.len(), 1);
+    assert!(result.warnings[0].contains("'no-console' dropped 2 invalid issues"));
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 2);
+    let repair_prompt = prompt_of(&requests[1]);
+    assert!(repair_prompt.contains("issues[1]: unknown file \"src/missing.ts\""));
+    assert!(repair_prompt.contains("issues[2]: message is empty"));
+}
+
+#[test]
+fn malformed_output_is_repaired() {
+    let server = MockServer::sequence(vec![
+        (200, openai_response("I found a console call on line 2.")),
+        (
+            200,
+            openai_response(&issues(json!([
+                { "file": "src/a.ts", "line": 2, "message": "console call" }
+            ]))),
+        ),
+    ]);
+
+    let result = run_rule("repair-malformed", openai_config(&server.url));
+
+    assert!(result.errors.is_empty(), "{:?}", result.errors);
+    assert_eq!(result.issues.len(), 1);
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 2);
+    let repair_prompt = prompt_of(&requests[1]);
+    assert!(repair_prompt.contains("No JSON object found"));
+    assert!(repair_prompt.contains("I found a console call on line 2."));
+}
+
+#[test]
+fn malformed_output_fails_after_repair_attempts() {
+    let server = MockServer::start(200, openai_response("{\"issues\": [{\"file\": "));
+
+    let result = run_rule("repair-fail", openai_config(&server.url));
+
+    assert!(result.issues.is_empty());
+    assert_eq!(result.errors.len(), 1);
+    assert!(
+        result.errors[0].contains("Invalid AI output"),
+        "{}",
+        result.errors[0]
+    );
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[test]
+fn malformed_entries_are_reported_individually() {
+    let server = MockServer::start(
+        200,
+        openai_response(&issues(json!([
+            { "file": "src/a.ts", "line": "two", "message": "console call" },
+            { "file": "src/a.ts", "line": 2, "message": "console call" }
+        ]))),
+    );
+
+    let result = run_rule("repair-entry", openai_config(&server.url));
+
+    assert!(result.errors.is_empty(), "{:?}", result.errors);
+    assert_eq!(result.issues.len(), 1);
+    assert_eq!(result.invalid_issues.get("no-console"), Some(&1));
+    assert!(prompt_of(&server.requests()[1]).contains("issues[0]: invalid type"));
+}
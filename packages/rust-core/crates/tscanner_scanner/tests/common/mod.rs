@@ -0,0 +1,249 @@
+#![allow(dead_code)]
+
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+use tscanner_cache::AiCache;
+use tscanner_config::{AiConfig, AiRuleConfig};
+use tscanner_scanner::executors::AiExecutionResult;
+use tscanner_scanner::{AiExecutor, AiRuleStatus};
+
+pub const SOURCE: &str = "const a = 1;\nconsole.log(a);\n";
+
+pub struct RecordedRequest {
+    pub path: String,
+    pub headers: HashMap<String, String>,
+    pub body: Value,
+}
+
+pub struct MockServer {
+    pub url: String,
+    requests: Arc<Mutex<Vec<RecordedRequest>>>,
+    max_in_flight: Arc<AtomicUsize>,
+}
+
+impl MockServer {
+    pub fn start(status: u16, response: Value) -> Self {
+        Self::sequence(vec![(status, response)])
+    }
+
+    pub fn sequence(responses: Vec<(u16, Value)>) -> Self {
+        Self::with_delay(responses, Duration::ZERO)
+    }
+
+    pub fn with_delay(responses: Vec<(u16, Value)>, delay: Duration) -> Self {
+        Self::spawn(delay, move |index, _| {
+            responses[index.min(responses.len() - 1)].clone()
+        })
+    }
+
+    pub fn with_handler(
+        handler: impl Fn(&RecordedRequest) -> (u16, Value) + Send + Sync + 'static,
+    ) -> Self {
+        Self::spawn(Duration::ZERO, move |_, request| handler(request))
+    }
+
+    fn spawn(
+        delay: Duration,
+        handler: impl Fn(usize, &RecordedRequest) -> (u16, Value) + Send + Sync + 'static,
+    ) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let max_in_flight = Arc::new(AtomicUsize::new(0));
+        let recorded = requests.clone();
+        let max_recorded = max_in_flight.clone();
+        let in_flight = Arc::new(AtomicUsize::new(0));
+        let handler = Arc::new(handler);
+
+        thread::spawn(move || {
+            for (index, stream) in listener.incoming().flatten().enumerate() {
+                let recorded = recorded.clone();
+                let max_recorded = max_recorded.clone();
+                let in_flight = in_flight.clone();
+                let handler = handler.clone();
+                thread::spawn(move || {
+                    let active = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                    max_recorded.fetch_max(active, Ordering::SeqCst);
+                    let request = read_request(&stream);
+                    let (status, response) = handler(index, &request);
+                    recorded.lock().unwrap().push(request);
+                    thread::sleep(delay);
+                    in_flight.fetch_sub(1, Ordering::SeqCst);
+                    write_response(stream, status, &response);
+                });
+            }
+        });
+
+        Self {
+            url,
+            requests,
+            max_in_flight,
+        }
+    }
+
+    pub fn max_in_flight(&self) -> usize {
+        self.max_in_flight.load(Ordering::SeqCst)
+    }
+
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        std::mem::take(&mut *self.requests.lock().unwrap())
+    }
+
+    pub fn single_request(&self) -> RecordedRequest {
+        let mut requests = self.requests();
+        assert_eq!(requests.len(), 1, "expected exactly one request");
+        requests.remove(0)
+    }
+}
+
+fn read_request(stream: &TcpStream) -> RecordedRequest {
+    let mut reader = BufReader::new(stream);
+
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line).unwrap();
+    let path = request_line
+        .split_whitespace()
+        .nth(1)
+        .unwrap_or_default()
+        .to_string();
+
+    let mut headers = HashMap::new();
+    loop {
+        let mut line = String::new();
+        reader.read_line(&mut line).unwrap();
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            headers.insert(name.trim().to_lowercase(), value.trim().to_string());
+        }
+    }
+
+    let length: usize = headers
+        .get("content-length")
+        .and_then(|l| l.parse().ok())
+        .unwrap_or_default();
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body).unwrap();
+
+    RecordedRequest {
+        path,
+        headers,
+        body: serde_json::from_slice(&body).unwrap_or(Value::Null),
+    }
+}
+
+fn write_response(mut stream: TcpStream, status: u16, body: &Value) {
+    let body = body.to_string();
+    let retry_after = if status == 429 || status >= 500 {
+        "Retry-After: 0\r\n"
+    } else {
+        ""
+    };
+    let response = format!(
+        "HTTP/1.1 {} Mock\r\nContent-Type: application/json\r\nContent-Length: {}\r\n{}Connection: close\r\n\r\n{}",
+        status,
+        body.len(),
+        retry_after,
+        body
+    );
+    let _ = stream.write_all(response.as_bytes());
+}
+
+fn workspace(name: &str) -> PathBuf {
+    let root = std::env::temp_dir().join(format!("tscanner-http-{}-{}", name, std::process::id()));
+    let rules_dir = root.join(".tscanner").join("ai-rules");
+    std::fs::create_dir_all(&rules_dir).unwrap();
+    std::fs::write(
+        rules_dir.join("rule.md"),
+        "Find console calls.\n\n{{FILES}}",
+    )
+    .unwrap();
+    root
+}
+
+pub fn run_rule(name: &str, ai_config: Value) -> AiExecutionResult {
+    let rule = json!({
+        "prompt": "rule.md",
+        "message": "No console",
+        "mode": "content"
+    });
+    run_rule_on_files(name, ai_config, rule, &["src/a.ts"]).0
+}
+
+pub fn run_rule_on_files(
+    name: &str,
+    ai_config: Value,
+    rule: Value,
+    files: &[&str],
+) -> (AiExecutionResult, Vec<AiRuleStatus>) {
+    let files: Vec<_> = files.iter().map(|file| (*file, SOURCE)).collect();
+    run_rule_with_cache(name, ai_config, rule, &files, Arc::new(AiCache::new()))
+}
+
+pub fn run_rule_with_cache(
+    name: &str,
+    ai_config: Value,
+    rule: Value,
+    files: &[(&str, &str)],
+    cache: Arc<AiCache>,
+) -> (AiExecutionResult, Vec<AiRuleStatus>) {
+    let root = workspace(name);
+    let ai_config: AiConfig = serde_json::from_value(ai_config).unwrap();
+    let rule: AiRuleConfig = serde_json::from_value(rule).unwrap();
+
+    let executor = AiExecutor::with_config_dir(
+        &root,
+        root.join(".tscanner"),
+        Some(ai_config),
+        cache,
+        |_| {},
+        |_| {},
+    );
+    let files: Vec<_> = files
+        .iter()
+        .map(|(file, content)| (root.join(file), content.to_string()))
+        .collect();
+    let statuses = Arc::new(Mutex::new(Vec::new()));
+    let recorded = statuses.clone();
+    let result = executor.execute_rules_with_progress(
+        &[("no-console".to_string(), rule)],
+        &files,
+        &root,
+        None,
+        Some(Arc::new(move |event| {
+            recorded.lock().unwrap().push(event.status)
+        })),
+    );
+
+    let _ = std::fs::remove_dir_all(&root);
+    let statuses = std::mem::take(&mut *statuses.lock().unwrap());
+    (result, statuses)
+}
+
+pub fn openai_response(content: &str) -> Value {
+    json!({ "choices": [{ "message": { "role": "assistant", "content": content } }] })
+}
+
+pub fn openai_config(url: &str) -> Value {
+    json!({
+        "provider": "openai",
+        "baseUrl": url,
+        "model": "gpt-test"
+    })
+}
+
+pub fn prompt_of(request: &RecordedRequest) -> String {
+    request.body["messages"][0]["content"]
+        .as_str()
+        .unwrap_or_default()
+        .to_string()
+}
@@ -0,0 +1,154 @@
+mod common;
+
+use common::{run_rule, MockServer};
+use serde_json::{json, Value};
+
+fn issues_json() -> String {
+    json!({ "issues": [{ "file": "src/a.ts", "line": 2, "column": 1, "message": "console call" }] })
+        .to_string()
+}
+
+#[test]
+fn openai_provider_sends_chat_completion_and_parses_issues() {
+    let server = MockServer::start(
+        200,
+        json!({ "choices": [{ "message": { "role": "assistant", "content": issues_json() } }] }),
+    );
+    std::env::set_var("TSCANNER_TEST_OPENAI_KEY", "sk-test");
+
+    let result = run_rule(
+        "openai",
+        json!({
+            "provider": "openai",
+            "baseUrl": format!("{}/v1/", server.url),
+            "model": "gpt-test",
+            "apiKeyEnv": "TSCANNER_TEST_OPENAI_KEY",
+            "temperature": 0.2,
+            "maxTokens": 512
+        }),
+    );
+
+    assert!(result.errors.is_empty(), "{:?}", result.errors);
+    assert_eq!(result.issues.len(), 1);
+    assert_eq!(result.issues[0].line, 2);
+    assert_eq!(result.issues[0].message, "console call");
+
+    let request = server.single_request();
+    assert_eq!(request.path, "/v1/chat/completions");
+    assert_eq!(request.headers["authorization"], "Bearer sk-test");
+    assert_eq!(request.body["model"], "gpt-test");
+    assert_eq!(request.body["max_tokens"], 512);
+    assert_eq!(request.body["temperature"], 0.2);
+    assert_eq!(request.body["response_format"]["type"], "json_object");
+    let prompt = request.body["messages"][0]["content"].as_str().unwrap();
+    assert!(prompt.contains("console.log(a);"));
+}
+
+#[test]
+fn openai_provider_works_without_api_key() {
+    let server = MockServer::start(
+        200,
+        json!({ "choices": [{ "message": { "content": "{\"issues\": []}" } }] }),
+    );
+
+    let result = run_rule(
+        "openai-local",
+        json!({
+            "provider": "openai",
+            "baseUrl": server.url,
+            "model": "llama3",
+            "apiKeyEnv": "TSCANNER_TEST_UNSET_KEY"
+        }),
+    );
+
+    assert!(result.errors.is_empty(), "{:?}", result.errors);
+    assert!(result.issues.is_empty());
+    assert!(!server
+        .single_request()
+        .headers
+        .contains_key("authorization"));
+}
+
+#[test]
+fn anthropic_provider_forces_tool_call_and_parses_issues() {
+    let issues: Value = serde_json::from_str(&issues_json()).unwrap();
+    let server = MockServer::start(
+        200,
+        json!({
+            "content": [
+                { "type": "text", "text": "Reporting issues." },
+                { "type": "tool_use", "id": "toolu_1", "name": "report_issues", "input": issues }
+            ],
+            "stop_reason": "tool_use"
+        }),
+    );
+    std::env::set_var("TSCANNER_TEST_ANTHROPIC_KEY", "ant-test");
+
+    let result = run_rule(
+        "anthropic",
+        json!({
+            "provider": "anthropic",
+            "baseUrl": server.url,
+            "model": "claude-test",
+            "apiKeyEnv": "TSCANNER_TEST_ANTHROPIC_KEY"
+        }),
+    );
+
+    assert!(result.errors.is_empty(), "{:?}", result.errors);
+    assert_eq!(result.issues.len(), 1);
+    assert_eq!(result.issues[0].message, "console call");
+
+    let request = server.single_request();
+    assert_eq!(request.path, "/messages");
+    assert_eq!(request.headers["x-api-key"], "ant-test");
+    assert!(request.headers.contains_key("anthropic-version"));
+    assert_eq!(request.body["model"], "claude-test");
+    assert_eq!(request.body["tool_choice"]["name"], "report_issues");
+    assert_eq!(
+        request.body["tools"][0]["input_schema"]["required"][0],
+        "issues"
+    );
+}
+
+#[test]
+fn anthropic_provider_requires_api_key() {
+    let result = run_rule(
+        "anthropic-no-key",
+        json!({
+            "provider": "anthropic",
+            "baseUrl": "http://127.0.0.1:9",
+            "model": "claude-test",
+            "apiKeyEnv": "TSCANNER_TEST_MISSING_KEY"
+        }),
+    );
+
+    assert!(result.issues.is_empty());
+    assert_eq!(result.errors.len(), 1);
+    assert!(result.errors[0].contains("TSCANNER_TEST_MISSING_KEY"));
+}
+
+#[test]
+fn http_error_status_is_reported() {
+    let server = MockServer::start(
+        429,
+        json!({ "error": { "type": "rate_limit_error", "message": "slow down" } }),
+    );
+
+    let result = run_rule(
+        "rate-limit",
+        json!({
+            "provider": "openai",
+            "baseUrl": server.url,
+            "model": "gpt-test"
+        }),
+    );
+
+    assert!(result.issues.is_empty());
+    assert_eq!(result.errors.len(), 1);
+    assert!(result.errors[0].contains("429"), "{}", result.errors[0]);
+    assert!(
+        result.errors[0].contains("slow down"),
+        "{}",
+        result.errors[0]
+    );
+}
@@ -0,0 +1,111 @@
+mod common;
+
+use common::{openai_config, openai_response, prompt_of, MockServer};
+use serde_json::{json, Value};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::Arc;
+use tscanner_cache::{AiCache, FileCache, ScriptCache};
+use tscanner_config::{AiExecutionMode, TscannerConfig};
+use tscanner_scanner::{GitContentSource, ScanCallbacks, Scanner};
+use tscanner_types::ScanResult;
+
+const STAGED: &str = "const staged = 1;\nconsole.log(staged);\n";
+const WORKING: &str = "const working = 2;\n";
+
+fn git(root: &Path, args: &[&str]) {
+    let status = Command::new("git")
+        .args(args)
+        .current_dir(root)
+        .output()
+        .unwrap()
+        .status;
+    assert!(status.success(), "git {:?} failed", args);
+}
+
+fn partially_staged_repo(name: &str) -> PathBuf {
+    let root =
+        std::env::temp_dir().join(format!("tscanner-staged-{}-{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&root);
+    let rules_dir = root.join(".tscanner").join("ai-rules");
+    std::fs::create_dir_all(&rules_dir).unwrap();
+    std::fs::create_dir_all(root.join("src")).unwrap();
+    std::fs::write(
+        rules_dir.join("rule.md"),
+        "Find console calls.\n\n{{FILES}}",
+    )
+    .unwrap();
+
+    git(&root, &["init", "-q"]);
+    std::fs::write(root.join("src/a.ts"), STAGED).unwrap();
+    git(&root, &["add", "src/a.ts"]);
+    std::fs::write(root.join("src/a.ts"), WORKING).unwrap();
+    root
+}
+
+fn scan_index(root: &Path, server: &MockServer, ai_rules: Value) -> ScanResult {
+    let config: TscannerConfig = serde_json::from_value(json!({
+        "ai": openai_config(&server.url),
+        "aiRules": ai_rules,
+        "files": { "include": ["**/*.ts"], "exclude": [] }
+    }))
+    .unwrap();
+    let scanner = Scanner::with_caches_and_config_dir(
+        config,
+        Arc::new(FileCache::new()),
+        Arc::new(AiCache::new()),
+        Arc::new(ScriptCache::new()),
+        root.to_path_buf(),
+        root.join(".tscanner"),
+    )
+    .unwrap();
+
+    scanner.scan_git_contents_with_callbacks(
+        &GitContentSource::Index,
+        &HashSet::from([root.join("src/a.ts")]),
+        AiExecutionMode::Include,
+        None,
+        ScanCallbacks {
+            on_regular_rules_complete: None,
+            on_ai_progress: None,
+        },
+    )
+}
+
+fn ai_rule(mode: &str) -> Value {
+    json!({
+        "prompt": "rule.md",
+        "message": "No console",
+        "mode": mode,
+        "include": ["**/*.ts"]
+    })
+}
+
+#[test]
+fn content_mode_reads_staged_contents() {
+    let root = partially_staged_repo("content");
+    let server = MockServer::start(200, openai_response("{\"issues\": []}"));
+
+    let result = scan_index(&root, &server, json!({ "no-console": ai_rule("content") }));
+
+    assert!(result.errors.is_empty(), "{:?}", result.errors);
+    assert!(result.warnings.is_empty(), "{:?}", result.warnings);
+    let prompt = prompt_of(&server.single_request());
+    assert!(prompt.contains(STAGED), "{}", prompt);
+    assert!(!prompt.contains("working"), "{}", prompt);
+    let _ = std::fs::remove_dir_all(&root);
+}
+
+#[test]
+fn path_modes_warn_when_working_tree_differs() {
+    let root = partially_staged_repo("paths");
+    let server = MockServer::start(200, openai_response("{\"issues\": []}"));
+
+    let result = scan_index(&root, &server, json!({ "no-console": ai_rule("paths") }));
+
+    assert!(result.errors.is_empty(), "{:?}", result.errors);
+    assert_eq!(result.warnings.len(), 1, "{:?}", result.warnings);
+    assert!(result.warnings[0].contains("AI rule 'no-console' uses 'paths' mode"));
+    let _ = std::fs::remove_dir_all(&root);
+}
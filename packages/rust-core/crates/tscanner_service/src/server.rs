@@ -79,9 +79,18 @@ impl Workspace for WorkspaceServer {
 
         let resolved_config_dir = resolve_config_dir(&params.root, params.config_dir);
 
-        let config_hash = config.compute_hash();
-        let ai_cache = Arc::new(tscanner_cache::AiCache::with_config_hash(config_hash));
-        let script_cache = Arc::new(tscanner_cache::ScriptCache::with_config_hash(config_hash));
+        let ai_cache = Arc::new(
+            tscanner_cache::AiCache::for_project(&params.root, None, config.ai_rule_fingerprints())
+                .with_limits(config.cache_limits()),
+        );
+        let script_cache = Arc::new(
+            tscanner_cache::ScriptCache::for_project(
+                &params.root,
+                None,
+                config.script_rule_fingerprints(),
+            )
+            .with_limits(config.cache_limits()),
+        );
 
         let scanner_result = Scanner::with_caches_and_config_dir(
             config.clone(),
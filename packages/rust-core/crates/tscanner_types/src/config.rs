@@ -27,12 +27,54 @@ fn is_zero(v: &u64) -> bool {
 #[serde(rename_all = "camelCase")]
 pub struct AiConfig {
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[schemars(description = "AI provider to use (claude, gemini, custom)")]
+    #[schemars(description = "AI provider to use (claude, gemini, custom, openai, anthropic)")]
     pub provider: Option<AiProvider>,
 
     #[serde(default, skip_serializing_if = "Option::is_none")]
     #[schemars(description = "Custom command path (required only for 'custom' provider)")]
     pub command: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(
+        description = "API base URL for HTTP providers (e.g. http://localhost:11434/v1 for Ollama)"
+    )]
+    pub base_url: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(description = "Model name (required for 'openai' and 'anthropic' providers)")]
+    pub model: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(
+        description = "Environment variable holding the API key (default: OPENAI_API_KEY or ANTHROPIC_API_KEY)"
+    )]
+    pub api_key_env: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(description = "Sampling temperature for HTTP providers")]
+    pub temperature: Option<f64>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(description = "Maximum tokens in the response for HTTP providers")]
+    pub max_tokens: Option<u64>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(
+        description = "Maximum number of AI requests running at the same time (default: 4)"
+    )]
+    pub max_concurrency: Option<u64>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(
+        description = "Maximum number of AI requests started per minute (default: unlimited)"
+    )]
+    pub requests_per_minute: Option<u64>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(
+        description = "Retries for rate-limited or transient AI failures, with exponential backoff (default: 4)"
+    )]
+    pub max_retries: Option<u64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -94,6 +136,18 @@ impl Default for CodeEditorConfig {
     }
 }
 
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CacheConfig {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(description = "Maximum size of each cache file in megabytes (default: 100)")]
+    pub max_size_mb: Option<u64>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(description = "Evict cache entries unused for this many days (default: 30)")]
+    pub max_age_days: Option<u64>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct FilesConfig {
@@ -135,6 +189,10 @@ pub struct TscannerConfig {
     #[schemars(description = "Code editor configuration (highlighting, auto-scan)")]
     pub code_editor: Option<CodeEditorConfig>,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schemars(description = "Scan cache limits (size cap and entry age)")]
+    pub cache: Option<CacheConfig>,
+
     #[serde(default)]
     #[schemars(description = "Rules configuration (builtin, regex, script)")]
     pub rules: RulesConfig,
@@ -231,6 +289,12 @@ pub struct ScriptRuleConfig {
     #[schemars(description = "File patterns to exclude")]
     pub exclude: Vec<String>,
 
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[schemars(
+        description = "Extra file patterns the script reads (helpers, schemas); changes to matching files invalidate cached results"
+    )]
+    pub depends_on: Vec<String>,
+
     #[serde(default, skip_serializing_if = "is_zero")]
     #[schemars(description = "Script timeout in seconds (default: 0 = no limit)")]
     pub timeout: u64,
@@ -248,6 +312,7 @@ impl Default for ScriptRuleConfig {
             severity: Severity::Warning,
             include: Vec::new(),
             exclude: Vec::new(),
+            depends_on: Vec::new(),
             timeout: 0,
             options: serde_json::Value::Null,
         }
@@ -286,6 +351,16 @@ pub struct AiRuleConfig {
     #[schemars(description = "Timeout in seconds (default: 0 = no limit)")]
     pub timeout: u64,
 
+    #[serde(default, skip_serializing_if = "is_zero")]
+    #[schemars(description = "Maximum files sent per AI call (default: 0 = no limit)")]
+    pub batch_size: u64,
+
+    #[serde(default, skip_serializing_if = "is_zero")]
+    #[schemars(
+        description = "Approximate token budget per AI call; files are split into batches to fit (default: 0 = no limit)"
+    )]
+    pub max_prompt_tokens: u64,
+
     #[serde(default, skip_serializing_if = "serde_json::Value::is_null")]
     #[schemars(description = "Additional options")]
     pub options: serde_json::Value,
@@ -301,6 +376,8 @@ impl Default for AiRuleConfig {
             include: Vec::new(),
             exclude: Vec::new(),
             timeout: 0,
+            batch_size: 0,
+            max_prompt_tokens: 0,
             options: serde_json::Value::Null,
         }
     }
@@ -71,20 +71,33 @@ pub enum AiProvider {
     Claude,
     Gemini,
     Custom,
+    Openai,
+    Anthropic,
 }
 
 impl AiProvider {
-    pub const ALL: &'static [AiProvider] =
-        &[AiProvider::Claude, AiProvider::Gemini, AiProvider::Custom];
+    pub const ALL: &'static [AiProvider] = &[
+        AiProvider::Claude,
+        AiProvider::Gemini,
+        AiProvider::Custom,
+        AiProvider::Openai,
+        AiProvider::Anthropic,
+    ];
 
     pub fn as_str(&self) -> &'static str {
         match self {
             AiProvider::Claude => "claude",
             AiProvider::Gemini => "gemini",
             AiProvider::Custom => "custom",
+            AiProvider::Openai => "openai",
+            AiProvider::Anthropic => "anthropic",
         }
     }
 
+    pub fn is_http(&self) -> bool {
+        matches!(self, AiProvider::Openai | AiProvider::Anthropic)
+    }
+
     pub fn all_names() -> String {
         Self::ALL
             .iter()
@@ -103,6 +116,16 @@ pub enum AiMode {
     Agentic,
 }
 
+impl AiMode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AiMode::Paths => "paths",
+            AiMode::Content => "content",
+            AiMode::Agentic => "agentic",
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, JsonSchema, PartialEq, Default)]
 #[serde(rename_all = "lowercase")]
 pub enum AiExecutionMode {
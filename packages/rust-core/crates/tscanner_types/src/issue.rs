@@ -1,4 +1,5 @@
 use crate::enums::{IssueRuleType, Severity};
+use crate::text_range::{TextEdit, TextRange};
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 
@@ -8,6 +9,8 @@ pub struct Issue {
     pub file: PathBuf,
     pub line: usize,
     pub column: usize,
+    #[serde(default)]
+    pub end_line: usize,
     pub end_column: usize,
     pub message: String,
     pub severity: Severity,
@@ -17,6 +20,38 @@ pub struct Issue {
     pub category: Option<String>,
     #[serde(default)]
     pub rule_type: IssueRuleType,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fix: Option<IssueFix>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub docs_url: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub related: Vec<RelatedLocation>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub blame: Option<IssueBlame>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssueFix {
+    pub title: String,
+    pub edits: Vec<TextEdit>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssueBlame {
+    pub author: String,
+    pub email: String,
+    pub commit: String,
+    pub date: String,
+    pub timestamp: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RelatedLocation {
+    pub file: PathBuf,
+    pub range: TextRange,
+    pub message: String,
 }
 
 impl Issue {
@@ -33,15 +68,25 @@ impl Issue {
             file,
             line,
             column,
+            end_line: line,
             end_column,
             message,
             severity: Severity::Error,
             line_text: None,
             category: None,
             rule_type: IssueRuleType::Builtin,
+            fix: None,
+            note: None,
+            docs_url: None,
+            related: Vec::new(),
+            blame: None,
         }
     }
 
+    pub fn last_line(&self) -> usize {
+        self.end_line.max(self.line)
+    }
+
     pub fn is_ai(&self) -> bool {
         self.rule_type == IssueRuleType::Ai
     }
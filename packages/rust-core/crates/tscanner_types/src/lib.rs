@@ -14,7 +14,7 @@ pub use config::*;
 pub use display::*;
 pub use enums::*;
 pub use file_source::{FileSource, Language, LanguageVariant};
-pub use issue::Issue;
+pub use issue::{Issue, IssueBlame, IssueFix, RelatedLocation};
 pub use metadata::*;
 pub use params::*;
 pub use results::{ContentScanResult, FileResult, ScanResult};
@@ -34,6 +34,12 @@ fn is_empty_options(options: &&'static [RuleOption]) -> bool {
     options.is_empty()
 }
 
+#[derive(Debug, Clone, Copy)]
+pub struct RuleExample {
+    pub invalid: &'static str,
+    pub valid: &'static str,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct RuleMetadata {
@@ -52,6 +58,8 @@ pub struct RuleMetadata {
     pub equivalent_biome_rule: Option<&'static str>,
     #[serde(default, skip_serializing_if = "is_empty_options", skip_deserializing)]
     pub options: &'static [RuleOption],
+    #[serde(skip)]
+    pub example: Option<RuleExample>,
 }
 
 impl RuleMetadata {
@@ -68,6 +76,7 @@ impl RuleMetadata {
             equivalent_eslint_rule: None,
             equivalent_biome_rule: None,
             options: &[],
+            example: None,
         }
     }
 }
@@ -121,6 +121,50 @@ impl ScanResult {
 
         self.total_issues = self.files.iter().map(|f| f.issues.len()).sum();
     }
+
+    pub fn filter_by_author(&mut self, author: &str) {
+        self.files = self
+            .files
+            .drain(..)
+            .filter_map(|mut file_result| {
+                file_result.issues.retain(|issue| {
+                    issue.blame.as_ref().is_some_and(|blame| {
+                        blame.email.eq_ignore_ascii_case(author)
+                            || blame.author.eq_ignore_ascii_case(author)
+                    })
+                });
+                if !file_result.issues.is_empty() {
+                    Some(file_result)
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        self.total_issues = self.files.iter().map(|f| f.issues.len()).sum();
+    }
+
+    pub fn filter_by_blame_since(&mut self, since_timestamp: i64) {
+        self.files = self
+            .files
+            .drain(..)
+            .filter_map(|mut file_result| {
+                file_result.issues.retain(|issue| {
+                    issue
+                        .blame
+                        .as_ref()
+                        .is_some_and(|blame| blame.timestamp >= since_timestamp)
+                });
+                if !file_result.issues.is_empty() {
+                    Some(file_result)
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        self.total_issues = self.files.iter().map(|f| f.issues.len()).sum();
+    }
 }
 
 fn severity_level(severity: Severity) -> u8 {
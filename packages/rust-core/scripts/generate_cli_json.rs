@@ -1,4 +1,4 @@
-use clap::CommandFactory;
+use clap::{Command, CommandFactory};
 use serde::Serialize;
 use std::fs;
 use std::path::PathBuf;
@@ -53,110 +53,17 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             continue;
         }
 
-        let mut arguments = Vec::new();
-        let mut flags = Vec::new();
-
-        for arg in subcmd.get_arguments() {
-            let arg_name = arg.get_id().to_string();
-
-            if arg_name == "help" || arg_name == "version" {
-                continue;
-            }
-
-            let is_positional = arg.is_positional();
-            let description = arg.get_help().map(|s| s.to_string());
-            let default_value = arg
-                .get_default_values()
-                .first()
-                .map(|v| v.to_string_lossy().to_string());
-            let required = arg.is_required_set();
-            let value_name = arg
-                .get_value_names()
-                .and_then(|names| names.first().map(|n| n.to_string()));
-
-            let kebab_name = arg_name.replace('_', "-");
-
-            if is_positional {
-                arguments.push(ArgumentInfo {
-                    name: kebab_name,
-                    description,
-                    required,
-                    default_value,
-                });
-            } else {
-                let short = arg.get_short();
-                let takes_value = arg.get_action().takes_values();
-                let possible_values: Vec<String> = arg
-                    .get_possible_values()
-                    .iter()
-                    .map(|v| v.get_name().to_string())
-                    .collect();
-                let group = arg.get_help_heading().map(|s| s.to_string());
-
-                flags.push(FlagInfo {
-                    name: kebab_name,
-                    short,
-                    description,
-                    takes_value,
-                    value_name: if takes_value { value_name } else { None },
-                    possible_values: if possible_values.is_empty() {
-                        None
-                    } else {
-                        Some(possible_values)
-                    },
-                    default_value,
-                    required,
-                    group,
-                });
-            }
+        let nested: Vec<&Command> = subcmd
+            .get_subcommands()
+            .filter(|nested| nested.get_name() != "help")
+            .collect();
+        if nested.is_empty() {
+            collect_command(subcmd.get_name().to_string(), subcmd, &mut commands);
+        }
+        for nested_cmd in nested {
+            let name = format!("{} {}", subcmd.get_name(), nested_cmd.get_name());
+            collect_command(name, nested_cmd, &mut commands);
         }
-
-        let group_order = ["Scan Mode", "AI Rules", "Filtering", "Output", "Other"];
-        flags.sort_by(|a, b| {
-            let a_idx = a
-                .group
-                .as_ref()
-                .and_then(|g| group_order.iter().position(|&x| x == g))
-                .unwrap_or(usize::MAX);
-            let b_idx = b
-                .group
-                .as_ref()
-                .and_then(|g| group_order.iter().position(|&x| x == g))
-                .unwrap_or(usize::MAX);
-            a_idx.cmp(&b_idx).then_with(|| a.name.cmp(&b.name))
-        });
-
-        let usage = format!(
-            "tscanner {} [options]{}",
-            subcmd.get_name(),
-            if arguments.is_empty() {
-                String::new()
-            } else {
-                format!(
-                    " {}",
-                    arguments
-                        .iter()
-                        .map(|a| if a.required {
-                            format!("<{}>", a.name)
-                        } else {
-                            format!("[{}]", a.name)
-                        })
-                        .collect::<Vec<_>>()
-                        .join(" ")
-                )
-            }
-        );
-
-        commands.push(CommandInfo {
-            name: subcmd.get_name().to_string(),
-            description: subcmd
-                .get_about()
-                .map(|s| s.to_string())
-                .unwrap_or_default(),
-            usage,
-            arguments,
-            flags,
-        });
     }
 
     commands.sort_by(|a, b| a.name.cmp(&b.name));
@@ -186,3 +93,110 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+fn collect_command(name: String, subcmd: &Command, commands: &mut Vec<CommandInfo>) {
+    let mut arguments = Vec::new();
+    let mut flags = Vec::new();
+
+    for arg in subcmd.get_arguments() {
+        let arg_name = arg.get_id().to_string();
+
+        if arg_name == "help" || arg_name == "version" {
+            continue;
+        }
+
+        let is_positional = arg.is_positional();
+        let description = arg.get_help().map(|s| s.to_string());
+        let default_value = arg
+            .get_default_values()
+            .first()
+            .map(|v| v.to_string_lossy().to_string());
+        let required = arg.is_required_set();
+        let value_name = arg
+            .get_value_names()
+            .and_then(|names| names.first().map(|n| n.to_string()));
+
+        let kebab_name = arg_name.replace('_', "-");
+
+        if is_positional {
+            arguments.push(ArgumentInfo {
+                name: kebab_name,
+                description,
+                required,
+                default_value,
+            });
+        } else {
+            let short = arg.get_short();
+            let takes_value = arg.get_action().takes_values();
+            let possible_values: Vec<String> = arg
+                .get_possible_values()
+                .iter()
+                .map(|v| v.get_name().to_string())
+                .collect();
+            let group = arg.get_help_heading().map(|s| s.to_string());
+
+            flags.push(FlagInfo {
+                name: kebab_name,
+                short,
+                description,
+                takes_value,
+                value_name: if takes_value { value_name } else { None },
+                possible_values: if possible_values.is_empty() {
+                    None
+                } else {
+                    Some(possible_values)
+                },
+                default_value,
+                required,
+                group,
+            });
+        }
+    }
+
+    let group_order = ["Scan Mode", "AI Rules", "Filtering", "Output", "Other"];
+    flags.sort_by(|a, b| {
+        let a_idx = a
+            .group
+            .as_ref()
+            .and_then(|g| group_order.iter().position(|&x| x == g))
+            .unwrap_or(usize::MAX);
+        let b_idx = b
+            .group
+            .as_ref()
+            .and_then(|g| group_order.iter().position(|&x| x == g))
+            .unwrap_or(usize::MAX);
+        a_idx.cmp(&b_idx).then_with(|| a.name.cmp(&b.name))
+    });
+
+    let usage = format!(
+        "tscanner {} [options]{}",
+        name,
+        if arguments.is_empty() {
+            String::new()
+        } else {
+            format!(
+                " {}",
+                arguments
+                    .iter()
+                    .map(|a| if a.required {
+                        format!("<{}>", a.name)
+                    } else {
+                        format!("[{}]", a.name)
+                    })
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            )
+        }
+    );
+
+    commands.push(CommandInfo {
+        name,
+        description: subcmd
+            .get_about()
+            .map(|s| s.to_string())
+            .unwrap_or_default(),
+        usage,
+        arguments,
+        flags,
+    });
+}
@@ -162,30 +162,22 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         .get_mut("definitions")
         .and_then(|d| d.as_object_mut())
     {
-        definitions.insert(
-            "AiConfig".to_string(),
-            json!({
-                "type": "object",
-                "properties": {
-                    "provider": {
-                        "anyOf": [
-                            { "$ref": "#/definitions/AiProvider" },
-                            { "type": "null" }
-                        ],
-                        "description": "AI provider to use (claude, gemini, custom)"
-                    },
-                    "command": {
-                        "type": ["string", "null"],
-                        "description": "Custom command path (required when provider is 'custom')"
-                    }
-                },
-                "if": {
+        if let Some(ai_config) = definitions
+            .get_mut("AiConfig")
+            .and_then(|c| c.as_object_mut())
+        {
+            ai_config.insert(
+                "if".to_string(),
+                json!({
                     "properties": {
                         "provider": { "const": "custom" }
                     },
                     "required": ["provider"]
-                },
-                "then": {
+                }),
+            );
+            ai_config.insert(
+                "then".to_string(),
+                json!({
                     "required": ["command"],
                     "properties": {
                         "command": {
@@ -193,9 +185,9 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                             "minLength": 1
                         }
                     }
-                }
-            }),
-        );
+                }),
+            );
+        }
 
         if let Some(files_config) = definitions.get_mut("FilesConfig") {
             if let Some(files_config_obj) = files_config.as_object_mut() {
@@ -23,12 +23,19 @@ class AiProgressItem extends vscode.TreeItem {
 function getStatusIcon(status: AiRuleStatus): string {
   if ('pending' in status) return DISPLAY_ICONS.regex;
   if ('running' in status) return DISPLAY_ICONS.progress;
+  if ('retrying' in status) return DISPLAY_ICONS.progress;
   if ('completed' in status) return DISPLAY_ICONS.success;
   if ('failed' in status) return DISPLAY_ICONS.error;
   return '?';
 }
 
 function getStatusSuffix(status: AiRuleStatus): string {
+  if ('running' in status && status.running.total_batches > 1) {
+    return ` (batch ${status.running.completed_batches}/${status.running.total_batches})`;
+  }
+  if ('retrying' in status) {
+    return ` (retrying ${status.retrying.attempt}/${status.retrying.max_retries})`;
+  }
   if ('completed' in status && status.completed.issues_found > 0) {
     const count = status.completed.issues_found;
     return ` (${count} ${count === 1 ? 'issue' : 'issues'})`;
@@ -35,7 +35,8 @@ export type ValidateConfigResult = {
 
 export type AiRuleStatus =
   | { pending: Record<string, never> }
-  | { running: Record<string, never> }
+  | { running: { completed_batches: number; total_batches: number } }
+  | { retrying: { attempt: number; max_retries: number } }
   | { completed: { issues_found: number } }
   | { failed: { error: string } };
 
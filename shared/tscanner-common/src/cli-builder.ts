@@ -3,16 +3,26 @@ import { AiExecutionMode, type GroupMode } from './types';
 
 export type CliCheckOptions = {
   branch?: string;
+  noMergeBase?: boolean;
   uncommitted?: boolean;
+  range?: string;
+  diff?: string;
   groupBy?: GroupMode;
   filter?: { type: string; value: string };
   severity?: string;
   kind?: string;
+  author?: string;
+  since?: string;
   aiMode?: AiExecutionMode;
   jsonOutput?: string;
+  report?: string[];
+  linkTemplate?: string;
+  codeFrame?: number;
+  blame?: boolean;
   configPath?: string;
   continueOnError?: boolean;
   noCache?: boolean;
+  cacheDir?: string;
 };
 
 export function buildCheckArgs(options: CliCheckOptions = {}): string[] {
@@ -30,10 +40,22 @@ export function buildCheckArgs(options: CliCheckOptions = {}): string[] {
     args.push('--branch', options.branch);
   }
 
+  if (options.noMergeBase) {
+    args.push('--no-merge-base');
+  }
+
   if (options.uncommitted) {
     args.push('--uncommitted');
   }
 
+  if (options.range) {
+    args.push('--range', options.range);
+  }
+
+  if (options.diff) {
+    args.push('--diff', options.diff);
+  }
+
   if (options.severity) {
     args.push('--severity', options.severity);
   }
@@ -42,6 +64,14 @@ export function buildCheckArgs(options: CliCheckOptions = {}): string[] {
     args.push('--kind', options.kind);
   }
 
+  if (options.author) {
+    args.push('--author', options.author);
+  }
+
+  if (options.since) {
+    args.push('--since', options.since);
+  }
+
   if (options.aiMode === AiExecutionMode.Include) {
     args.push('--include-ai');
   } else if (options.aiMode === AiExecutionMode.Only) {
@@ -52,6 +82,22 @@ export function buildCheckArgs(options: CliCheckOptions = {}): string[] {
     args.push('--json-output', options.jsonOutput);
   }
 
+  for (const report of options.report ?? []) {
+    args.push('--report', report);
+  }
+
+  if (options.linkTemplate) {
+    args.push('--link-template', options.linkTemplate);
+  }
+
+  if (options.codeFrame !== undefined) {
+    args.push('--code-frame', String(options.codeFrame));
+  }
+
+  if (options.blame) {
+    args.push('--blame');
+  }
+
   if (options.configPath) {
     args.push('--config-path', options.configPath);
   }
@@ -64,6 +110,10 @@ export function buildCheckArgs(options: CliCheckOptions = {}): string[] {
     args.push('--no-cache');
   }
 
+  if (options.cacheDir) {
+    args.push('--cache-dir', options.cacheDir);
+  }
+
   return args;
 }
 
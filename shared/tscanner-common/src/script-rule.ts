@@ -21,6 +21,7 @@ export type ScriptIssue = {
 
 export type ScriptOutput = {
   issues: ScriptIssue[];
+  reads?: string[];
 };
 
 export function addIssue(issues: ScriptIssue[], issue: Omit<ScriptIssue, 'column'> & { column?: number }): void {
@@ -35,16 +36,17 @@ export async function readScriptInput<TOptions = Record<string, unknown>>(): Pro
   return JSON.parse(data);
 }
 
-export function writeScriptOutput(issues: ScriptIssue[]): void {
-  console.log(JSON.stringify({ issues }));
+export function writeScriptOutput(issues: ScriptIssue[], reads?: string[]): void {
+  const output: ScriptOutput = reads && reads.length > 0 ? { issues, reads } : { issues };
+  console.log(JSON.stringify(output));
 }
 
 export function runScript<TOptions = Record<string, unknown>>(
-  fn: (input: ScriptInput<TOptions>) => Promise<ScriptIssue[]> | ScriptIssue[],
+  fn: (input: ScriptInput<TOptions>) => Promise<ScriptIssue[] | ScriptOutput> | ScriptIssue[] | ScriptOutput,
 ): void {
   readScriptInput<TOptions>()
     .then((input) => Promise.resolve(fn(input)))
-    .then(writeScriptOutput)
+    .then((result) => (Array.isArray(result) ? writeScriptOutput(result) : writeScriptOutput(result.issues, result.reads)))
     .catch((err) => {
       console.error(err);
       process.exit(1);
@@ -1,13 +1,28 @@
 import type { IssueRuleType } from './enums';
 
+export type CliOutputRelatedLocation = {
+  file: string;
+  line: number;
+  column: number;
+  end_line: number;
+  end_column: number;
+  message: string;
+};
+
 export type CliOutputIssue = {
   rule: string;
   severity: string;
   line: number;
   column: number;
+  end_line: number;
+  end_column: number;
   message: string;
   line_text?: string;
   rule_type: IssueRuleType;
+  fix_title?: string;
+  note?: string;
+  docs_url?: string;
+  related?: CliOutputRelatedLocation[];
 };
 
 export type CliOutputFileGroup = {
@@ -51,9 +66,15 @@ export type CliOutputRuleIssue = {
   file: string;
   line: number;
   column: number;
+  end_line: number;
+  end_column: number;
   message: string;
   severity: string;
   line_text?: string;
+  fix_title?: string;
+  note?: string;
+  docs_url?: string;
+  related?: CliOutputRelatedLocation[];
 };
 
 export type CliOutputRuleGroup = {
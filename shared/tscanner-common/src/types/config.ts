@@ -20,6 +20,7 @@ const regexRuleConfigSchema = baseRuleConfigSchema.extend({
 const scriptRuleConfigSchema = baseRuleConfigSchema.extend({
   command: z.string(),
   message: z.string(),
+  dependsOn: z.array(z.string()).optional(),
   timeout: z.number().optional(),
   options: z.any().optional(),
 });
@@ -31,6 +32,8 @@ const aiRuleConfigSchema = baseRuleConfigSchema.extend({
   message: z.string(),
   mode: aiModeSchema.optional(),
   timeout: z.number().optional(),
+  batchSize: z.number().optional(),
+  maxPromptTokens: z.number().optional(),
   options: z.any().optional(),
 });
 
@@ -45,6 +48,14 @@ const aiProviderSchema = z.enum(AiProvider);
 const aiConfigSchema = z.object({
   provider: aiProviderSchema.optional(),
   command: z.string().optional(),
+  baseUrl: z.string().optional(),
+  model: z.string().optional(),
+  apiKeyEnv: z.string().optional(),
+  temperature: z.number().optional(),
+  maxTokens: z.number().optional(),
+  maxConcurrency: z.number().optional(),
+  requestsPerMinute: z.number().optional(),
+  maxRetries: z.number().optional(),
 });
 
 const codeEditorConfigSchema = z.object({
@@ -58,6 +69,11 @@ const codeEditorConfigSchema = z.object({
   startupAiScan: startupScanModeSchema.optional(),
 });
 
+const cacheConfigSchema = z.object({
+  maxSizeMb: z.number().optional(),
+  maxAgeDays: z.number().optional(),
+});
+
 const filesConfigSchema = z.object({
   include: z.array(z.string()),
   exclude: z.array(z.string()),
@@ -70,6 +86,7 @@ export const tscannerConfigSchema = z.object({
   files: filesConfigSchema,
   ai: aiConfigSchema.optional(),
   codeEditor: codeEditorConfigSchema.optional(),
+  cache: cacheConfigSchema.optional(),
 });
 
 export type TscannerConfig = z.infer<typeof tscannerConfigSchema>;
@@ -18,6 +18,8 @@ export enum AiProvider {
   Claude = 'claude',
   Gemini = 'gemini',
   Custom = 'custom',
+  Openai = 'openai',
+  Anthropic = 'anthropic',
 }
 
 export enum AiMode {
@@ -1,17 +1,32 @@
 import z from 'zod';
 import { IssueRuleType, issueRuleTypeSchema, severitySchema } from './enums';
 
+const relatedLocationSchema = z.object({
+  file: z.string(),
+  range: z.object({
+    start_line: z.number(),
+    start_col: z.number(),
+    end_line: z.number(),
+    end_col: z.number(),
+  }),
+  message: z.string(),
+});
+
 const issueSchema = z.object({
   rule: z.string(),
   file: z.string(),
   line: z.number(),
   column: z.number(),
+  end_line: z.number().optional(),
   end_column: z.number(),
   message: z.string(),
   severity: severitySchema,
   line_text: z.string().optional(),
   category: z.string().optional(),
   rule_type: issueRuleTypeSchema.optional().default(IssueRuleType.Builtin),
+  note: z.string().optional(),
+  docs_url: z.string().optional(),
+  related: z.array(relatedLocationSchema).optional(),
 });
 
 export type Issue = z.infer<typeof issueSchema>;
@@ -6,12 +6,15 @@ const issueSchema = z.object({
   file: z.string(),
   line: z.number(),
   column: z.number(),
+  end_line: z.number().optional(),
   end_column: z.number(),
   message: z.string(),
   severity: severitySchema,
   line_text: z.string().optional(),
   category: z.string().optional(),
   rule_type: issueRuleTypeSchema.optional().default(IssueRuleType.Builtin),
+  note: z.string().optional(),
+  docs_url: z.string().optional(),
 });
 
 const fileResultSchema = z.object({